@@ -0,0 +1,236 @@
+//! Binary angle measurement (BAM), where a full turn maps onto the range of an unsigned integer.
+//!
+//! Arithmetic on binary angles wraps around at full turns, so phase wrapping is free and exact.
+//! This suits phase accumulators, where radians accumulate rounding errors and need wrapping.
+//!
+//! ## Example
+//!
+//! ```
+//! use mixed_num::*;
+//!
+//! let step = BinaryAngle::<u32>::from_degrees(22.5f64);
+//! let mut phase = BinaryAngle::<u32>::ZERO;
+//! for _ in 0..160 {
+//!     phase += step;
+//! }
+//! // 3600 degrees is ten full turns.
+//! assert_eq!{ phase, BinaryAngle::<u32>::ZERO };
+//!
+//! let (sin, cos) = BinaryAngle::<u32>::from_degrees(30f64).sincos::<f32>();
+//! assert!{ (sin-0.5f32).abs() < 1e-6 && (cos-0.75f32.sqrt()).abs() < 1e-6 };
+//! ```
+
+use crate::*;
+use crate::trigonometry::lut;
+
+/// Binary angle, where a full turn is `2^N` for the `N` bit unsigned integer `T`.
+///
+/// Implemented for `u8`, `u16`, `u32` and `u64`.
+/// Conversions to radians are in the -π=<x<π range, and to degrees in the -180=<x<180 range.
+///
+/// `MixedSin` and `MixedCos` return `Self`, which is not an angle, so binary angles instead provide `sin`, `cos` and `sincos` in any Mixed type.
+/// These use the quarter wave table of `trigonometry::lut` with quadratic interpolation, with an error below 2e-8.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct BinaryAngle<T = u32>(pub T);
+
+macro_rules! impl_binary_angle{
+    ( $T:ty, $S:ty ) => {
+        impl BinaryAngle<$T>
+        {
+            /// The zero angle.
+            pub const ZERO: Self = BinaryAngle(0);
+            /// A quarter turn, π/2.
+            pub const QUARTER_TURN: Self = BinaryAngle(1 << (<$T>::BITS-2));
+            /// Half a turn, π.
+            pub const HALF_TURN: Self = BinaryAngle(1 << (<$T>::BITS-1));
+
+            /// Create an angle from a fraction of a turn, wrapping to a full turn.
+            pub fn from_turns( turns: f64 ) -> Self
+            {
+                let fraction = turns - libm::floor(turns);
+                let scaled = libm::round( fraction*libm::ldexp(1f64, <$T>::BITS as i32) );
+                return BinaryAngle(scaled as u128 as $T);
+            }
+
+            /// Create an angle from radians in any Mixed type, wrapping to a full turn.
+            ///
+            /// ## Example
+            ///
+            /// ```
+            /// use mixed_num::*;
+            /// use fixed::{types::extra::U28, FixedI32};
+            ///
+            /// let angle = BinaryAngle::<u16>::from_radians( FixedI32::<U28>::from_num(-core::f64::consts::FRAC_PI_2) );
+            /// assert_eq!{ angle, BinaryAngle(0xC000) };
+            /// ```
+            pub fn from_radians<R>( radians: R ) -> Self
+                where R: MixedReal
+            {
+                let radians: f64 = radians.mixed_to_num();
+                return Self::from_turns( radians/core::f64::consts::TAU );
+            }
+
+            /// Create an angle from degrees in any Mixed type, wrapping to a full turn.
+            pub fn from_degrees<R>( degrees: R ) -> Self
+                where R: MixedReal
+            {
+                let degrees: f64 = degrees.mixed_to_num();
+                return Self::from_turns( degrees/360f64 );
+            }
+
+            /// The angle as a fraction of a turn, in the -0.5=<x<0.5 range.
+            pub fn to_turns( self ) -> f64
+            {
+                return libm::ldexp( (self.0 as $S) as f64, -(<$T>::BITS as i32) );
+            }
+
+            /// The angle in radians, in the -π=<x<π range.
+            pub fn to_radians<R>( self ) -> R
+                where R: MixedReal
+            {
+                return R::mixed_from_num( self.to_turns()*core::f64::consts::TAU );
+            }
+
+            /// The angle in degrees, in the -180=<x<180 range.
+            pub fn to_degrees<R>( self ) -> R
+                where R: MixedReal
+            {
+                return R::mixed_from_num( self.to_turns()*360f64 );
+            }
+
+            /// The angle as a 32 bit binary angle, for the tables in `trigonometry::lut`.
+            fn to_bam32( self ) -> u32
+            {
+                return ((self.0 as u128) << 32 >> <$T>::BITS) as u32;
+            }
+
+            /// The sine of the angle.
+            pub fn sin<R>( &self ) -> R
+                where R: MixedReal
+            {
+                let q30 = lut::sin_bam(self.to_bam32(), lut::Interpolation::Quadratic);
+                return R::mixed_from_num( q30 as f64/1073741824f64 );
+            }
+
+            /// The cosine of the angle.
+            pub fn cos<R>( &self ) -> R
+                where R: MixedReal
+            {
+                let q30 = lut::cos_bam(self.to_bam32(), lut::Interpolation::Quadratic);
+                return R::mixed_from_num( q30 as f64/1073741824f64 );
+            }
+
+            /// The sine and cosine of the angle.
+            pub fn sincos<R>( &self ) -> (R, R)
+                where R: MixedReal
+            {
+                return (self.sin(), self.cos());
+            }
+        }
+
+        impl core::ops::Add for BinaryAngle<$T> {
+            type Output = Self;
+            #[inline(always)]
+            fn add(self, rhs: Self) -> Self {
+                return BinaryAngle(self.0.wrapping_add(rhs.0));
+            }
+        }
+
+        impl core::ops::AddAssign for BinaryAngle<$T> {
+            #[inline(always)]
+            fn add_assign(&mut self, rhs: Self) {
+                self.0 = self.0.wrapping_add(rhs.0);
+            }
+        }
+
+        impl core::ops::Sub for BinaryAngle<$T> {
+            type Output = Self;
+            #[inline(always)]
+            fn sub(self, rhs: Self) -> Self {
+                return BinaryAngle(self.0.wrapping_sub(rhs.0));
+            }
+        }
+
+        impl core::ops::SubAssign for BinaryAngle<$T> {
+            #[inline(always)]
+            fn sub_assign(&mut self, rhs: Self) {
+                self.0 = self.0.wrapping_sub(rhs.0);
+            }
+        }
+
+        impl core::ops::Neg for BinaryAngle<$T> {
+            type Output = Self;
+            #[inline(always)]
+            fn neg(self) -> Self {
+                return BinaryAngle(self.0.wrapping_neg());
+            }
+        }
+
+        impl core::ops::Mul<$T> for BinaryAngle<$T> {
+            type Output = Self;
+            /// Multiply the angle by an integer, wrapping to a full turn.
+            #[inline(always)]
+            fn mul(self, rhs: $T) -> Self {
+                return BinaryAngle(self.0.wrapping_mul(rhs));
+            }
+        }
+
+        impl<T> Polar<T, BinaryAngle<$T>>
+            where T: MixedReal + MixedNumSigned + MixedOps
+        {
+            /// Convert a polar number with the angle in radians.
+            pub fn from_radians( polar: Polar<T> ) -> Self
+            {
+                return Polar::new(polar.mag, BinaryAngle::<$T>::from_radians(polar.ang));
+            }
+
+            /// Convert to a polar number with the angle in radians, in the -π=<x<π range.
+            pub fn to_radians( &self ) -> Polar<T>
+            {
+                return Polar::new(self.mag, self.ang.to_radians());
+            }
+
+            /// Complex conjugate.
+            pub fn conj( &self ) -> Self
+            {
+                return Polar::new(self.mag, -self.ang);
+            }
+        }
+
+        impl<T> ToCartesian<T> for Polar<T, BinaryAngle<$T>>
+            where T: MixedReal + MixedNumSigned + MixedOps
+        {
+            /// Polar to cartesian, with the sine and cosine of the binary angle.
+            fn to_cartesian( &self ) -> Cartesian<T>
+            {
+                let (sin, cos) = self.ang.sincos::<T>();
+                return Cartesian::new(self.mag*cos, self.mag*sin);
+            }
+        }
+
+        impl<T> core::ops::Mul for Polar<T, BinaryAngle<$T>>
+            where T: MixedNum + MixedOps
+        {
+            type Output = Self;
+            #[inline]
+            fn mul(self, rhs: Self) -> Self {
+                return Polar::new(self.mag*rhs.mag, self.ang+rhs.ang);
+            }
+        }
+
+        impl<T> core::ops::Mul<T> for Polar<T, BinaryAngle<$T>>
+            where T: MixedNum + MixedOps
+        {
+            type Output = Self;
+            #[inline]
+            fn mul(self, rhs: T) -> Self {
+                return Polar::new(self.mag*rhs, self.ang);
+            }
+        }
+    }
+}
+
+impl_binary_angle!(u8,  i8);
+impl_binary_angle!(u16, i16);
+impl_binary_angle!(u32, i32);
+impl_binary_angle!(u64, i64);
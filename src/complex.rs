@@ -22,9 +22,11 @@ pub struct Cartesian<T> {
 #[derive(PartialEq, PartialOrd, Eq, Copy, Clone, Debug, Default)]
 #[repr(C)]
 /// Polar complex number.
-pub struct Polar<T> {
+///
+/// The angle is in radians by default, or e.g. a `BinaryAngle` for wrap-free phase arithmetic.
+pub struct Polar<T, A = T> {
     // Magnitude
     pub mag: T,
-    // Angle [rad]
-    pub ang: T,
+    // Angle [rad], or a binary angle
+    pub ang: A,
 }
\ No newline at end of file
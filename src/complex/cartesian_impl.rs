@@ -16,6 +16,24 @@ impl<T> Cartesian<T>
     }
 }
 
+impl <T: MixedNum + MixedZero + MixedOne> Cartesian<T>
+{
+    /// The imaginary unit, i.
+    /// 
+    /// ## Example
+    /// 
+    /// ```
+    /// use mixed_num::*;
+    /// 
+    /// let i = Cartesian::<f32>::i();
+    /// assert_eq!{ i*i, Cartesian::new(-1f32, 0f32) };
+    /// ```
+    pub fn i() -> Self
+    {
+        return Cartesian::new(T::mixed_zero(), T::mixed_one());
+    }
+}
+
 impl <T: MixedNum + MixedNumSigned> NewFromCartesian<T> for Cartesian<T>
 {
     /// Type cast from real number T to Complex<T>.
@@ -43,7 +61,7 @@ impl <T: MixedNum + MixedNumSigned> ToCartesian<T> for Cartesian<T>
     }
 }
 
-impl <T: MixedNum + MixedNumSigned + MixedSqrt + MixedOps + MixedAbs + MixedPowi + MixedAtan> ToPolar<T> for Cartesian<T>
+impl <T: MixedNum + MixedNumSigned + MixedHypot + MixedOps + MixedAbs + MixedAtan> ToPolar<T> for Cartesian<T>
 {
     /// Complex<T> to Polar<T>.
     #[inline(always)]
@@ -71,6 +89,202 @@ impl <T: MixedNum + MixedZero + MixedOne> MixedOne for Cartesian<T>
     }
 }
 
+impl <T: MixedReal> Cartesian<T>
+{
+    /// The squared magnitude in `f64`, which does not overflow for fixed-point types.
+    fn mag2_f64(&self) -> f64
+    {
+        let re: f64 = self.re.mixed_to_num();
+        let im: f64 = self.im.mixed_to_num();
+        return re*re + im*im;
+    }
+
+    /// Convert from `f64`, saturating at the range of `T`.
+    fn saturating_from_f64(x: f64) -> T
+    {
+        let max: f64 = T::mixed_max_value().mixed_to_num();
+        let min: f64 = T::mixed_min_value().mixed_to_num();
+        if max <= x
+        {
+            return T::mixed_max_value();
+        }
+        if x <= min
+        {
+            return T::mixed_min_value();
+        }
+        return T::mixed_from_num(x);
+    }
+}
+
+impl <T: MixedReal> MixedMinMax for Cartesian<T>
+{
+    /// The one of `self` and `other` with the smallest magnitude, see `mixed_min_by_mag`.
+    fn mixed_min(&self, other:Self) -> Self {
+        return self.mixed_min_by_mag(other);
+    }
+    /// The one of `self` and `other` with the largest magnitude, see `mixed_max_by_mag`.
+    fn mixed_max(&self, other:Self) -> Self {
+        return self.mixed_max_by_mag(other);
+    }
+    /// Limit the magnitude of `self` to the magnitudes of `min` and `max`, keeping the phase.
+    /// 
+    /// Zero is raised to the magnitude of `min` on the real axis, and NaN values are returned unchanged.
+    /// The scaling is calculated in `f64`, and parts out of the range of `T` saturate.
+    fn mixed_clamp(&self, min:Self, max:Self) -> Self {
+        let mag2 = self.mag2_f64();
+        let limit2 = if max.mag2_f64() < mag2
+        {
+            max.mag2_f64()
+        }
+        else if mag2 < min.mag2_f64()
+        {
+            min.mag2_f64()
+        }
+        else
+        {
+            return *self;
+        };
+
+        if mag2 == 0f64
+        {
+            return Cartesian::new(Self::saturating_from_f64(libm::sqrt(limit2)), T::mixed_from_num(0f64));
+        }
+        let scale = libm::sqrt(limit2/mag2);
+        let re: f64 = self.re.mixed_to_num();
+        let im: f64 = self.im.mixed_to_num();
+        return Cartesian::new(Self::saturating_from_f64(re*scale), Self::saturating_from_f64(im*scale));
+    }
+    /// Compares the squared magnitudes in `f64`. A NaN magnitude is ignored, as for floats.
+    fn mixed_min_by_mag(&self, other:Self) -> Self {
+        let (a, b) = (self.mag2_f64(), other.mag2_f64());
+        if b.is_nan() || a <= b
+        {
+            return *self;
+        }
+        return other;
+    }
+    /// Compares the squared magnitudes in `f64`. A NaN magnitude is ignored, as for floats.
+    fn mixed_max_by_mag(&self, other:Self) -> Self {
+        let (a, b) = (self.mag2_f64(), other.mag2_f64());
+        if b.is_nan() || b <= a
+        {
+            return *self;
+        }
+        return other;
+    }
+}
+
+impl <T: MixedClassify> MixedClassify for Cartesian<T>
+{
+    /// Either part is NaN.
+    fn mixed_is_nan(&self) -> bool {
+        return self.re.mixed_is_nan() || self.im.mixed_is_nan();
+    }
+    /// Either part is infinite.
+    fn mixed_is_infinite(&self) -> bool {
+        return self.re.mixed_is_infinite() || self.im.mixed_is_infinite();
+    }
+    /// Both parts are finite.
+    fn mixed_is_finite(&self) -> bool {
+        return self.re.mixed_is_finite() && self.im.mixed_is_finite();
+    }
+    /// Either part is saturated.
+    fn mixed_is_saturated(&self) -> bool {
+        return self.re.mixed_is_saturated() || self.im.mixed_is_saturated();
+    }
+    /// The first category of either part, in the order of `MixedCategory`.
+    fn mixed_classify(&self) -> MixedCategory {
+        let re = self.re.mixed_classify();
+        let im = self.im.mixed_classify();
+        for category in [MixedCategory::Nan, MixedCategory::Infinite, MixedCategory::Saturated, MixedCategory::Zero, MixedCategory::Subnormal]
+        {
+            if re == category || im == category
+            {
+                // Zero only if both parts are zero.
+                if category != MixedCategory::Zero || re == im
+                {
+                    return category;
+                }
+            }
+        }
+        return MixedCategory::Normal;
+    }
+}
+
+impl <T: MixedRound> MixedRound for Cartesian<T>
+{
+    /// Round each part to the closest integer, with ties away from zero.
+    fn mixed_round(&self) -> Self {
+        return Cartesian::new(self.re.mixed_round(), self.im.mixed_round());
+    }
+    /// Round each part to the closest integer, with ties to the even integer.
+    fn mixed_round_ties_even(&self) -> Self {
+        return Cartesian::new(self.re.mixed_round_ties_even(), self.im.mixed_round_ties_even());
+    }
+}
+
+impl <T: MixedTrunc> MixedTrunc for Cartesian<T>
+{
+    /// Round each part towards zero to an integer.
+    fn mixed_trunc(&self) -> Self {
+        return Cartesian::new(self.re.mixed_trunc(), self.im.mixed_trunc());
+    }
+    /// The fractional part of each part.
+    fn mixed_fract(&self) -> Self {
+        return Cartesian::new(self.re.mixed_fract(), self.im.mixed_fract());
+    }
+}
+
+impl <T: MixedReal + MixedNumSigned + MixedZero + MixedAbs + MixedRecip + MixedOps> Cartesian<T>
+{
+    /// The reciprocal by Smith's method, with `recip` as the real reciprocal.
+    /// 
+    /// The ratio of the smaller to the larger part stays within one, so the squared magnitude is never formed.
+    fn smith_recip( &self, recip: fn(&T) -> T ) -> Self
+    {
+        if self.im.mixed_abs() <= self.re.mixed_abs()
+        {
+            if self.re == T::mixed_zero()
+            {
+                return Cartesian::new(recip(&self.re), T::mixed_zero());
+            }
+            let ratio = self.im/self.re;
+            let scale = Self::smith_scale(self.re, self.im*ratio, recip);
+            return Cartesian::new(scale, -(ratio*scale));
+        }
+        let ratio = self.re/self.im;
+        let scale = Self::smith_scale(self.im, self.re*ratio, recip);
+        return Cartesian::new(ratio*scale, -scale);
+    }
+
+    /// The reciprocal of `larger + smaller`, where the magnitude of `smaller` is at most that of `larger`.
+    /// 
+    /// Above half the range the sum would overflow, so both terms are halved and the reciprocal is halved to compensate.
+    fn smith_scale( larger: T, smaller: T, recip: fn(&T) -> T ) -> T
+    {
+        let two = T::mixed_from_num(2i32);
+        if larger.mixed_abs() <= T::mixed_max_value()/two
+        {
+            return recip(&(larger + smaller));
+        }
+        return recip(&(larger/two + smaller/two))/two;
+    }
+}
+
+impl <T: MixedReal + MixedNumSigned + MixedZero + MixedAbs + MixedRecip + MixedOps> MixedRecip for Cartesian<T>
+{
+    /// The reciprocal, `conj(z)/|z|²`, by Smith's method.
+    /// 
+    /// The reciprocal of zero is the real reciprocal of zero.
+    fn mixed_recip(&self) -> Self {
+        return self.smith_recip(T::mixed_recip);
+    }
+    /// The reciprocal by Smith's method, with the fast real reciprocal.
+    fn mixed_recip_fast(&self) -> Self {
+        return self.smith_recip(T::mixed_recip_fast);
+    }
+}
+
 impl <T: MixedNum + MixedZero> MixedComplexConversion<T>  for Cartesian<T>
 {
     /// Type cast from real number T to Complex<T>.
@@ -79,9 +293,9 @@ impl <T: MixedNum + MixedZero> MixedComplexConversion<T>  for Cartesian<T>
     }
 }
 
-impl <T: MixedNum + MixedNumSigned + MixedSqrt + MixedPowi + MixedOps> Mag<T> for Cartesian<T>
+impl <T: MixedNum + MixedNumSigned + MixedHypot + MixedOps> Mag<T> for Cartesian<T>
 {
-    /// Magnitude of the complex number.
+    /// Magnitude of the complex number, by `MixedHypot` without overflow of the squared parts.
     ///  
     /// ```
     /// use mixed_num::*;
@@ -97,7 +311,7 @@ impl <T: MixedNum + MixedNumSigned + MixedSqrt + MixedPowi + MixedOps> Mag<T> fo
     #[inline(always)]
     fn mag( &self ) -> T
     {
-        return (self.re.mixed_powi(2)+self.im.mixed_powi(2)).mixed_sqrt();
+        return self.re.mixed_hypot(self.im);
     }
     /// Magnitude of the complex number.
     /// 
@@ -117,9 +331,26 @@ impl <T: MixedNum + MixedNumSigned + MixedSqrt + MixedPowi + MixedOps> Mag<T> fo
     {
         return self.mag();
     }
+    /// A fast estimate of the magnitude, by the alpha max plus beta min method of `MixedHypot`, with an error below 1.3%.
+    /// 
+    /// ```
+    /// use mixed_num::*;
+    /// use mixed_num::traits::*;
+    /// use fixed::{types::extra::U28, FixedI32};
+    ///
+    /// let z = Cartesian::new( FixedI32::<U28>::from_num(-3), FixedI32::<U28>::from_num(3) );
+    /// let estimate = z.mag_fast().to_num::<f64>();
+    /// 
+    /// assert!{ (estimate-18f64.sqrt()).abs() < 18f64.sqrt()*0.013 };
+    /// ```
+    #[inline(always)]
+    fn mag_fast( &self ) -> T
+    {
+        return self.re.mixed_hypot_fast(self.im);
+    }
 }
 
-impl <T: MixedNum + MixedNumSigned + MixedSqrt + MixedPowi + MixedOps + MixedZero> MixedAbs for Cartesian<T>
+impl <T: MixedNum + MixedNumSigned + MixedHypot + MixedOps + MixedZero> MixedAbs for Cartesian<T>
 {   
     fn mixed_abs( &self ) -> Self
     {
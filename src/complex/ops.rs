@@ -7,7 +7,7 @@ use super::*;
 /// * `x` - The number transform.
 ///
 pub fn to_polar<T>( x: Cartesian<T> ) -> Polar<T>
-    where T:  MixedNum + MixedNumSigned + MixedSqrt + MixedAtan + MixedPowi + MixedAbs + MixedOps
+    where T:  MixedNum + MixedNumSigned + MixedHypot + MixedAtan + MixedAbs + MixedOps
 {
     let c_polar = Polar::<T>{
         mag:     abs(x),
@@ -18,6 +18,8 @@ pub fn to_polar<T>( x: Cartesian<T> ) -> Polar<T>
 
 /// Calculate the absolute value of the argument.
 /// 
+/// Uses `MixedHypot`, so fixed-point values do not overflow when the squared parts are out of range.
+/// 
 /// ## Arguments
 /// 
 /// * `a` - The argument to apply the function to.
@@ -33,10 +35,9 @@ pub fn to_polar<T>( x: Cartesian<T> ) -> Polar<T>
 /// ``` 
 /// 
 pub fn abs<T>( a: Cartesian<T> ) -> T
-where T: MixedNum + MixedNumSigned + MixedSqrt + MixedPowi + MixedOps
+where T: MixedNum + MixedNumSigned + MixedHypot + MixedOps
 {
-    let r_sqr = a.re.mixed_powi(2) + a.im.mixed_powi(2);
-    return r_sqr.mixed_sqrt();
+    return a.re.mixed_hypot(a.im);
 }
 
 /// Cast cartesian complex fixed point number to polar form.
@@ -150,7 +151,7 @@ pub fn mul_cartesian<T>( ab: Cartesian<T>, bc: Cartesian<T> ) -> Cartesian<T>
 /// let x = Cartesian::new( FixedI32::<U22>::from_num(1f32), FixedI32::<U22>::from_num(1f32) );
 /// let y = powi( x, 2 );
 /// 
-/// let result = Cartesian::new( FixedI32::<U22>::from_num( -0.0000038f32, ), FixedI32::<U22>::from_num( 1.996035f32 ));
+/// let result = Cartesian::new( FixedI32::<U22>::from_num( -0.000004f32, ), FixedI32::<U22>::from_num( 1.9999995f32 ));
 /// assert_eq!{ y, result };
 /// ```
 /// 
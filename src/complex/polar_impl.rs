@@ -8,15 +8,15 @@ impl <T: MixedNum + MixedNumSigned> MixedComplex for Polar<T>
 {
 }
 
-impl<T> Polar<T>
+impl<T, A> Polar<T, A>
 {
-    pub fn new(mag:T, ang:T) -> Self
+    pub fn new(mag:T, ang:A) -> Self
     {
         return Polar{mag:mag, ang:ang};
     }
 }
 
-impl <T: MixedNum + MixedNumSigned + MixedSqrt + MixedAtan + MixedPowi + MixedAbs + MixedOps > NewFromCartesian<T> for Polar<T>
+impl <T: MixedNum + MixedNumSigned + MixedHypot + MixedAtan + MixedAbs + MixedOps > NewFromCartesian<T> for Polar<T>
 {
     /// Type cast from real number T to Cartesian<T>.
     fn new_from_cartesian( re:T, im:T ) -> Self
@@ -105,6 +105,12 @@ impl <T: MixedNum + MixedNumSigned> Mag<T> for Polar<T>
     {
         return self.mag;
     }
+    /// Magnitude of the complex number, which is exact in polar form.
+    #[inline(always)]
+    fn mag_fast( &self ) -> T
+    {
+        return self.mag;
+    }
 }
 
 impl <T: MixedNum + MixedNumSigned + MixedZero> MixedAbs for Polar<T>
@@ -141,7 +147,7 @@ impl <T: MixedNum + MixedNumSigned + MixedOps> core::ops::Mul<Polar<T>> for Pola
     }
 }
 
-impl <T: MixedNum + MixedNumSigned + MixedSqrt + MixedOps + MixedAbs + MixedPowi + MixedAtan + ToPolar<T>> core::ops::Mul<Cartesian<T>> for Polar<T> {
+impl <T: MixedNum + MixedNumSigned + MixedHypot + MixedOps + MixedAbs + MixedAtan> core::ops::Mul<Cartesian<T>> for Polar<T> {
     type Output = Self;
     #[inline]
     fn mul(self, rhs: Cartesian<T>) -> Self {
@@ -177,9 +183,10 @@ impl <T: MixedComplex + NewFromPolar<T2>, T2: MixedNum + MixedNumSigned> Conj<T>
     }
 }
 
-impl<T> core::fmt::Display for Polar<T>
+impl<T, A> core::fmt::Display for Polar<T, A>
 where
     T: core::fmt::Display,
+    A: core::fmt::Display,
 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{}∠{}", self.mag, self.ang)
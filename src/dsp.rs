@@ -0,0 +1,33 @@
+//! Digital signal processing building blocks, implemented generically over the mixed number traits.
+//! 
+//! Frequencies are normalized, in radians per sample. 
+
+use super::*;
+
+mod nco;
+pub use nco::*;
+
+mod mixer;
+pub use mixer::*;
+
+mod fft;
+pub use fft::*;
+
+mod rfft;
+pub use rfft::*;
+
+mod dct;
+pub use dct::*;
+
+mod goertzel;
+pub use goertzel::*;
+
+mod fir;
+pub use fir::*;
+
+mod biquad;
+pub use biquad::*;
+
+pub mod design;
+
+pub mod window;
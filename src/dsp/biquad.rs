@@ -0,0 +1,517 @@
+use super::*;
+
+/// Coefficients of a second order section, normalized to `a0 = 1`.
+///
+/// `H(z) = (b0 + b1*z^-1 + b2*z^-2)/(1 + a1*z^-1 + a2*z^-2)`
+///
+/// The designers follow the RBJ audio EQ cookbook, with the frequency `ω0` in radians per sample, in the 0<x<π range.
+/// For fixed-point types, the designs with a gain use `MixedPow`, which is only implemented for floats.
+/// Design these in `f64`, and quantize the result with `quantize`.
+///
+/// ## Example
+///
+/// ```
+/// use mixed_num::*;
+/// use mixed_num::dsp::*;
+/// use fixed::{types::extra::U29, FixedI32};
+///
+/// let design = BiquadCoefficients::<f64>::peaking( 0.3f64, 2f64, 6f64 );
+/// assert!{ (design.frequency_response(0.3f64).mag()-10f64.powf(6f64/20f64)).abs() < 1e-12 };
+///
+/// let quantized = design.quantize::<FixedI32<U29>>();
+/// assert!{ (quantized.frequency_response(0.3f64).mag()-10f64.powf(6f64/20f64)).abs() < 1e-6 };
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct BiquadCoefficients<T> {
+    pub b0: T,
+    pub b1: T,
+    pub b2: T,
+    pub a1: T,
+    pub a2: T,
+}
+
+impl <T> BiquadCoefficients<T>
+    where T: MixedNumConversion<f64> + Copy
+{
+    /// Create coefficients from an `f64` design, normalizing by `a0`.
+    /// The coefficients are rounded to the nearest value of `T` for fixed-point types.
+    ///
+    /// ## Arguments
+    ///
+    /// * `b` - The numerator coefficients `[b0, b1, b2]`.
+    /// * `a` - The denominator coefficients `[a0, a1, a2]`.
+    pub fn from_f64( b: [f64; 3], a: [f64; 3] ) -> Self
+    {
+        return BiquadCoefficients{
+            b0: T::mixed_from_num(b[0]/a[0]),
+            b1: T::mixed_from_num(b[1]/a[0]),
+            b2: T::mixed_from_num(b[2]/a[0]),
+            a1: T::mixed_from_num(a[1]/a[0]),
+            a2: T::mixed_from_num(a[2]/a[0]),
+        };
+    }
+
+    /// Convert the coefficients to another type, via `f64`.
+    ///
+    /// Used to quantize a floating-point design to a fixed-point type.
+    /// The magnitude of `a1` is up to two, and the numerator coefficients can be larger for designs with gain, which the integer bits of `T2` must hold.
+    pub fn quantize<T2>( &self ) -> BiquadCoefficients<T2>
+        where T2: MixedNumConversion<f64>
+    {
+        return BiquadCoefficients{
+            b0: T2::mixed_from_num(self.b0.mixed_to_num()),
+            b1: T2::mixed_from_num(self.b1.mixed_to_num()),
+            b2: T2::mixed_from_num(self.b2.mixed_to_num()),
+            a1: T2::mixed_from_num(self.a1.mixed_to_num()),
+            a2: T2::mixed_from_num(self.a2.mixed_to_num()),
+        };
+    }
+
+    /// The frequency response `H(e^(jω))` of the coefficients, calculated in `f64`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `frequency` - The normalized frequency `ω`, in radians per sample.
+    pub fn frequency_response( &self, frequency: f64 ) -> Cartesian<f64>
+    {
+        let polynomial = |c0: f64, c1: f64, c2: f64| -> Cartesian<f64> {
+            return Cartesian::new( c0 + c1*libm::cos(frequency) + c2*libm::cos(2f64*frequency),
+                                  -c1*libm::sin(frequency) - c2*libm::sin(2f64*frequency) );
+        };
+        let numerator   = polynomial(self.b0.mixed_to_num(), self.b1.mixed_to_num(), self.b2.mixed_to_num());
+        let denominator = polynomial(1f64, self.a1.mixed_to_num(), self.a2.mixed_to_num());
+
+        let scale = 1f64/(denominator.re*denominator.re + denominator.im*denominator.im);
+        let h = complex::mul_cartesian(numerator, Cartesian::new(denominator.re, -denominator.im));
+        return Cartesian::new(h.re*scale, h.im*scale);
+    }
+}
+
+impl <T> BiquadCoefficients<T>
+    where T: MixedReal + MixedNumSigned + MixedOps + MixedSin + MixedZero + MixedOne
+{
+    /// Normalize a design by `a0`.
+    fn normalized( b0: T, b1: T, b2: T, a0: T, a1: T, a2: T ) -> Self
+    {
+        return BiquadCoefficients{ b0: b0/a0, b1: b1/a0, b2: b2/a0, a1: a1/a0, a2: a2/a0 };
+    }
+
+    /// `(sin(ω0), cos(ω0), alpha)`, where `alpha = sin(ω0)/(2*Q)`.
+    fn prototype( frequency: T, q: T ) -> (T, T, T)
+    {
+        let (sin, cos) = frequency.mixed_sincos();
+        return (sin, cos, sin/(q+q));
+    }
+
+    /// Second order lowpass filter.
+    ///
+    /// ## Arguments
+    ///
+    /// * `frequency` - The cutoff frequency `ω0`, in radians per sample.
+    /// * `q`         - The quality factor, `1/√2` for a Butterworth response.
+    pub fn lowpass( frequency: T, q: T ) -> Self
+    {
+        let (_, cos, alpha) = Self::prototype(frequency, q);
+        let one = T::mixed_one();
+        let b1 = one-cos;
+        let b0 = b1*T::mixed_from_num(0.5f64);
+        return Self::normalized( b0, b1, b0, one+alpha, -(cos+cos), one-alpha );
+    }
+
+    /// Second order highpass filter.
+    ///
+    /// ## Arguments
+    ///
+    /// * `frequency` - The cutoff frequency `ω0`, in radians per sample.
+    /// * `q`         - The quality factor, `1/√2` for a Butterworth response.
+    pub fn highpass( frequency: T, q: T ) -> Self
+    {
+        let (_, cos, alpha) = Self::prototype(frequency, q);
+        let one = T::mixed_one();
+        let b1 = one+cos;
+        let b0 = b1*T::mixed_from_num(0.5f64);
+        return Self::normalized( b0, -b1, b0, one+alpha, -(cos+cos), one-alpha );
+    }
+
+    /// Second order bandpass filter, with a peak gain of 0 dB.
+    ///
+    /// ## Arguments
+    ///
+    /// * `frequency` - The center frequency `ω0`, in radians per sample.
+    /// * `q`         - The quality factor, the center frequency divided by the bandwidth.
+    pub fn bandpass( frequency: T, q: T ) -> Self
+    {
+        let (_, cos, alpha) = Self::prototype(frequency, q);
+        let one = T::mixed_one();
+        return Self::normalized( alpha, T::mixed_zero(), -alpha, one+alpha, -(cos+cos), one-alpha );
+    }
+
+    /// Second order notch filter.
+    ///
+    /// ## Arguments
+    ///
+    /// * `frequency` - The notch frequency `ω0`, in radians per sample.
+    /// * `q`         - The quality factor, the notch frequency divided by the bandwidth.
+    pub fn notch( frequency: T, q: T ) -> Self
+    {
+        let (_, cos, alpha) = Self::prototype(frequency, q);
+        let one = T::mixed_one();
+        return Self::normalized( one, -(cos+cos), one, one+alpha, -(cos+cos), one-alpha );
+    }
+
+    /// Peaking equalizer.
+    ///
+    /// ## Arguments
+    ///
+    /// * `frequency` - The center frequency `ω0`, in radians per sample.
+    /// * `q`         - The quality factor.
+    /// * `gain_db`   - The gain at the center frequency, in dB.
+    pub fn peaking( frequency: T, q: T, gain_db: T ) -> Self
+        where T: MixedPow
+    {
+        let (_, cos, alpha) = Self::prototype(frequency, q);
+        let one = T::mixed_one();
+        let a = T::mixed_from_num(10f64).mixed_pow(gain_db/T::mixed_from_num(40f64));
+        return Self::normalized( one+alpha*a, -(cos+cos), one-alpha*a, one+alpha/a, -(cos+cos), one-alpha/a );
+    }
+
+    /// Low shelf filter.
+    ///
+    /// ## Arguments
+    ///
+    /// * `frequency` - The midpoint frequency `ω0` of the shelf transition, in radians per sample.
+    /// * `q`         - The quality factor, `1/√2` for the steepest transition without overshoot.
+    /// * `gain_db`   - The gain at DC, in dB.
+    pub fn low_shelf( frequency: T, q: T, gain_db: T ) -> Self
+        where T: MixedPow
+    {
+        let (_, cos, alpha) = Self::prototype(frequency, q);
+        let (a, beta) = shelf_gain(alpha, gain_db);
+        let one = T::mixed_one();
+        let two = T::mixed_from_num(2f64);
+        return Self::normalized(
+                a*((a+one) - (a-one)*cos + beta),
+                two*a*((a-one) - (a+one)*cos),
+                a*((a+one) - (a-one)*cos - beta),
+                (a+one) + (a-one)*cos + beta,
+                -two*((a-one) + (a+one)*cos),
+                (a+one) + (a-one)*cos - beta );
+    }
+
+    /// High shelf filter.
+    ///
+    /// ## Arguments
+    ///
+    /// * `frequency` - The midpoint frequency `ω0` of the shelf transition, in radians per sample.
+    /// * `q`         - The quality factor, `1/√2` for the steepest transition without overshoot.
+    /// * `gain_db`   - The gain at the Nyquist frequency, in dB.
+    pub fn high_shelf( frequency: T, q: T, gain_db: T ) -> Self
+        where T: MixedPow
+    {
+        let (_, cos, alpha) = Self::prototype(frequency, q);
+        let (a, beta) = shelf_gain(alpha, gain_db);
+        let one = T::mixed_one();
+        let two = T::mixed_from_num(2f64);
+        return Self::normalized(
+                a*((a+one) + (a-one)*cos + beta),
+                -two*a*((a-one) + (a+one)*cos),
+                a*((a+one) + (a-one)*cos - beta),
+                (a+one) - (a-one)*cos + beta,
+                two*((a-one) - (a+one)*cos),
+                (a+one) - (a-one)*cos - beta );
+    }
+}
+
+/// `(A, 2*√A*alpha)` of the shelf filters, where `A = 10^(gain_db/40)`.
+fn shelf_gain<T>( alpha: T, gain_db: T ) -> (T, T)
+    where T: MixedReal + MixedOps + MixedPow
+{
+    let ten = T::mixed_from_num(10f64);
+    let a      = ten.mixed_pow(gain_db/T::mixed_from_num(40f64));
+    let sqrt_a = ten.mixed_pow(gain_db/T::mixed_from_num(80f64));
+    return (a, (sqrt_a+sqrt_a)*alpha);
+}
+
+/// Structure of a second order section.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum BiquadForm {
+    /// Direct Form I. The input and output history is stored in the sample type,
+    /// and the sum is formed in the accumulator type, with a single narrowing per output sample.
+    /// The history is limited to the range of the sample type, but the partial sums of the accumulator are not.
+    /// They reach `(|b0|+|b1|+|b2|+|a1|+|a2|)` times the largest sample magnitude, about 3 for a narrow lowpass section where `a1` is close to -2 and `a2` to 1,
+    /// and fixed-point accumulators must have the integer bits for it, as overflow panics in debug builds.
+    DirectForm1,
+    /// Transposed Direct Form II. Two states are stored in the accumulator type.
+    /// Requires half the state of Direct Form I, and has good numerical properties for floats,
+    /// but the states can exceed the output range for fixed-point types.
+    TransposedDirectForm2,
+}
+
+/// Feedback of the output quantization error in Direct Form I.
+///
+/// The error of narrowing the accumulator to the sample type is added to the following accumulations,
+/// which shapes the spectrum of the quantization noise with zeros at DC.
+/// This cancels the amplification of the noise by poles close to DC, as in narrow lowpass filters.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ErrorFeedback {
+    /// No error feedback.
+    None,
+    /// Noise shaping by `1-z^-1`.
+    FirstOrder,
+    /// Noise shaping by `(1-z^-1)^2`.
+    SecondOrder,
+}
+
+/// Second order IIR filter section, with samples of type `T`, and an accumulator of type `A`.
+///
+/// The accumulator defaults to the sample type.
+/// For fixed-point types, a wider accumulator with more fractional bits reduces the quantization noise,
+/// and enables error feedback, see `ErrorFeedback`.
+///
+/// ## Example
+///
+/// ```
+/// use mixed_num::*;
+/// use mixed_num::dsp::*;
+/// use fixed::{types::extra::{U13, U26}, FixedI16, FixedI32};
+///
+/// let coeffs = BiquadCoefficients::<f64>::lowpass( 0.05f64, core::f64::consts::FRAC_1_SQRT_2 ).quantize();
+/// let mut filter = Biquad::<FixedI16<U13>, FixedI32<U26>>::new( coeffs, BiquadForm::DirectForm1 );
+/// filter.set_error_feedback(ErrorFeedback::FirstOrder);
+///
+/// let mut y = FixedI16::<U13>::from_num(0);
+/// for _ in 0..500 {
+///     y = filter.process_sample( FixedI16::<U13>::from_num(0.5f32) );
+/// }
+/// assert!{ (y.to_num::<f32>()-0.5f32).abs() < 1e-3 };
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct Biquad<T, A = T> {
+    coeffs: BiquadCoefficients<T>,
+    /// The coefficients in the accumulator type.
+    coeffs_a: BiquadCoefficients<A>,
+    form: BiquadForm,
+    error_feedback: ErrorFeedback,
+    /// Input history of Direct Form I, newest first.
+    x: [T; 2],
+    /// Output history of Direct Form I, newest first.
+    y: [T; 2],
+    /// States of Transposed Direct Form II.
+    s: [A; 2],
+    /// Quantization error history, newest first.
+    e: [A; 2],
+}
+
+impl <T, A> Biquad<T, A>
+    where T: MixedReal + MixedOps + MixedZero,
+          A: MixedNum + MixedOps + MixedZero + MixedNumConversion<T>
+{
+    /// Create a filter section with cleared state, and no error feedback.
+    pub fn new( coeffs: BiquadCoefficients<T>, form: BiquadForm ) -> Self
+    {
+        let coeffs_a = BiquadCoefficients{
+            b0: A::mixed_from_num(coeffs.b0),
+            b1: A::mixed_from_num(coeffs.b1),
+            b2: A::mixed_from_num(coeffs.b2),
+            a1: A::mixed_from_num(coeffs.a1),
+            a2: A::mixed_from_num(coeffs.a2),
+        };
+        return Biquad{
+            coeffs,
+            coeffs_a,
+            form,
+            error_feedback: ErrorFeedback::None,
+            x: [T::mixed_zero(); 2],
+            y: [T::mixed_zero(); 2],
+            s: [A::mixed_zero(); 2],
+            e: [A::mixed_zero(); 2],
+        };
+    }
+
+    /// Set the error feedback. Only used in Direct Form I, where the recursion uses the narrowed output.
+    pub fn set_error_feedback( &mut self, error_feedback: ErrorFeedback )
+    {
+        self.error_feedback = error_feedback;
+    }
+
+    /// The error feedback of the section.
+    pub fn error_feedback( &self ) -> ErrorFeedback
+    {
+        return self.error_feedback;
+    }
+
+    /// The coefficients of the section.
+    pub fn coeffs( &self ) -> BiquadCoefficients<T>
+    {
+        return self.coeffs;
+    }
+
+    /// The structure of the section.
+    pub fn form( &self ) -> BiquadForm
+    {
+        return self.form;
+    }
+
+    /// Clear the state of the section.
+    pub fn reset( &mut self )
+    {
+        self.x = [T::mixed_zero(); 2];
+        self.y = [T::mixed_zero(); 2];
+        self.s = [A::mixed_zero(); 2];
+        self.e = [A::mixed_zero(); 2];
+    }
+
+    /// Filter one sample.
+    #[inline(always)]
+    pub fn process_sample( &mut self, x: T ) -> T
+    {
+        let c = &self.coeffs_a;
+        let x_a = A::mixed_from_num(x);
+
+        match self.form {
+            BiquadForm::DirectForm1 => {
+                let mut acc = c.b0*x_a + c.b1*A::mixed_from_num(self.x[0]) + c.b2*A::mixed_from_num(self.x[1])
+                            - c.a1*A::mixed_from_num(self.y[0]) - c.a2*A::mixed_from_num(self.y[1]);
+                match self.error_feedback {
+                    ErrorFeedback::None        => {},
+                    ErrorFeedback::FirstOrder  => acc = acc + self.e[0],
+                    ErrorFeedback::SecondOrder => acc = acc + self.e[0] + self.e[0] - self.e[1],
+                }
+
+                let y: T = acc.mixed_to_num();
+                self.e = [acc - A::mixed_from_num(y), self.e[0]];
+                self.x = [x, self.x[0]];
+                self.y = [y, self.y[0]];
+                return y;
+            },
+            BiquadForm::TransposedDirectForm2 => {
+                let acc = c.b0*x_a + self.s[0];
+                self.s[0] = c.b1*x_a - c.a1*acc + self.s[1];
+                self.s[1] = c.b2*x_a - c.a2*acc;
+                return acc.mixed_to_num();
+            },
+        }
+    }
+
+    /// Filter a block of samples.
+    ///
+    /// Processes the first `min(input.len(), output.len())` samples.
+    pub fn process( &mut self, input: &[T], output: &mut [T] )
+    {
+        for (x, y) in input.iter().zip(output.iter_mut())
+        {
+            *y = self.process_sample(*x);
+        }
+    }
+
+    /// Filter a block of samples in place.
+    pub fn process_in_place( &mut self, buffer: &mut [T] )
+    {
+        for x in buffer.iter_mut()
+        {
+            *x = self.process_sample(*x);
+        }
+    }
+
+    /// The frequency response of the section, see `BiquadCoefficients::frequency_response`.
+    pub fn frequency_response( &self, frequency: f64 ) -> Cartesian<f64>
+    {
+        return self.coeffs.frequency_response(frequency);
+    }
+}
+
+/// Cascade of `N` second order sections, for higher order IIR filters.
+///
+/// ## Example
+///
+/// ```
+/// use mixed_num::*;
+/// use mixed_num::dsp::*;
+///
+/// // Fourth order Linkwitz-Riley lowpass.
+/// let section = BiquadCoefficients::<f32>::lowpass( 0.2f32, core::f32::consts::FRAC_1_SQRT_2 );
+/// let filter = BiquadCascade::<f32, f32, 2>::new( [section; 2], BiquadForm::TransposedDirectForm2 );
+/// assert!{ (filter.frequency_response(0.2f64).mag()-0.5f64).abs() < 1e-6 };
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct BiquadCascade<T, A, const N: usize> {
+    sections: [Biquad<T, A>; N],
+}
+
+impl <T, A, const N: usize> BiquadCascade<T, A, N>
+    where T: MixedReal + MixedOps + MixedZero,
+          A: MixedNum + MixedOps + MixedZero + MixedNumConversion<T>
+{
+    /// Create a cascade of sections with cleared state, and no error feedback.
+    pub fn new( coeffs: [BiquadCoefficients<T>; N], form: BiquadForm ) -> Self
+    {
+        return BiquadCascade{ sections: coeffs.map( |c| Biquad::new(c, form) ) };
+    }
+
+    /// Set the error feedback of all sections.
+    pub fn set_error_feedback( &mut self, error_feedback: ErrorFeedback )
+    {
+        for section in self.sections.iter_mut()
+        {
+            section.set_error_feedback(error_feedback);
+        }
+    }
+
+    /// The sections of the cascade, in processing order.
+    pub fn sections( &self ) -> &[Biquad<T, A>; N]
+    {
+        return &self.sections;
+    }
+
+    /// Clear the state of all sections.
+    pub fn reset( &mut self )
+    {
+        for section in self.sections.iter_mut()
+        {
+            section.reset();
+        }
+    }
+
+    /// Filter one sample.
+    #[inline(always)]
+    pub fn process_sample( &mut self, x: T ) -> T
+    {
+        let mut y = x;
+        for section in self.sections.iter_mut()
+        {
+            y = section.process_sample(y);
+        }
+        return y;
+    }
+
+    /// Filter a block of samples.
+    ///
+    /// Processes the first `min(input.len(), output.len())` samples.
+    pub fn process( &mut self, input: &[T], output: &mut [T] )
+    {
+        for (x, y) in input.iter().zip(output.iter_mut())
+        {
+            *y = self.process_sample(*x);
+        }
+    }
+
+    /// Filter a block of samples in place.
+    pub fn process_in_place( &mut self, buffer: &mut [T] )
+    {
+        for x in buffer.iter_mut()
+        {
+            *x = self.process_sample(*x);
+        }
+    }
+
+    /// The frequency response of the cascade, the product of the section responses.
+    pub fn frequency_response( &self, frequency: f64 ) -> Cartesian<f64>
+    {
+        let mut h = Cartesian::new(1f64, 0f64);
+        for section in self.sections.iter()
+        {
+            h = complex::mul_cartesian(h, section.frequency_response(frequency));
+        }
+        return h;
+    }
+}
@@ -0,0 +1,189 @@
+use super::*;
+use super::rfft::conj;
+
+/// Discrete cosine transforms of size `N`, computed with an `N` point complex FFT.
+///
+/// The transforms are unnormalized:
+///
+/// * DCT-II:  `X[k] = Σ x[n]*cos(π*(2n+1)*k/(2N))`
+/// * DCT-III: `x[n] = X[0]/2 + Σ_(k>0) X[k]*cos(π*(2n+1)*k/(2N))`
+/// * DCT-IV:  `X[k] = Σ x[n]*cos(π*(2n+1)*(2k+1)/(4N))`
+///
+/// DCT-III is the inverse of DCT-II, and DCT-IV is its own inverse, both up to a factor `N/2`.
+///
+/// The scaling options are those of `Fft`, where `FftScaling::PerStage` divides the output by `N`.
+/// The caller provides a scratch buffer of `N` complex samples.
+///
+/// ## Example
+///
+/// ```
+/// use mixed_num::*;
+/// use mixed_num::dsp::*;
+///
+/// let dct = Dct::<f64, 4>::new().unwrap();
+/// let mut scratch = [Cartesian::new(0f64, 0f64); 4];
+///
+/// let x = [1f64, 2f64, 3f64, 4f64];
+/// let mut y = [0f64; 4];
+/// let mut z = [0f64; 4];
+///
+/// dct.dct2(&x, &mut y, &mut scratch, FftScaling::None);
+/// assert!{ (y[0]-10f64).abs() < 1e-12 };
+///
+/// dct.dct3(&y, &mut z, &mut scratch, FftScaling::PerStage);
+/// for (x_, z_) in x.iter().zip(z.iter()) {
+///     assert!{ (x_/2f64-z_).abs() < 1e-12 };
+/// }
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct Dct<T, const N: usize> {
+    fft: Fft<T, N>,
+    /// `W_4N^k = e^(-j*π*k/(2N))` for `k` in `0..N`.
+    quarter_twiddles: [Cartesian<T>; N],
+    /// `e^(-j*π*(2n+1)/(4N))` for `n` in `0..N`.
+    dct4_twiddles: [Cartesian<T>; N],
+}
+
+impl <T, const N: usize> Dct<T, N>
+    where T: MixedReal + MixedNumSigned + MixedSin + MixedOps + MixedZero + MixedAbs
+{
+    /// Create the transforms, and precompute the twiddle factors.
+    ///
+    /// Returns `None` if `N` is not supported by `Fft<T, N>`.
+    pub fn new() -> Option<Self>
+    {
+        let fft = Fft::<T, N>::new()?;
+
+        let mut quarter_twiddles = [Cartesian::new(T::mixed_zero(), T::mixed_zero()); N];
+        let mut dct4_twiddles    = [Cartesian::new(T::mixed_zero(), T::mixed_zero()); N];
+        for k in 0..N
+        {
+            let angle = -core::f64::consts::PI*(k as f64)/(2*N) as f64;
+            let (sin, cos) = T::mixed_from_num(angle).mixed_sincos();
+            quarter_twiddles[k] = Cartesian::new(cos, sin);
+
+            let angle = -core::f64::consts::PI*((2*k+1) as f64)/(4*N) as f64;
+            let (sin, cos) = T::mixed_from_num(angle).mixed_sincos();
+            dct4_twiddles[k] = Cartesian::new(cos, sin);
+        }
+
+        return Some(Dct{ fft, quarter_twiddles, dct4_twiddles });
+    }
+
+    /// Compute the DCT-II of `input`.
+    ///
+    /// Returns the block floating point exponent, which is zero unless `FftScaling::BlockFloatingPoint` is used.
+    ///
+    /// ## Arguments
+    ///
+    /// * `input`   - The `N` input samples.
+    /// * `output`  - The `N` output samples.
+    /// * `scratch` - Scratch buffer of `N` complex samples.
+    /// * `scaling` - Scaling of the intermediate results.
+    ///
+    /// Panics if any of the buffer lengths differ from `N`.
+    pub fn dct2( &self, input: &[T], output: &mut [T], scratch: &mut [Cartesian<T>], scaling: FftScaling ) -> i32
+    {
+        check_lengths::<T, N>(input, output, scratch);
+
+        for (n, x) in input.iter().enumerate()
+        {
+            scratch[dct2_index::<N>(n)] = Cartesian::new(*x, T::mixed_zero());
+        }
+        return self.dct2_scratch(output, scratch, scaling);
+    }
+
+    /// DCT-II of the reordered input in `scratch`, i.e. the even samples in order, followed by the odd samples reversed.
+    fn dct2_scratch( &self, output: &mut [T], scratch: &mut [Cartesian<T>], scaling: FftScaling ) -> i32
+    {
+        let exponent = self.fft.fft(scratch, scaling);
+
+        for (k, y) in output.iter_mut().enumerate()
+        {
+            *y = complex::mul_cartesian(self.quarter_twiddles[k], scratch[k]).re;
+        }
+        return exponent;
+    }
+
+    /// Compute the DCT-III of `input`.
+    ///
+    /// Returns the block floating point exponent, which is zero unless `FftScaling::BlockFloatingPoint` is used.
+    ///
+    /// ## Arguments
+    ///
+    /// * `input`   - The `N` input samples.
+    /// * `output`  - The `N` output samples.
+    /// * `scratch` - Scratch buffer of `N` complex samples.
+    /// * `scaling` - Scaling of the intermediate results.
+    ///
+    /// Panics if any of the buffer lengths differ from `N`.
+    pub fn dct3( &self, input: &[T], output: &mut [T], scratch: &mut [Cartesian<T>], scaling: FftScaling ) -> i32
+    {
+        check_lengths::<T, N>(input, output, scratch);
+
+        // V[k] = conj(W_4N^k)*(X[k] - j*X[N-k])/2, with X[N] = 0.
+        let half = T::mixed_from_num(0.5f64);
+        scratch[0] = Cartesian::new(input[0]*half, T::mixed_zero());
+        for k in 1..N
+        {
+            let x = Cartesian::new(input[k]*half, -input[N-k]*half);
+            scratch[k] = complex::mul_cartesian(conj(self.quarter_twiddles[k]), x);
+        }
+
+        let exponent = self.fft.ifft(scratch, scaling);
+
+        for (n, y) in output.iter_mut().enumerate()
+        {
+            *y = scratch[dct2_index::<N>(n)].re;
+        }
+        return exponent;
+    }
+
+    /// Compute the DCT-IV of `input`.
+    ///
+    /// The DCT-IV is calculated as the real part of a DCT-II of the complex input `x[n]*e^(-j*π*(2n+1)/(4N))`, with the odd samples conjugated.
+    /// Each output sample is formed independently, so the error is that of the DCT-II.
+    ///
+    /// Returns the block floating point exponent, which is zero unless `FftScaling::BlockFloatingPoint` is used.
+    ///
+    /// ## Arguments
+    ///
+    /// * `input`   - The `N` input samples.
+    /// * `output`  - The `N` output samples.
+    /// * `scratch` - Scratch buffer of `N` complex samples.
+    /// * `scaling` - Scaling of the intermediate results.
+    ///
+    /// Panics if any of the buffer lengths differ from `N`.
+    pub fn dct4( &self, input: &[T], output: &mut [T], scratch: &mut [Cartesian<T>], scaling: FftScaling ) -> i32
+    {
+        check_lengths::<T, N>(input, output, scratch);
+
+        // cos(π*(2n+1)*(2k+1)/(4N)) = cos(π*(2n+1)*k/(2N) + b[n]), with b[n] = π*(2n+1)/(4N).
+        // The DCT-II reordering conjugates the kernel of the odd samples, so their rotation by b[n] is conjugated to match.
+        for (n, x) in input.iter().enumerate()
+        {
+            let twiddle = self.dct4_twiddles[n];
+            let twiddle = if n % 2 == 0 { twiddle } else { conj(twiddle) };
+            scratch[dct2_index::<N>(n)] = Cartesian::new(*x*twiddle.re, *x*twiddle.im);
+        }
+        return self.dct2_scratch(output, scratch, scaling);
+    }
+}
+
+/// Position of sample `n` in the DCT-II reordering, the even samples in order, followed by the odd samples reversed.
+#[inline(always)]
+fn dct2_index<const N: usize>( n: usize ) -> usize
+{
+    if n % 2 == 0
+    {
+        return n/2;
+    }
+    return N-1-n/2;
+}
+
+fn check_lengths<T, const N: usize>( input: &[T], output: &[T], scratch: &[Cartesian<T>] )
+{
+    assert_eq!{ input.len(), N, "The input length must equal the DCT size." };
+    assert_eq!{ output.len(), N, "The output length must equal the DCT size." };
+    assert_eq!{ scratch.len(), N, "The scratch length must equal the DCT size." };
+}
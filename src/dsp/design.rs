@@ -0,0 +1,354 @@
+//! Filter designers, computing the coefficients in the target type.
+//!
+//! The designs are calculated with the mixed number traits of the coefficient type, and work without `std`.
+//! For fixed-point types, the intermediate values must fit the integer bits of the type, as noted for each designer.
+//! Alternatively, design in `f64`, and convert the coefficients.
+//!
+//! ## Example
+//!
+//! ```
+//! use mixed_num::*;
+//! use mixed_num::dsp::*;
+//! use mixed_num::dsp::design::*;
+//!
+//! // FIR lowpass with 60 dB stopband attenuation, and a transition band of 0.1π.
+//! let beta = kaiser_beta(60f32);
+//! assert!{ kaiser_length(60f32, 0.1f32*f32::mixed_pi()) <= 75 };
+//! let coeffs: [f32; 75] = fir_lowpass(0.3f32*f32::mixed_pi(), beta);
+//!
+//! let mut delay_line = [0f32; 75];
+//! let mut fir = Fir::<f32, f32, f32>::new(&coeffs, &mut delay_line);
+//!
+//! // Fourth order Butterworth lowpass.
+//! let sections: [BiquadCoefficients<f64>; 2] = butterworth_lowpass(4, 0.3f64*f64::mixed_pi());
+//! let iir = BiquadCascade::<f64, f64, 2>::new(sections, BiquadForm::DirectForm1);
+//! assert!{ (iir.frequency_response(0.3f64*f64::mixed_pi()).mag()-core::f64::consts::FRAC_1_SQRT_2).abs() < 1e-12 };
+//! ```
+
+use super::*;
+
+/// Kaiser window parameter `β` for a stopband attenuation in dB.
+///
+/// Uses the empirical formula by Kaiser, calculated in `f64`.
+pub fn kaiser_beta<T>( attenuation_db: T ) -> T
+    where T: MixedReal
+{
+    let a: f64 = attenuation_db.mixed_to_num();
+    let beta = if 50f64 < a {
+        0.1102f64*(a-8.7f64)
+    } else if 21f64 <= a {
+        0.5842f64*libm::pow(a-21f64, 0.4f64) + 0.07886f64*(a-21f64)
+    } else {
+        0f64
+    };
+    return T::mixed_from_num(beta);
+}
+
+/// Estimated number of taps of a Kaiser windowed-sinc filter.
+///
+/// ## Arguments
+///
+/// * `attenuation_db`   - The stopband attenuation in dB. The passband ripple is of the same relative size.
+/// * `transition_width` - The width of the transition band, in radians per sample.
+pub fn kaiser_length<T>( attenuation_db: T, transition_width: T ) -> usize
+    where T: MixedReal
+{
+    let a: f64  = attenuation_db.mixed_to_num();
+    let dw: f64 = transition_width.mixed_to_num();
+    return libm::ceil((a-7.95f64)/(2.285f64*dw)) as usize + 1;
+}
+
+/// Kaiser windowed impulse response of an ideal lowpass filter with unity gain at DC, before normalization.
+fn windowed_sinc<T, const N: usize>( cutoff: T, beta: T ) -> [T; N]
+    where T: MixedReal + MixedNumSigned + MixedOps + MixedZero + MixedOne + MixedPi + MixedSin + MixedSqrt + MixedWrapPhase + MixedBesselI0
+{
+    let mut h = [T::mixed_zero(); N];
+    let mut kaiser = [T::mixed_zero(); N];
+    window::kaiser(&mut kaiser, beta, window::WindowSymmetry::Symmetric);
+    // The first half, including the center tap of odd lengths, is mirrored for an exactly linear phase.
+    for n in 0..N.div_ceil(2)
+    {
+        // Offset from the center tap.
+        let t = T::mixed_from_num( (2f64*n as f64 - (N-1) as f64)/2f64 );
+        let sinc = if t == T::mixed_zero() {
+            cutoff/T::mixed_pi()
+        } else {
+            (cutoff*t).mixed_wrap_phase().mixed_sin()/(T::mixed_pi()*t)
+        };
+        h[n]     = sinc*kaiser[n];
+        h[N-1-n] = h[n];
+    }
+    return h;
+}
+
+/// Divide the coefficients by the gain at `frequency`, of the linear phase filter around the center tap.
+fn normalize<T, const N: usize>( h: &mut [T; N], frequency: T )
+    where T: MixedReal + MixedNumSigned + MixedOps + MixedZero + MixedSin + MixedWrapPhase
+{
+    let mut gain = T::mixed_zero();
+    for (n, h_) in h.iter().enumerate()
+    {
+        let t = T::mixed_from_num( (2f64*n as f64 - (N-1) as f64)/2f64 );
+        let (_, cos) = (frequency*t).mixed_wrap_phase().mixed_sincos();
+        gain += *h_*cos;
+    }
+    for h_ in h.iter_mut()
+    {
+        *h_ = *h_/gain;
+    }
+}
+
+/// Kaiser windowed-sinc lowpass FIR filter of `N` taps, with unity gain at DC.
+///
+/// For fixed-point types, the integer bits must hold `N*π/2`, and `I0(β)`, which is about 400 for β=8.
+///
+/// ## Arguments
+///
+/// * `cutoff` - The cutoff frequency, in radians per sample, in the 0<x<π range.
+/// * `beta`   - The Kaiser window parameter, see `kaiser_beta`. Zero yields a rectangular window.
+pub fn fir_lowpass<T, const N: usize>( cutoff: T, beta: T ) -> [T; N]
+    where T: MixedReal + MixedNumSigned + MixedOps + MixedZero + MixedOne + MixedPi + MixedSin + MixedSqrt + MixedWrapPhase + MixedBesselI0
+{
+    let mut h = windowed_sinc::<T, N>(cutoff, beta);
+    normalize(&mut h, T::mixed_zero());
+    return h;
+}
+
+/// Kaiser windowed-sinc highpass FIR filter of `N` taps, with unity gain at π.
+///
+/// Panics if `N` is even, as an even length linear phase filter has a zero at π.
+///
+/// ## Arguments
+///
+/// * `cutoff` - The cutoff frequency, in radians per sample, in the 0<x<π range.
+/// * `beta`   - The Kaiser window parameter, see `kaiser_beta`.
+pub fn fir_highpass<T, const N: usize>( cutoff: T, beta: T ) -> [T; N]
+    where T: MixedReal + MixedNumSigned + MixedOps + MixedZero + MixedOne + MixedPi + MixedSin + MixedSqrt + MixedWrapPhase + MixedBesselI0
+{
+    assert!{ N % 2 == 1, "A highpass filter must have an odd number of taps." };
+
+    let mut h = fir_lowpass::<T, N>(cutoff, beta);
+    for h_ in h.iter_mut()
+    {
+        *h_ = -*h_;
+    }
+    h[N/2] += T::mixed_one();
+    normalize(&mut h, T::mixed_pi());
+    return h;
+}
+
+/// Kaiser windowed-sinc bandpass FIR filter of `N` taps, with unity gain at the center of the passband.
+///
+/// ## Arguments
+///
+/// * `low`  - The lower cutoff frequency, in radians per sample, in the 0<x<π range.
+/// * `high` - The upper cutoff frequency, in radians per sample, in the low<x<π range.
+/// * `beta` - The Kaiser window parameter, see `kaiser_beta`.
+pub fn fir_bandpass<T, const N: usize>( low: T, high: T, beta: T ) -> [T; N]
+    where T: MixedReal + MixedNumSigned + MixedOps + MixedZero + MixedOne + MixedPi + MixedSin + MixedSqrt + MixedWrapPhase + MixedBesselI0
+{
+    let mut h = windowed_sinc::<T, N>(high, beta);
+    let h_low = windowed_sinc::<T, N>(low, beta);
+    for (h_, l) in h.iter_mut().zip(h_low.iter())
+    {
+        *h_ -= *l;
+    }
+    normalize(&mut h, (low+high)*T::mixed_from_num(0.5f64));
+    return h;
+}
+
+/// Bilinear transform `s = (1-z^-1)/(1+z^-1)` of the analog section `(b[0]*s^2 + b[1]*s + b[2])/(a[0]*s^2 + a[1]*s + a[2])`.
+fn bilinear<T>( b: [T; 3], a: [T; 3] ) -> BiquadCoefficients<T>
+    where T: MixedReal + MixedOps
+{
+    let two = T::mixed_from_num(2f64);
+    let a0 = a[0] + a[1] + a[2];
+    return BiquadCoefficients{
+        b0: (b[0] + b[1] + b[2])/a0,
+        b1: two*(b[2] - b[0])/a0,
+        b2: (b[0] - b[1] + b[2])/a0,
+        a1: two*(a[2] - a[0])/a0,
+        a2: (a[0] - a[1] + a[2])/a0,
+    };
+}
+
+/// Bilinear transform of the first order analog section `(b[0]*s + b[1])/(a[0]*s + a[1])`.
+fn bilinear_first_order<T>( b: [T; 2], a: [T; 2] ) -> BiquadCoefficients<T>
+    where T: MixedReal + MixedOps + MixedZero
+{
+    let a0 = a[0] + a[1];
+    return BiquadCoefficients{
+        b0: (b[0] + b[1])/a0,
+        b1: (b[1] - b[0])/a0,
+        b2: T::mixed_zero(),
+        a1: (a[1] - a[0])/a0,
+        a2: T::mixed_zero(),
+    };
+}
+
+/// Prototype of an all-pole analog lowpass filter with a cutoff of 1 rad/s.
+///
+/// The poles are `-σ*sin(θ_k) ± j*ω*cos(θ_k)`, for `θ_k = π*(2k+1)/(2*order)`.
+/// The real pole of odd orders is `-σ`.
+struct Prototype<T> {
+    sigma: T,
+    omega: T,
+    /// Gain of the filter at DC.
+    gain: T,
+}
+
+/// Transform the prototype to `S` digital sections, with the cutoff `frequency` in radians per sample.
+///
+/// The sections have unity gain at DC for `highpass = false`, and at π otherwise, except for the first section, which has the gain of the prototype.
+fn prototype_to_sections<T, const S: usize>( prototype: Prototype<T>, order: usize, frequency: T, highpass: bool ) -> [BiquadCoefficients<T>; S]
+    where T: MixedReal + MixedNumSigned + MixedOps + MixedZero + MixedOne + MixedSin
+{
+    assert!{ 0 < order, "The filter order must be positive." };
+    assert_eq!{ order.div_ceil(2), S, "The number of sections must be the order divided by two, rounded up." };
+
+    let zero = T::mixed_zero();
+    let one  = T::mixed_one();
+    let two  = T::mixed_from_num(2f64);
+
+    // Prewarped cutoff, tan(ω/2).
+    let (sin, cos) = (frequency*T::mixed_from_num(0.5f64)).mixed_sincos();
+    let wc = sin/cos;
+
+    let mut sections = [BiquadCoefficients{ b0: zero, b1: zero, b2: zero, a1: zero, a2: zero }; S];
+    for (k, section) in sections.iter_mut().enumerate()
+    {
+        if 2*k+1 == order
+        {
+            // Real pole s = -σ, with s -> s/wc or s -> wc/s.
+            let p = prototype.sigma;
+            *section = if highpass {
+                bilinear_first_order( [p, zero], [p, wc] )
+            } else {
+                bilinear_first_order( [zero, p*wc], [one, p*wc] )
+            };
+            continue;
+        }
+
+        let theta = T::mixed_from_num( core::f64::consts::PI*((2*k+1) as f64)/((2*order) as f64) );
+        let (sin, cos) = theta.mixed_sincos();
+        let re = prototype.sigma*sin;
+        let im = prototype.omega*cos;
+        // s^2 + 2*re*s + |p|^2
+        let p2 = re*re + im*im;
+        *section = if highpass {
+            bilinear( [p2, zero, zero], [p2, two*re*wc, wc*wc] )
+        } else {
+            bilinear( [zero, zero, p2*wc*wc], [one, two*re*wc, p2*wc*wc] )
+        };
+    }
+
+    let s = &mut sections[0];
+    s.b0 *= prototype.gain;
+    s.b1 *= prototype.gain;
+    s.b2 *= prototype.gain;
+    return sections;
+}
+
+/// Butterworth lowpass filter of `order`, as `S = order/2` second order sections, rounded up.
+///
+/// The gain is -3 dB at the cutoff frequency.
+/// For fixed-point types, the integer bits must hold `tan(cutoff/2)^2`.
+///
+/// ## Arguments
+///
+/// * `order`  - The filter order. Panics if zero, or if `S` is not `order/2` rounded up.
+/// * `cutoff` - The cutoff frequency, in radians per sample, in the 0<x<π range.
+pub fn butterworth_lowpass<T, const S: usize>( order: usize, cutoff: T ) -> [BiquadCoefficients<T>; S]
+    where T: MixedReal + MixedNumSigned + MixedOps + MixedZero + MixedOne + MixedSin
+{
+    let prototype = Prototype{ sigma: T::mixed_one(), omega: T::mixed_one(), gain: T::mixed_one() };
+    return prototype_to_sections(prototype, order, cutoff, false);
+}
+
+/// Butterworth highpass filter of `order`, as `S = order/2` second order sections, rounded up.
+///
+/// The gain is -3 dB at the cutoff frequency.
+/// For fixed-point types, the integer bits must hold `tan(cutoff/2)^2`.
+///
+/// ## Arguments
+///
+/// * `order`  - The filter order. Panics if zero, or if `S` is not `order/2` rounded up.
+/// * `cutoff` - The cutoff frequency, in radians per sample, in the 0<x<π range.
+pub fn butterworth_highpass<T, const S: usize>( order: usize, cutoff: T ) -> [BiquadCoefficients<T>; S]
+    where T: MixedReal + MixedNumSigned + MixedOps + MixedZero + MixedOne + MixedSin
+{
+    let prototype = Prototype{ sigma: T::mixed_one(), omega: T::mixed_one(), gain: T::mixed_one() };
+    return prototype_to_sections(prototype, order, cutoff, true);
+}
+
+/// Prototype of a Chebyshev type I filter with `ripple_db` of passband ripple.
+fn chebyshev1_prototype<T>( order: usize, ripple_db: T ) -> Prototype<T>
+    where T: MixedReal + MixedNumSigned + MixedOps + MixedZero + MixedOne + MixedExp + MixedSqrt + MixedPowi
+{
+    let one  = T::mixed_one();
+    let half = T::mixed_from_num(0.5f64);
+
+    // ε^2 = 10^(ripple/10) - 1
+    let eps2 = (ripple_db*T::mixed_from_num(core::f64::consts::LN_10/10f64)).mixed_exp() - one;
+    let x = one/eps2.mixed_sqrt();
+
+    // With t = (x + √(x^2+1))^(1/order) = e^μ, where μ = asinh(1/ε)/order.
+    let t = nth_root(x + (x*x + one).mixed_sqrt(), order);
+    let sinh = (t - one/t)*half;
+    let cosh = (t + one/t)*half;
+
+    // Even orders have the lower edge of the ripple at DC.
+    let gain = if order % 2 == 0 { one/(one + eps2).mixed_sqrt() } else { one };
+    return Prototype{ sigma: sinh, omega: cosh, gain };
+}
+
+/// `y^(1/n)` for `y >= 1`, by Newton's method from above.
+fn nth_root<T>( y: T, n: usize ) -> T
+    where T: MixedReal + MixedOps + MixedOne + MixedPowi
+{
+    let one = T::mixed_one();
+    let n_t = T::mixed_from_num(n as f64);
+    let mut t = one + (y-one)/n_t;
+    for _ in 0..100
+    {
+        let next = t - (t.mixed_powi(n as i32) - y)/(n_t*t.mixed_powi(n as i32 - 1));
+        if t <= next
+        {
+            break;
+        }
+        t = next;
+    }
+    return t;
+}
+
+/// Chebyshev type I lowpass filter of `order`, as `S = order/2` second order sections, rounded up.
+///
+/// The passband gain ripples between 0 dB and `-ripple_db` up to the cutoff frequency, and falls monotonically above it.
+/// For fixed-point types, the integer bits must hold `tan(cutoff/2)^2`.
+///
+/// ## Arguments
+///
+/// * `order`     - The filter order. Panics if zero, or if `S` is not `order/2` rounded up.
+/// * `cutoff`    - The passband edge frequency, in radians per sample, in the 0<x<π range.
+/// * `ripple_db` - The passband ripple in dB.
+pub fn chebyshev1_lowpass<T, const S: usize>( order: usize, cutoff: T, ripple_db: T ) -> [BiquadCoefficients<T>; S]
+    where T: MixedReal + MixedNumSigned + MixedOps + MixedZero + MixedOne + MixedSin + MixedExp + MixedSqrt + MixedPowi
+{
+    return prototype_to_sections(chebyshev1_prototype(order, ripple_db), order, cutoff, false);
+}
+
+/// Chebyshev type I highpass filter of `order`, as `S = order/2` second order sections, rounded up.
+///
+/// The passband gain ripples between 0 dB and `-ripple_db` from the cutoff frequency to π, and falls monotonically below it.
+/// For fixed-point types, the integer bits must hold `tan(cutoff/2)^2`.
+///
+/// ## Arguments
+///
+/// * `order`     - The filter order. Panics if zero, or if `S` is not `order/2` rounded up.
+/// * `cutoff`    - The passband edge frequency, in radians per sample, in the 0<x<π range.
+/// * `ripple_db` - The passband ripple in dB.
+pub fn chebyshev1_highpass<T, const S: usize>( order: usize, cutoff: T, ripple_db: T ) -> [BiquadCoefficients<T>; S]
+    where T: MixedReal + MixedNumSigned + MixedOps + MixedZero + MixedOne + MixedSin + MixedExp + MixedSqrt + MixedPowi
+{
+    return prototype_to_sections(chebyshev1_prototype(order, ripple_db), order, cutoff, true);
+}
@@ -0,0 +1,276 @@
+use super::*;
+
+/// Maximum number of radix factors of the transform size.
+const MAX_FACTORS: usize = usize::BITS as usize;
+
+/// Scaling of the intermediate results in the FFT stages.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FftScaling {
+    /// No scaling. The output is the unnormalized DFT, which grows by up to a factor `N`.
+    None,
+    /// Divide by the radix in each stage. The output is the DFT divided by `N`, and can not overflow for inputs of magnitude below one.
+    PerStage,
+    /// Block floating point. Each stage is scaled by a power of two only when the largest value risks overflow.
+    /// The output is the DFT scaled by `2^-exponent`, where the exponent is returned by the transform.
+    BlockFloatingPoint,
+}
+
+/// In-place mixed-radix fast Fourier transform of size `N`.
+///
+/// Sizes of the form `N = 2^a * 3^b * 5^c` are supported.
+/// The twiddle factors are precomputed at creation, using `MixedSin` of `T`.
+///
+/// The transform is a decimation in time FFT, with an in-place digit reversal permutation of the input.
+///
+/// ## Example
+///
+/// ```
+/// use mixed_num::*;
+/// use mixed_num::dsp::*;
+///
+/// let fft = Fft::<f32, 6>::new().unwrap();
+///
+/// let mut x = [Cartesian::new(1f32, 0f32); 6];
+/// fft.fft(&mut x, FftScaling::None);
+/// assert_eq!{ x[0], Cartesian::new(6f32, 0f32) };
+/// assert!{ x[1].mag() < 1e-6 };
+///
+/// fft.ifft(&mut x, FftScaling::PerStage);
+/// assert!{ (x[3].re-1f32).abs() < 1e-6 };
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct Fft<T, const N: usize> {
+    /// `W_N^j = e^(-j*2π*j/N)` for `j` in `0..N`.
+    twiddles: [Cartesian<T>; N],
+    /// Radix of each stage, outermost first.
+    factors: [usize; MAX_FACTORS],
+    n_factors: usize,
+}
+
+impl <T, const N: usize> Fft<T, N>
+    where T: MixedReal + MixedNumSigned + MixedSin + MixedOps + MixedZero + MixedAbs
+{
+    /// Create the transform, and precompute the twiddle factors.
+    ///
+    /// Returns `None` if `N` is zero, or has prime factors other than 2, 3 and 5.
+    pub fn new() -> Option<Self>
+    {
+        if N == 0
+        {
+            return None;
+        }
+
+        let mut factors = [0usize; MAX_FACTORS];
+        let mut n_factors = 0;
+        let mut n = N;
+        for radix in [2, 3, 5]
+        {
+            while n % radix == 0
+            {
+                factors[n_factors] = radix;
+                n_factors += 1;
+                n /= radix;
+            }
+        }
+        if n != 1
+        {
+            return None;
+        }
+
+        let mut twiddles = [Cartesian::new(T::mixed_zero(), T::mixed_zero()); N];
+        for (j, w) in twiddles.iter_mut().enumerate()
+        {
+            // Angle in the -π=<x<π range.
+            let angle = if 2*j < N {
+                -2f64*core::f64::consts::PI*(j as f64)/(N as f64)
+            } else {
+                2f64*core::f64::consts::PI*((N-j) as f64)/(N as f64)
+            };
+            let (sin, cos) = T::mixed_from_num(angle).mixed_sincos();
+            *w = Cartesian::new(cos, sin);
+        }
+
+        return Some(Fft{ twiddles, factors, n_factors });
+    }
+
+    /// Compute the forward transform of `data` in place.
+    ///
+    /// Returns the block floating point exponent, which is zero unless `FftScaling::BlockFloatingPoint` is used.
+    ///
+    /// ## Arguments
+    ///
+    /// * `data`    - The `N` samples to transform. Panics if the length is not `N`.
+    /// * `scaling` - Scaling of the intermediate results.
+    pub fn fft( &self, data: &mut [Cartesian<T>], scaling: FftScaling ) -> i32
+    {
+        return self.transform(data, scaling, false);
+    }
+
+    /// Compute the inverse transform of `data` in place.
+    ///
+    /// The unscaled inverse is `N` times the input of the forward transform.
+    /// Use `FftScaling::PerStage` for the normalized inverse.
+    ///
+    /// Returns the block floating point exponent, which is zero unless `FftScaling::BlockFloatingPoint` is used.
+    pub fn ifft( &self, data: &mut [Cartesian<T>], scaling: FftScaling ) -> i32
+    {
+        return self.transform(data, scaling, true);
+    }
+
+    fn transform( &self, data: &mut [Cartesian<T>], scaling: FftScaling, inverse: bool ) -> i32
+    {
+        assert_eq!{ data.len(), N, "The data length must equal the FFT size." };
+
+        self.digit_reverse_permutation(data);
+
+        let mut exponent = 0;
+        let mut m = 1;
+        for i in (0..self.n_factors).rev()
+        {
+            let radix = self.factors[i];
+            let scale = match scaling {
+                FftScaling::None     => None,
+                FftScaling::PerStage => Some(T::mixed_from_num(1f64/(radix as f64))),
+                FftScaling::BlockFloatingPoint => {
+                    let shift = block_shift(data, radix);
+                    exponent += shift;
+                    if shift == 0 {
+                        None
+                    } else {
+                        Some(T::mixed_from_num(libm::exp2(-(shift as f64))))
+                    }
+                },
+            };
+            self.stage(data, radix, m, scale, inverse);
+            m *= radix;
+        }
+        return exponent;
+    }
+
+    /// Position of input sample `n` after the digit reversal permutation.
+    #[inline(always)]
+    fn digit_reverse( &self, mut n: usize ) -> usize
+    {
+        let mut m   = N;
+        let mut pos = 0;
+        for radix in self.factors[..self.n_factors].iter()
+        {
+            m   /= radix;
+            pos += (n % radix)*m;
+            n   /= radix;
+        }
+        return pos;
+    }
+
+    /// Permute `data` in place, by following each cycle of the permutation from its smallest index.
+    fn digit_reverse_permutation( &self, data: &mut [Cartesian<T>] )
+    {
+        for start in 1..N
+        {
+            let mut j = self.digit_reverse(start);
+            while start < j
+            {
+                j = self.digit_reverse(j);
+            }
+            if j < start
+            {
+                // The cycle has already been permuted.
+                continue;
+            }
+
+            let mut carry = data[start];
+            let mut j = self.digit_reverse(start);
+            while j != start
+            {
+                core::mem::swap(&mut carry, &mut data[j]);
+                j = self.digit_reverse(j);
+            }
+            data[start] = carry;
+        }
+    }
+
+    /// Multiply `x` by the twiddle `W_N^index`, conjugated for the inverse transform.
+    #[inline(always)]
+    fn twiddle( &self, x: Cartesian<T>, index: usize, inverse: bool ) -> Cartesian<T>
+    {
+        if index == 0
+        {
+            return x;
+        }
+        let mut w = self.twiddles[index];
+        if inverse
+        {
+            w.im = -w.im;
+        }
+        return complex::mul_cartesian(x, w);
+    }
+
+    /// Combine `radix` transforms of length `m` into transforms of length `radix*m`.
+    ///
+    /// `X[k+m*t] = Σ_q W_(radix*m)^(q*k) * Y_q[k] * W_radix^(q*t)`
+    fn stage( &self, data: &mut [Cartesian<T>], radix: usize, m: usize, scale: Option<T>, inverse: bool )
+    {
+        let l = radix*m;
+        let stride = N/l;
+        let radix_stride = N/radix;
+
+        for block in (0..N).step_by(l)
+        {
+            for k in 0..m
+            {
+                let mut a = [Cartesian::new(T::mixed_zero(), T::mixed_zero()); 5];
+                for (q, a_) in a[..radix].iter_mut().enumerate()
+                {
+                    let mut x = data[block+q*m+k];
+                    if let Some(s) = scale
+                    {
+                        x = Cartesian::new(x.re*s, x.im*s);
+                    }
+                    *a_ = self.twiddle(x, q*k*stride, inverse);
+                }
+
+                if radix == 2
+                {
+                    data[block+k]   = Cartesian::new(a[0].re+a[1].re, a[0].im+a[1].im);
+                    data[block+k+m] = Cartesian::new(a[0].re-a[1].re, a[0].im-a[1].im);
+                    continue;
+                }
+
+                for t in 0..radix
+                {
+                    let mut acc = a[0];
+                    for (q, a_) in a[1..radix].iter().enumerate()
+                    {
+                        let y = self.twiddle(*a_, (((q+1)*t) % radix)*radix_stride, inverse);
+                        acc = Cartesian::new(acc.re+y.re, acc.im+y.im);
+                    }
+                    data[block+k+m*t] = acc;
+                }
+            }
+        }
+    }
+}
+
+/// Number of bits to shift a block floating point stage of `radix`, to avoid overflow in the stage.
+pub(super) fn block_shift<T>( data: &[Cartesian<T>], radix: usize ) -> i32
+    where T: MixedReal + MixedNumSigned + MixedOps + MixedZero + MixedAbs
+{
+    let mut max = T::mixed_zero();
+    for x in data.iter()
+    {
+        let re = x.re.mixed_abs();
+        let im = x.im.mixed_abs();
+        if max < re { max = re; }
+        if max < im { max = im; }
+    }
+
+    // A stage grows a component by at most `radix*√2`.
+    let limit = T::mixed_max_value()*T::mixed_from_num(1f64/(2*radix) as f64);
+    let mut shift = 0;
+    while limit < max
+    {
+        max = max*T::mixed_from_num(0.5f64);
+        shift += 1;
+    }
+    return shift;
+}
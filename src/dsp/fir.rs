@@ -0,0 +1,406 @@
+use super::*;
+
+/// Multiply-accumulate of a filter sample with a coefficient, in the accumulator type `A`.
+///
+/// The sample and coefficient are widened to the accumulator with `MixedNumConversion`,
+/// and the accumulated value is narrowed back to the sample type when the filter output is formed.
+/// For `Cartesian` samples, the real and imaginary parts are accumulated separately with the same real coefficient.
+pub trait MixedMac<C, A>: Copy + Default {
+    /// Return `acc + sample*coeff`, calculated in the accumulator type.
+    fn mixed_mac( acc: A, sample: Self, coeff: C ) -> A;
+    /// Narrow the accumulated value to the sample type.
+    fn mixed_narrow( acc: A ) -> Self;
+}
+
+macro_rules! impl_mixed_mac{
+    ( $T:ty ) => {
+        impl <C, A> MixedMac<C, A> for $T
+            where A: MixedNumConversion<$T> + MixedNumConversion<C> + core::ops::Add<Output = A> + core::ops::Mul<Output = A> + Copy
+        {
+            #[inline(always)]
+            fn mixed_mac( acc: A, sample: Self, coeff: C ) -> A {
+                return acc + A::mixed_from_num(sample)*A::mixed_from_num(coeff);
+            }
+            #[inline(always)]
+            fn mixed_narrow( acc: A ) -> Self {
+                return acc.mixed_to_num();
+            }
+        }
+    }
+}
+
+macro_rules! impl_mixed_mac_fixed{
+    ( $T:ident, $L:ident ) => {
+        impl <F, C, A> MixedMac<C, A> for fixed::$T<F>
+            where F: fixed::types::extra::$L,
+                  A: MixedNumConversion<fixed::$T<F>> + MixedNumConversion<C> + core::ops::Add<Output = A> + core::ops::Mul<Output = A> + Copy
+        {
+            #[inline(always)]
+            fn mixed_mac( acc: A, sample: Self, coeff: C ) -> A {
+                return acc + A::mixed_from_num(sample)*A::mixed_from_num(coeff);
+            }
+            #[inline(always)]
+            fn mixed_narrow( acc: A ) -> Self {
+                return acc.mixed_to_num();
+            }
+        }
+    }
+}
+
+impl_mixed_mac!(f32);
+impl_mixed_mac!(f64);
+impl_mixed_mac!(i8);
+impl_mixed_mac!(i16);
+impl_mixed_mac!(i32);
+impl_mixed_mac!(i64);
+impl_mixed_mac!(u8);
+impl_mixed_mac!(u16);
+impl_mixed_mac!(u32);
+impl_mixed_mac!(u64);
+
+impl_mixed_mac_fixed!(FixedI8,  LeEqU8);
+impl_mixed_mac_fixed!(FixedI16, LeEqU16);
+impl_mixed_mac_fixed!(FixedI32, LeEqU32);
+impl_mixed_mac_fixed!(FixedI64, LeEqU64);
+impl_mixed_mac_fixed!(FixedU8,  LeEqU8);
+impl_mixed_mac_fixed!(FixedU16, LeEqU16);
+impl_mixed_mac_fixed!(FixedU32, LeEqU32);
+impl_mixed_mac_fixed!(FixedU64, LeEqU64);
+
+impl <S, C, A> MixedMac<C, Cartesian<A>> for Cartesian<S>
+    where S: MixedMac<C, A>,
+          C: Copy
+{
+    #[inline(always)]
+    fn mixed_mac( acc: Cartesian<A>, sample: Self, coeff: C ) -> Cartesian<A> {
+        return Cartesian::new( S::mixed_mac(acc.re, sample.re, coeff), S::mixed_mac(acc.im, sample.im, coeff) );
+    }
+    #[inline(always)]
+    fn mixed_narrow( acc: Cartesian<A> ) -> Self {
+        return Cartesian::new( S::mixed_narrow(acc.re), S::mixed_narrow(acc.im) );
+    }
+}
+
+/// `Σ_k coeffs[k]*x[n-k]`, where the newest sample `x[n]` is at `newest` in the circular `delay_line`.
+#[inline(always)]
+fn convolve<S, C, A>( coeffs: &[C], delay_line: &[S], newest: usize, stride: usize, offset: usize ) -> S
+    where S: MixedMac<C, A>,
+          C: Copy,
+          A: Default
+{
+    let mut acc = A::default();
+    let mut index = newest;
+    for c in coeffs.iter().skip(offset).step_by(stride)
+    {
+        acc = S::mixed_mac(acc, delay_line[index], *c);
+        index = if index == 0 { delay_line.len()-1 } else { index-1 };
+    }
+    return S::mixed_narrow(acc);
+}
+
+/// Finite impulse response filter, with the delay line in caller-provided storage.
+///
+/// The sample type `S`, the coefficient type `C` and the accumulator type `A` are separate.
+/// Each product is calculated in the accumulator type, and the sum is narrowed to the sample type once per output sample.
+/// `Cartesian` samples are filtered with real coefficients, with a `Cartesian` accumulator.
+///
+/// For fixed-point types, choose an accumulator with enough fractional bits for the products,
+/// and enough integer bits for the sum, e.g. `FixedI64<U40>` for `FixedI16<U15>` samples and coefficients.
+/// The narrowing truncates, and panics in debug builds on overflow.
+///
+/// ## Example
+///
+/// ```
+/// use mixed_num::*;
+/// use mixed_num::dsp::*;
+///
+/// let coeffs = [0.25f32, 0.5f32, 0.25f32];
+/// let mut delay_line = [0f32; 3];
+/// let mut fir = Fir::<f32, f32, f32>::new(&coeffs, &mut delay_line);
+///
+/// let mut y = [0f32; 4];
+/// fir.process(&[1f32, 0f32, 0f32, 0f32], &mut y);
+/// assert_eq!{ y, [0.25f32, 0.5f32, 0.25f32, 0f32] };
+/// ```
+#[derive(Debug)]
+pub struct Fir<'a, S, C, A> {
+    coeffs: &'a [C],
+    delay_line: &'a mut [S],
+    /// Index of the newest sample in `delay_line`.
+    index: usize,
+    accumulator: core::marker::PhantomData<A>,
+}
+
+impl <'a, S, C, A> Fir<'a, S, C, A>
+    where S: MixedMac<C, A>,
+          C: Copy,
+          A: Default
+{
+    /// Create a filter, and clear the delay line.
+    ///
+    /// ## Arguments
+    ///
+    /// * `coeffs`     - The impulse response of the filter. Panics if empty.
+    /// * `delay_line` - Storage for the delay line. Panics if the length differs from that of `coeffs`.
+    pub fn new( coeffs: &'a [C], delay_line: &'a mut [S] ) -> Self
+    {
+        assert!{ !coeffs.is_empty(), "The filter must have at least one coefficient." };
+        assert_eq!{ delay_line.len(), coeffs.len(), "The delay line length must equal the number of coefficients." };
+
+        let mut fir = Fir{ coeffs, delay_line, index: 0, accumulator: core::marker::PhantomData };
+        fir.reset();
+        return fir;
+    }
+
+    /// Clear the delay line.
+    pub fn reset( &mut self )
+    {
+        for x in self.delay_line.iter_mut()
+        {
+            *x = S::default();
+        }
+        self.index = 0;
+    }
+
+    /// The impulse response of the filter.
+    pub fn coeffs( &self ) -> &[C]
+    {
+        return self.coeffs;
+    }
+
+    /// Push a sample to the delay line, without calculating an output.
+    #[inline(always)]
+    pub fn push( &mut self, x: S )
+    {
+        self.index = if self.index+1 == self.delay_line.len() { 0 } else { self.index+1 };
+        self.delay_line[self.index] = x;
+    }
+
+    /// The filter output for the samples in the delay line.
+    #[inline(always)]
+    pub fn output( &self ) -> S
+    {
+        return convolve::<S, C, A>(self.coeffs, self.delay_line, self.index, 1, 0);
+    }
+
+    /// Filter one sample.
+    #[inline(always)]
+    pub fn process_sample( &mut self, x: S ) -> S
+    {
+        self.push(x);
+        return self.output();
+    }
+
+    /// Filter a block of samples.
+    ///
+    /// Processes the first `min(input.len(), output.len())` samples.
+    pub fn process( &mut self, input: &[S], output: &mut [S] )
+    {
+        for (x, y) in input.iter().zip(output.iter_mut())
+        {
+            *y = self.process_sample(*x);
+        }
+    }
+
+    /// Filter a block of samples in place.
+    pub fn process_in_place( &mut self, buffer: &mut [S] )
+    {
+        for x in buffer.iter_mut()
+        {
+            *x = self.process_sample(*x);
+        }
+    }
+}
+
+/// Decimating finite impulse response filter.
+///
+/// Filters the input, and keeps every `factor`-th output sample, starting with the first.
+/// Only the kept output samples are calculated.
+///
+/// ## Example
+///
+/// ```
+/// use mixed_num::*;
+/// use mixed_num::dsp::*;
+///
+/// let coeffs = [0.5f64, 0.5f64];
+/// let mut delay_line = [0f64; 2];
+/// let mut fir = DecimatingFir::<f64, f64, f64>::new(&coeffs, &mut delay_line, 2);
+///
+/// let mut y = [0f64; 2];
+/// let n = fir.process(&[1f64, 2f64, 3f64, 4f64], &mut y);
+/// assert_eq!{ n, 2 };
+/// assert_eq!{ y, [0.5f64, 2.5f64] };
+/// ```
+#[derive(Debug)]
+pub struct DecimatingFir<'a, S, C, A> {
+    fir: Fir<'a, S, C, A>,
+    factor: usize,
+    /// Number of input samples until the next output sample.
+    phase: usize,
+}
+
+impl <'a, S, C, A> DecimatingFir<'a, S, C, A>
+    where S: MixedMac<C, A>,
+          C: Copy,
+          A: Default
+{
+    /// Create a filter, and clear the delay line.
+    ///
+    /// ## Arguments
+    ///
+    /// * `coeffs`     - The impulse response of the filter. Panics if empty.
+    /// * `delay_line` - Storage for the delay line. Panics if the length differs from that of `coeffs`.
+    /// * `factor`     - The decimation factor. Panics if zero.
+    pub fn new( coeffs: &'a [C], delay_line: &'a mut [S], factor: usize ) -> Self
+    {
+        assert!{ 0 < factor, "The decimation factor must be positive." };
+        return DecimatingFir{ fir: Fir::new(coeffs, delay_line), factor, phase: 0 };
+    }
+
+    /// Clear the delay line, and restart the decimation phase.
+    pub fn reset( &mut self )
+    {
+        self.fir.reset();
+        self.phase = 0;
+    }
+
+    /// The decimation factor.
+    pub fn factor( &self ) -> usize
+    {
+        return self.factor;
+    }
+
+    /// Filter one input sample, returning an output sample every `factor`-th call.
+    #[inline(always)]
+    pub fn process_sample( &mut self, x: S ) -> Option<S>
+    {
+        self.fir.push(x);
+        if self.phase != 0
+        {
+            self.phase -= 1;
+            return None;
+        }
+        self.phase = self.factor-1;
+        return Some(self.fir.output());
+    }
+
+    /// Filter a block of samples, returning the number of output samples written.
+    ///
+    /// There are at most `input.len()/factor` output samples, rounded up.
+    /// When `output` is full, the processing stops before the next input sample which produces an output sample.
+    pub fn process( &mut self, input: &[S], output: &mut [S] ) -> usize
+    {
+        let mut n = 0;
+        for x in input.iter()
+        {
+            if self.phase == 0 && n == output.len()
+            {
+                break;
+            }
+            if let Some(y) = self.process_sample(*x)
+            {
+                output[n] = y;
+                n += 1;
+            }
+        }
+        return n;
+    }
+}
+
+/// Interpolating finite impulse response filter, in polyphase form.
+///
+/// Produces `factor` output samples per input sample, equal to filtering the input upsampled by inserting `factor-1` zeros after each sample.
+/// The zero samples are skipped, so the delay line holds `coeffs.len()/factor` input samples, rounded up.
+///
+/// The inserted zeros reduce the signal amplitude by `factor`, which is usually compensated for by a passband gain of `factor` in the coefficients.
+///
+/// ## Example
+///
+/// ```
+/// use mixed_num::*;
+/// use mixed_num::dsp::*;
+///
+/// let coeffs = [0.5f32, 1f32, 0.5f32];
+/// let mut delay_line = [0f32; 2];
+/// let mut fir = InterpolatingFir::<f32, f32, f32>::new(&coeffs, &mut delay_line, 2);
+///
+/// let mut y = [0f32; 4];
+/// fir.process(&[2f32, 4f32], &mut y);
+/// assert_eq!{ y, [1f32, 2f32, 3f32, 4f32] };
+/// ```
+#[derive(Debug)]
+pub struct InterpolatingFir<'a, S, C, A> {
+    coeffs: &'a [C],
+    delay_line: &'a mut [S],
+    /// Index of the newest sample in `delay_line`.
+    index: usize,
+    factor: usize,
+    accumulator: core::marker::PhantomData<A>,
+}
+
+impl <'a, S, C, A> InterpolatingFir<'a, S, C, A>
+    where S: MixedMac<C, A>,
+          C: Copy,
+          A: Default
+{
+    /// Create a filter, and clear the delay line.
+    ///
+    /// ## Arguments
+    ///
+    /// * `coeffs`     - The impulse response of the filter, at the output rate. Panics if empty.
+    /// * `delay_line` - Storage for the delay line. Panics if the length differs from `coeffs.len()/factor` rounded up.
+    /// * `factor`     - The interpolation factor. Panics if zero.
+    pub fn new( coeffs: &'a [C], delay_line: &'a mut [S], factor: usize ) -> Self
+    {
+        assert!{ 0 < factor, "The interpolation factor must be positive." };
+        assert!{ !coeffs.is_empty(), "The filter must have at least one coefficient." };
+        assert_eq!{ delay_line.len(), coeffs.len().div_ceil(factor), "The delay line length must equal the number of coefficients divided by the factor, rounded up." };
+
+        let mut fir = InterpolatingFir{ coeffs, delay_line, index: 0, factor, accumulator: core::marker::PhantomData };
+        fir.reset();
+        return fir;
+    }
+
+    /// Clear the delay line.
+    pub fn reset( &mut self )
+    {
+        for x in self.delay_line.iter_mut()
+        {
+            *x = S::default();
+        }
+        self.index = 0;
+    }
+
+    /// The interpolation factor.
+    pub fn factor( &self ) -> usize
+    {
+        return self.factor;
+    }
+
+    /// Filter one input sample, writing `factor` output samples.
+    ///
+    /// Panics if `output` is shorter than `factor`.
+    pub fn process_sample( &mut self, x: S, output: &mut [S] )
+    {
+        self.index = if self.index+1 == self.delay_line.len() { 0 } else { self.index+1 };
+        self.delay_line[self.index] = x;
+
+        // Output phase p uses the coefficients p, p+factor, p+2*factor, ...
+        for (p, y) in output[..self.factor].iter_mut().enumerate()
+        {
+            *y = convolve::<S, C, A>(self.coeffs, self.delay_line, self.index, self.factor, p);
+        }
+    }
+
+    /// Filter a block of samples.
+    ///
+    /// Processes the first `min(input.len(), output.len()/factor)` input samples, writing `factor` output samples for each.
+    pub fn process( &mut self, input: &[S], output: &mut [S] )
+    {
+        for (x, y) in input.iter().zip(output.chunks_exact_mut(self.factor))
+        {
+            self.process_sample(*x, y);
+        }
+    }
+}
@@ -0,0 +1,249 @@
+use super::*;
+
+/// Goertzel single-bin DFT detector.
+///
+/// Computes one bin of the DFT of a block of samples, using a second order resonator with a single real multiplication per sample.
+///
+/// ## Fixed-point overflow
+///
+/// For input samples of amplitude `A`, the resonator states grow to about `A*N/(2*sin(ω))` over a block of `N` samples,
+/// and the bin power to `(A*N/2)^2` for a tone at the bin frequency.
+/// Choose the integer bits of `T` accordingly, or scale the input, e.g. by `1/N`.
+/// Bins close to DC or π grow the fastest.
+///
+/// ## Example
+///
+/// ```
+/// use mixed_num::*;
+/// use mixed_num::dsp::*;
+///
+/// let mut tone = [0f32; 64];
+/// Nco::new( 2f32*f32::mixed_pi()*8f32/64f32 ).fill_real(&mut tone);
+///
+/// let mut goertzel = Goertzel::from_bin(8, 64);
+/// goertzel.process(&tone);
+/// assert!{ (goertzel.bin().mag()-32f32).abs() < 1e-3 };
+///
+/// let mut goertzel = Goertzel::from_bin(9, 64);
+/// goertzel.process(&tone);
+/// assert!{ goertzel.bin().mag() < 1e-3 };
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct Goertzel<T> {
+    /// `2*cos(ω)`
+    coeff: T,
+    cos: T,
+    sin: T,
+    /// Resonator output of the previous sample.
+    s1: T,
+    /// Resonator output of the sample before the previous.
+    s2: T,
+}
+
+impl <T> Goertzel<T>
+    where T: MixedReal + MixedNumSigned + MixedOps + MixedZero + MixedSin
+{
+    /// Create a detector for the normalized frequency `frequency`, in radians per sample.
+    pub fn new( frequency: T ) -> Self
+    {
+        let (sin, cos) = frequency.mixed_sincos();
+        return Goertzel{
+            coeff: cos+cos,
+            cos,
+            sin,
+            s1: T::mixed_zero(),
+            s2: T::mixed_zero(),
+        };
+    }
+
+    /// Create a detector for bin `k` of an `n` point DFT, i.e. the frequency `2π*k/n`.
+    pub fn from_bin( k: usize, n: usize ) -> Self
+    {
+        return Self::new( bin_frequency(k, n) );
+    }
+
+    /// Clear the resonator, to start a new block.
+    pub fn reset( &mut self )
+    {
+        self.s1 = T::mixed_zero();
+        self.s2 = T::mixed_zero();
+    }
+
+    /// Process one input sample.
+    #[inline(always)]
+    pub fn process_sample( &mut self, x: T )
+    {
+        let s0 = x + self.coeff*self.s1 - self.s2;
+        self.s2 = self.s1;
+        self.s1 = s0;
+    }
+
+    /// Process a block of input samples.
+    pub fn process( &mut self, input: &[T] )
+    {
+        for x in input.iter()
+        {
+            self.process_sample(*x);
+        }
+    }
+
+    /// The DFT bin of the samples processed since the last reset.
+    ///
+    /// For an integer bin `k` of an `N` point DFT, over `N` samples, this is the DFT bin `X[k]`.
+    /// For other frequencies, the result is rotated by `e^(j*ω*N)` relative to the DTFT at `ω`.
+    pub fn bin( &self ) -> Cartesian<T>
+    {
+        // One more resonator step with zero input aligns the phase with the DFT.
+        let s0 = self.coeff*self.s1 - self.s2;
+        return Cartesian::new( s0 - self.cos*self.s1, self.sin*self.s1 );
+    }
+
+    /// The power `|X|^2` of the bin, without calculating the complex bin value.
+    pub fn power( &self ) -> T
+    {
+        return self.s1*self.s1 + self.s2*self.s2 - self.coeff*self.s1*self.s2;
+    }
+
+    /// The magnitude `|X|` of the bin.
+    pub fn magnitude( &self ) -> T
+        where T: MixedHypot
+    {
+        return self.bin().mag();
+    }
+
+    /// The power of the bin in dB.
+    pub fn power_db( &self ) -> T
+        where T: DbPow
+    {
+        return self.power().mixed_pow2db();
+    }
+}
+
+/// Sliding DFT single-bin detector.
+///
+/// Updates one bin of the DFT over the `N` most recent samples for every new sample, at a constant cost per sample.
+///
+/// The recursion `S[n] = r*e^(j*ω)*(S[n-1] + x[n] - r^N*x[n-N])` is stabilized by the damping factor `r`.
+/// With `r = 1` the resonator is marginally stable, and rounding errors accumulate without bound.
+/// A damping factor slightly below one, e.g. `1-2^-16`, makes the errors decay, at the cost of a slight bias of the bin value.
+///
+/// ## Fixed-point overflow
+///
+/// For input samples of amplitude `A`, the bin magnitude is at most `A*N`, and `A*N/2` for a real tone at the bin frequency.
+///
+/// ## Example
+///
+/// ```
+/// use mixed_num::*;
+/// use mixed_num::dsp::*;
+///
+/// let mut tone = [0f64; 100];
+/// Nco::new( 2f64*f64::mixed_pi()*3f64/32f64 ).fill_real(&mut tone);
+///
+/// let mut sdft = SlidingDft::<f64, 32>::new(3, 1f64);
+/// for x in tone {
+///     sdft.update(x);
+/// }
+/// assert!{ (sdft.bin().mag()-16f64).abs() < 1e-9 };
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct SlidingDft<T, const N: usize> {
+    /// The `N` most recent input samples.
+    delay_line: [T; N],
+    /// Index of the oldest sample in `delay_line`.
+    index: usize,
+    /// `r*e^(j*ω)`
+    twiddle: Cartesian<T>,
+    /// `r^N`
+    damping_n: T,
+    state: Cartesian<T>,
+}
+
+impl <T, const N: usize> SlidingDft<T, N>
+    where T: MixedReal + MixedNumSigned + MixedOps + MixedZero + MixedSin + MixedPowi
+{
+    /// Create a detector for bin `k` of an `N` point DFT.
+    ///
+    /// ## Arguments
+    ///
+    /// * `k`       - The bin index.
+    /// * `damping` - The damping factor `r`, at most one.
+    pub fn new( k: usize, damping: T ) -> Self
+    {
+        let (sin, cos) = bin_frequency::<T>(k, N).mixed_sincos();
+        return SlidingDft{
+            delay_line: [T::mixed_zero(); N],
+            index:      0,
+            twiddle:    Cartesian::new(damping*cos, damping*sin),
+            damping_n:  damping.mixed_powi(N as i32),
+            state:      Cartesian::new(T::mixed_zero(), T::mixed_zero()),
+        };
+    }
+
+    /// Clear the delay line and the bin value.
+    pub fn reset( &mut self )
+    {
+        self.delay_line = [T::mixed_zero(); N];
+        self.index = 0;
+        self.state = Cartesian::new(T::mixed_zero(), T::mixed_zero());
+    }
+
+    /// Process one input sample, returning the updated bin value.
+    pub fn update( &mut self, x: T ) -> Cartesian<T>
+    {
+        let oldest = self.delay_line[self.index];
+        self.delay_line[self.index] = x;
+        self.index = (self.index+1)%N;
+
+        let s = Cartesian::new( self.state.re + x - self.damping_n*oldest, self.state.im );
+        self.state = complex::mul_cartesian(self.twiddle, s);
+        return self.state;
+    }
+
+    /// Process a block of input samples.
+    pub fn process( &mut self, input: &[T] )
+    {
+        for x in input.iter()
+        {
+            self.update(*x);
+        }
+    }
+
+    /// The DFT bin of the `N` most recent samples.
+    pub fn bin( &self ) -> Cartesian<T>
+    {
+        return self.state;
+    }
+
+    /// The power `|X|^2` of the bin.
+    pub fn power( &self ) -> T
+    {
+        return self.state.re*self.state.re + self.state.im*self.state.im;
+    }
+
+    /// The magnitude `|X|` of the bin.
+    pub fn magnitude( &self ) -> T
+        where T: MixedHypot
+    {
+        return self.state.mag();
+    }
+
+    /// The power of the bin in dB.
+    pub fn power_db( &self ) -> T
+        where T: DbPow
+    {
+        return self.power().mixed_pow2db();
+    }
+}
+
+/// The frequency of bin `k` of an `n` point DFT in the -π=<x<π range.
+fn bin_frequency<T: MixedNumConversion<f64>>( k: usize, n: usize ) -> T
+{
+    let k = k%n;
+    let frequency = if 2*k < n {
+        2f64*core::f64::consts::PI*(k as f64)/(n as f64)
+    } else {
+        -2f64*core::f64::consts::PI*((n-k) as f64)/(n as f64)
+    };
+    return T::mixed_from_num(frequency);
+}
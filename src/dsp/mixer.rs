@@ -0,0 +1,160 @@
+use super::*;
+
+/// Direction of the frequency shift of a `Mixer`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum MixerDirection {
+    /// Multiply by `e^(j*ω*n)`, shifting the spectrum up by ω.
+    Up,
+    /// Multiply by `e^(-j*ω*n)`, shifting the spectrum down by ω.
+    Down,
+}
+
+/// Digital mixer, shifting the frequency of a sample stream.
+///
+/// The local oscillator is an `Nco<T>`, and the phase is continuous across calls, such that a stream can be processed block by block.
+///
+/// ## Example
+///
+/// ```
+/// use mixed_num::*;
+/// use mixed_num::dsp::*;
+///
+/// let f0 = 0.2f32;
+/// let mut tone = [Cartesian::new(0f32, 0f32); 4];
+/// Nco::new(f0).fill_cartesian(&mut tone);
+///
+/// let mut mixer = Mixer::new(f0, MixerDirection::Down);
+/// mixer.process_in_place(&mut tone);
+///
+/// for x in tone {
+///     assert!{ (x.re-1f32).abs() < 1e-6 && x.im.abs() < 1e-6 };
+/// }
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct Mixer<T> {
+    /// Local oscillator.
+    lo: Nco<T>,
+    direction: MixerDirection,
+}
+
+impl <T> Mixer<T>
+    where T: MixedReal + MixedNumSigned + MixedOps + MixedZero + MixedPi + MixedAbs + MixedSin + MixedWrapPhase
+{
+    /// Create a mixer with a local oscillator accumulating the phase in type `T`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `frequency` - The frequency shift in radians per sample.
+    /// * `direction` - The direction of the frequency shift.
+    pub fn new( frequency: T, direction: MixerDirection ) -> Self
+    {
+        return Self::from_nco( Nco::new(frequency), direction );
+    }
+
+    /// Create a mixer from a configured local oscillator, e.g. with binary phase accumulation or phase dither.
+    pub fn from_nco( lo: Nco<T>, direction: MixerDirection ) -> Self
+    {
+        return Mixer{ lo, direction };
+    }
+
+    /// Set the frequency shift in radians per sample.
+    pub fn set_frequency( &mut self, frequency: T )
+    {
+        self.lo.set_frequency(frequency);
+    }
+
+    /// Get the frequency shift in radians per sample.
+    pub fn frequency( &self ) -> T
+    {
+        return self.lo.frequency();
+    }
+
+    /// Get the direction of the frequency shift.
+    pub fn direction( &self ) -> MixerDirection
+    {
+        return self.direction;
+    }
+
+    /// Get a mutable reference to the local oscillator.
+    pub fn nco( &mut self ) -> &mut Nco<T>
+    {
+        return &mut self.lo;
+    }
+
+    /// Produce the next local oscillator sample, conjugated for down conversion.
+    #[inline(always)]
+    fn next_lo( &mut self ) -> Cartesian<T>
+    {
+        let lo = self.lo.next_cartesian();
+        return match self.direction {
+            MixerDirection::Up   => lo,
+            MixerDirection::Down => Cartesian::new(lo.re, -lo.im),
+        };
+    }
+
+    /// Mix `input`, writing the result to `output`.
+    ///
+    /// Processes the first `min(input.len(), output.len())` samples.
+    pub fn process( &mut self, input: &[Cartesian<T>], output: &mut [Cartesian<T>] )
+    {
+        for (y, x) in output.iter_mut().zip(input.iter())
+        {
+            *y = complex::mul_cartesian(*x, self.next_lo());
+        }
+    }
+
+    /// Mix `buffer` in place.
+    pub fn process_in_place( &mut self, buffer: &mut [Cartesian<T>] )
+    {
+        for x in buffer.iter_mut()
+        {
+            *x = complex::mul_cartesian(*x, self.next_lo());
+        }
+    }
+
+    /// Mix the real valued `input`, writing the complex result to `output`.
+    ///
+    /// Processes the first `min(input.len(), output.len())` samples.
+    pub fn process_real( &mut self, input: &[T], output: &mut [Cartesian<T>] )
+    {
+        for (y, x) in output.iter_mut().zip(input.iter())
+        {
+            let lo = self.next_lo();
+            *y = Cartesian::new(*x*lo.re, *x*lo.im);
+        }
+    }
+
+    /// Mix `input`, rounding the result to the nearest value of the output type `T2`.
+    ///
+    /// Useful to mix with extended precision in `T`, e.g. `FixedI32<U28>`, and store the result in a narrower type, e.g. `FixedI16<U13>`.
+    /// Fixed-point multiplication and conversion otherwise truncate towards -∞, which biases the output by half an LSB.
+    ///
+    /// Processes the first `min(input.len(), output.len())` samples.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use mixed_num::*;
+    /// use mixed_num::dsp::*;
+    /// use fixed::{types::extra::U2, types::extra::U28, FixedI16, FixedI32};
+    ///
+    /// let x = [Cartesian::new(FixedI32::<U28>::from_num(0.2), FixedI32::<U28>::from_num(-0.2))];
+    /// let mut y = [Cartesian::new(FixedI16::<U2>::from_num(0), FixedI16::<U2>::from_num(0))];
+    ///
+    /// let mut mixer = Mixer::new(FixedI32::<U28>::from_num(0), MixerDirection::Up);
+    /// mixer.process_rounded(&x, &mut y);
+    /// assert_eq!{ y[0].re, FixedI16::<U2>::from_num(0.25) };
+    /// assert_eq!{ y[0].im, FixedI16::<U2>::from_num(-0.25) };
+    /// ```
+    pub fn process_rounded<T2>( &mut self, input: &[Cartesian<T>], output: &mut [Cartesian<T2>] )
+        where T2: MixedNumConversion<f64>
+    {
+        for (y, x) in output.iter_mut().zip(input.iter())
+        {
+            let z = complex::mul_cartesian(*x, self.next_lo());
+            let re: f64 = z.re.mixed_to_num();
+            let im: f64 = z.im.mixed_to_num();
+            *y = Cartesian::new( T2::mixed_from_num(re), T2::mixed_from_num(im) );
+        }
+    }
+}
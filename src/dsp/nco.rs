@@ -0,0 +1,357 @@
+use super::*;
+
+/// Scale from a signed 32-bit binary phase to radians, π/2^31.
+const BINARY_PHASE_TO_RAD: f64 = core::f64::consts::PI / 2147483648f64;
+
+/// Numerically controlled oscillator.
+///
+/// The oscillator accumulates phase by a fixed increment per sample, and produces `cos(phi)` as real output or `e^(j*phi)` as complex output.
+///
+/// The phase is accumulated in one of two modes:
+///
+/// * Type phase, where the phase is accumulated in `T` and kept in the -π=<x<π range.
+///   Rounding of the increment results in a slight frequency error.
+/// * Binary phase, where the phase is accumulated in a 32-bit integer spanning one full turn.
+///   The integer accumulator wraps exactly, and does not drift over long runs.
+///   The frequency resolution is 2π/2^32 rad/sample.
+///
+/// Phase dithering can be enabled to spread the spurs caused by phase truncation across the noise floor.
+///
+/// ## Example
+///
+/// ```
+/// use mixed_num::*;
+/// use mixed_num::dsp::*;
+///
+/// let mut nco = Nco::new( f32::mixed_pi()/2f32 );
+///
+/// let x = nco.next_cartesian();
+/// assert_eq!{ x.to_string(), "1+0i" };
+/// let x = nco.next_cartesian();
+/// assert_eq!{ x.to_string(), "-0.00000004371139+1i" };
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct Nco<T> {
+    /// Current phase in type phase mode [rad].
+    phase: T,
+    /// Phase increment per sample in type phase mode [rad].
+    phase_inc: T,
+    /// Current phase in binary phase mode.
+    binary_phase: u32,
+    /// Phase increment per sample in binary phase mode.
+    tuning_word: u32,
+    /// Accumulate the phase in the binary accumulator.
+    binary: bool,
+    /// Peak dither amplitude [rad]. Zero disables dithering.
+    dither: T,
+    /// Peak dither amplitude in binary phase units.
+    dither_word: u32,
+    /// State of the dither generator.
+    rng_state: u32,
+}
+
+impl <T> Nco<T>
+    where T: MixedReal + MixedNumSigned + MixedOps + MixedZero + MixedPi + MixedAbs + MixedSin + MixedWrapPhase
+{
+    /// Create an oscillator accumulating the phase in type `T`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `frequency` - The normalized frequency in radians per sample. Wrapped to the -π=<x<π range.
+    pub fn new( frequency: T ) -> Self
+    {
+        let mut nco = Nco{
+            phase:       T::mixed_zero(),
+            phase_inc:   T::mixed_zero(),
+            binary_phase: 0,
+            tuning_word: 0,
+            binary:      false,
+            dither:      T::mixed_zero(),
+            dither_word: 0,
+            rng_state:   0x9E37_79B9,
+        };
+        nco.set_frequency(frequency);
+        return nco;
+    }
+
+    /// Create an oscillator accumulating the phase in a 32-bit binary phase accumulator.
+    ///
+    /// ## Arguments
+    ///
+    /// * `frequency` - The normalized frequency in radians per sample. Rounded to the nearest multiple of 2π/2^32.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use mixed_num::*;
+    /// use mixed_num::dsp::*;
+    /// use fixed::{types::extra::U28, FixedI32};
+    ///
+    /// let mut nco = Nco::new_binary( FixedI32::<U28>::mixed_pi()/4 );
+    /// assert_eq!{ nco.tuning_word(), 1<<29 };
+    ///
+    /// for _ in 0..8000 {
+    ///     nco.next_real();
+    /// }
+    /// assert_eq!{ nco.phase(), FixedI32::<U28>::from_num(0) };
+    /// ```
+    pub fn new_binary( frequency: T ) -> Self
+    {
+        let mut nco = Self::new( T::mixed_zero() );
+        nco.binary = true;
+        nco.set_frequency(frequency);
+        return nco;
+    }
+
+    /// Set the normalized frequency in radians per sample.
+    pub fn set_frequency( &mut self, frequency: T )
+    {
+        self.phase_inc   = frequency.mixed_wrap_phase();
+        self.tuning_word = rad_to_binary(frequency);
+    }
+
+    /// Get the normalized frequency in radians per sample.
+    pub fn frequency( &self ) -> T
+    {
+        if self.binary
+        {
+            return binary_to_rad(self.tuning_word);
+        }
+        return self.phase_inc;
+    }
+
+    /// Set the tuning word of the binary phase accumulator, in units of 2π/2^32 rad/sample.
+    pub fn set_tuning_word( &mut self, tuning_word: u32 )
+    {
+        self.tuning_word = tuning_word;
+        self.phase_inc   = binary_to_rad(tuning_word);
+    }
+
+    /// Get the tuning word of the binary phase accumulator, in units of 2π/2^32 rad/sample.
+    pub fn tuning_word( &self ) -> u32
+    {
+        return self.tuning_word;
+    }
+
+    /// Set the phase of the next output sample [rad].
+    pub fn set_phase( &mut self, phase: T )
+    {
+        self.phase        = phase.mixed_wrap_phase();
+        self.binary_phase = rad_to_binary(phase);
+    }
+
+    /// Get the phase of the next output sample [rad], excluding dither.
+    pub fn phase( &self ) -> T
+    {
+        if self.binary
+        {
+            return binary_to_rad(self.binary_phase);
+        }
+        return self.phase;
+    }
+
+    /// Set the peak amplitude of the uniform phase dither [rad].
+    ///
+    /// A zero amplitude disables dithering.
+    pub fn set_phase_dither( &mut self, amplitude: T )
+    {
+        self.dither      = amplitude.mixed_abs();
+        self.dither_word = rad_to_binary(self.dither);
+    }
+
+    /// Step the oscillator, returning the phase of the current sample [rad].
+    ///
+    /// ## Arguments
+    ///
+    /// * `fm` - Frequency deviation applied to the phase increment of this sample [rad/sample].
+    /// * `pm` - Phase offset applied to the output phase of this sample [rad].
+    fn step( &mut self, fm: Option<T>, pm: Option<T> ) -> T
+    {
+        if self.binary
+        {
+            let mut phase = self.binary_phase;
+            if let Some(pm) = pm {
+                phase = phase.wrapping_add(rad_to_binary(pm));
+            }
+            if self.dither_word != 0 {
+                let r = (self.next_random() as i32) as i64;
+                phase = phase.wrapping_add( ((r*self.dither_word as i64)>>31) as u32 );
+            }
+
+            let mut tuning_word = self.tuning_word;
+            if let Some(fm) = fm {
+                tuning_word = tuning_word.wrapping_add(rad_to_binary(fm));
+            }
+            self.binary_phase = self.binary_phase.wrapping_add(tuning_word);
+
+            return binary_to_rad(phase);
+        }
+        else
+        {
+            let mut phase = self.phase;
+            if let Some(pm) = pm {
+                phase = (phase+pm).mixed_wrap_phase();
+            }
+            if self.dither != T::mixed_zero() {
+                let r = T::mixed_from_num( (self.next_random() as i32) as f64 / 2147483648f64 );
+                phase = (phase+r*self.dither).mixed_wrap_phase();
+            }
+
+            let mut phase_inc = self.phase_inc;
+            if let Some(fm) = fm {
+                phase_inc = (phase_inc+fm).mixed_wrap_phase();
+            }
+            self.phase = accumulate_phase(self.phase, phase_inc);
+
+            return phase;
+        }
+    }
+
+    /// xorshift32 pseudo-random number generator for the phase dither.
+    fn next_random( &mut self ) -> u32
+    {
+        let mut x = self.rng_state;
+        x ^= x << 13;
+        x ^= x >> 17;
+        x ^= x << 5;
+        self.rng_state = x;
+        return x;
+    }
+
+    /// Produce the next real output sample, `cos(phi)`.
+    pub fn next_real( &mut self ) -> T
+    {
+        let phi = self.step(None, None);
+        let (_, cos) = phi.mixed_sincos();
+        return cos;
+    }
+
+    /// Produce the next complex output sample, `e^(j*phi)`.
+    pub fn next_cartesian( &mut self ) -> Cartesian<T>
+    {
+        let phi = self.step(None, None);
+        let (sin, cos) = phi.mixed_sincos();
+        return Cartesian::new(cos, sin);
+    }
+
+    /// Produce the next real output sample with frequency and phase modulation.
+    ///
+    /// ## Arguments
+    ///
+    /// * `fm` - Frequency deviation from the oscillator frequency, applied after this sample [rad/sample].
+    /// * `pm` - Phase offset of this sample [rad].
+    pub fn next_real_modulated( &mut self, fm: T, pm: T ) -> T
+    {
+        let phi = self.step(Some(fm), Some(pm));
+        let (_, cos) = phi.mixed_sincos();
+        return cos;
+    }
+
+    /// Produce the next complex output sample with frequency and phase modulation.
+    ///
+    /// ## Arguments
+    ///
+    /// * `fm` - Frequency deviation from the oscillator frequency, applied after this sample [rad/sample].
+    /// * `pm` - Phase offset of this sample [rad].
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use mixed_num::*;
+    /// use mixed_num::dsp::*;
+    ///
+    /// let mut nco = Nco::new( 0f64 );
+    ///
+    /// let x = nco.next_cartesian_modulated( f64::mixed_pi()/2f64, 0f64 );
+    /// assert_eq!{ x.to_string(), "1+0i" };
+    /// let x = nco.next_cartesian_modulated( 0f64, -f64::mixed_pi()/2f64 );
+    /// assert_eq!{ x.to_string(), "1+0i" };
+    /// ```
+    pub fn next_cartesian_modulated( &mut self, fm: T, pm: T ) -> Cartesian<T>
+    {
+        let phi = self.step(Some(fm), Some(pm));
+        let (sin, cos) = phi.mixed_sincos();
+        return Cartesian::new(cos, sin);
+    }
+
+    /// Fill `output` with consecutive real output samples.
+    pub fn fill_real( &mut self, output: &mut [T] )
+    {
+        for y in output.iter_mut()
+        {
+            *y = self.next_real();
+        }
+    }
+
+    /// Fill `output` with consecutive complex output samples.
+    pub fn fill_cartesian( &mut self, output: &mut [Cartesian<T>] )
+    {
+        for y in output.iter_mut()
+        {
+            *y = self.next_cartesian();
+        }
+    }
+
+    /// Fill `output` with consecutive complex output samples, frequency modulated by `fm` [rad/sample].
+    ///
+    /// Processes the first `min(fm.len(), output.len())` samples.
+    pub fn fill_cartesian_fm( &mut self, fm: &[T], output: &mut [Cartesian<T>] )
+    {
+        for (y, fm_) in output.iter_mut().zip(fm.iter())
+        {
+            *y = self.next_cartesian_modulated(*fm_, T::mixed_zero());
+        }
+    }
+
+    /// Fill `output` with consecutive complex output samples, phase modulated by `pm` [rad].
+    ///
+    /// Processes the first `min(pm.len(), output.len())` samples.
+    pub fn fill_cartesian_pm( &mut self, pm: &[T], output: &mut [Cartesian<T>] )
+    {
+        for (y, pm_) in output.iter_mut().zip(pm.iter())
+        {
+            *y = self.next_cartesian_modulated(T::mixed_zero(), *pm_);
+        }
+    }
+}
+
+/// Advance a phase in the -π=<x<π range by `phase_inc`, in the -π=<x<π range.
+///
+/// Avoids intermediate values outside the -π=<x<π range, allowing types which cannot represent 2π.
+#[inline(always)]
+fn accumulate_phase<T>( phase: T, phase_inc: T ) -> T
+    where T: MixedNum + MixedOps + MixedPi + MixedZero + MixedNumSigned
+{
+    let pi = T::mixed_pi();
+    // The limits pi-phase_inc and -pi-phase_inc are within the 0=<x=<π and -π<x=<0 ranges.
+    if T::mixed_zero() <= phase_inc
+    {
+        if pi-phase_inc <= phase {
+            return ((phase-pi)+phase_inc)-pi;
+        }
+    }
+    else if phase < -pi-phase_inc
+    {
+        return ((phase+pi)+phase_inc)+pi;
+    }
+    return phase+phase_inc;
+}
+
+/// Convert a binary phase, where 2^32 is one full turn, to radians in the -π=<x<π range.
+#[inline(always)]
+fn binary_to_rad<T>( phase: u32 ) -> T
+    where T: MixedNumConversion<f64>
+{
+    return T::mixed_from_num( (phase as i32) as f64 * BINARY_PHASE_TO_RAD );
+}
+
+/// Convert a phase in radians to binary phase, where 2^32 is one full turn.
+#[inline(always)]
+fn rad_to_binary<T>( phase: T ) -> u32
+    where T: MixedNumConversion<f64>
+{
+    let phase: f64 = phase.mixed_to_num();
+    let word = libm::round( phase / BINARY_PHASE_TO_RAD );
+    // Reduce to one turn before the cast, which saturates.
+    return libm::fmod(word, 4294967296f64) as i64 as u32;
+}
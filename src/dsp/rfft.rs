@@ -0,0 +1,194 @@
+use super::*;
+use super::fft::block_shift;
+
+/// Fast Fourier transform of `2*M` real samples, using an `M` point complex FFT.
+///
+/// The even and odd samples are packed into the real and imaginary parts of `M` complex samples.
+/// After the complex transform, the two interleaved spectra are separated into the `M+1` bin half-spectrum of the real input.
+/// The remaining bins follow from conjugate symmetry, `X[2*M-k] = conj(X[k])`.
+///
+/// ## Example
+///
+/// ```
+/// use mixed_num::*;
+/// use mixed_num::dsp::*;
+///
+/// let rfft = RealFft::<f32, 4>::new().unwrap();
+///
+/// let x = [1f32, 0f32, -1f32, 0f32, 1f32, 0f32, -1f32, 0f32];
+/// let mut spectrum = [Cartesian::new(0f32, 0f32); 5];
+/// rfft.rfft(&x, &mut spectrum, FftScaling::None);
+///
+/// assert!{ (spectrum[2].re-4f32).abs() < 1e-6 };
+/// assert!{ spectrum[0].mag() < 1e-6 && spectrum[4].mag() < 1e-6 };
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct RealFft<T, const M: usize> {
+    fft: Fft<T, M>,
+    /// `W_2M^k = e^(-j*π*k/M)` for `k` in `0..M`.
+    twiddles: [Cartesian<T>; M],
+}
+
+impl <T, const M: usize> RealFft<T, M>
+    where T: MixedReal + MixedNumSigned + MixedSin + MixedOps + MixedZero + MixedAbs
+{
+    /// Create the transform of `2*M` real samples, and precompute the twiddle factors.
+    ///
+    /// Returns `None` if `M` is not supported by `Fft<T, M>`.
+    pub fn new() -> Option<Self>
+    {
+        let fft = Fft::<T, M>::new()?;
+
+        let mut twiddles = [Cartesian::new(T::mixed_zero(), T::mixed_zero()); M];
+        for (k, w) in twiddles.iter_mut().enumerate()
+        {
+            let angle = -core::f64::consts::PI*(k as f64)/(M as f64);
+            let (sin, cos) = T::mixed_from_num(angle).mixed_sincos();
+            *w = Cartesian::new(cos, sin);
+        }
+
+        return Some(RealFft{ fft, twiddles });
+    }
+
+    /// Compute the half-spectrum of the real valued `input`.
+    ///
+    /// The scaling is that of the `2*M` point complex transform, i.e. `FftScaling::PerStage` divides the output by `2*M`.
+    /// Returns the block floating point exponent, which is zero unless `FftScaling::BlockFloatingPoint` is used.
+    ///
+    /// ## Arguments
+    ///
+    /// * `input`    - The `2*M` real samples. Panics on any other length.
+    /// * `spectrum` - The `M+1` output bins. Panics on any other length.
+    /// * `scaling`  - Scaling of the intermediate results.
+    pub fn rfft( &self, input: &[T], spectrum: &mut [Cartesian<T>], scaling: FftScaling ) -> i32
+    {
+        assert_eq!{ input.len(), 2*M, "The input length must be 2*M." };
+        assert_eq!{ spectrum.len(), M+1, "The spectrum length must be M+1." };
+
+        for (z, x) in spectrum.iter_mut().zip(input.chunks(2))
+        {
+            *z = Cartesian::new(x[0], x[1]);
+        }
+        let mut exponent = self.fft.fft(&mut spectrum[..M], scaling);
+
+        // Halving in the separation keeps the intermediate results in range. PerStage halves once more for the last radix 2 stage.
+        let mut scale = T::mixed_from_num(0.5f64);
+        match scaling {
+            FftScaling::None => {},
+            FftScaling::PerStage => {
+                scale = T::mixed_from_num(0.25f64);
+            },
+            FftScaling::BlockFloatingPoint => {
+                let shift = block_shift(&spectrum[..M], 2);
+                exponent += shift;
+                scale = T::mixed_from_num(libm::exp2(-1f64-(shift as f64)));
+            },
+        }
+
+        for k in 0..=M/2
+        {
+            let z_k = scale_cartesian(spectrum[k], scale);
+            let z_m = conj(scale_cartesian(spectrum[(M-k)%M], scale));
+
+            // Even and odd spectra.
+            let e = Cartesian::new(z_k.re+z_m.re, z_k.im+z_m.im);
+            let o = Cartesian::new(z_k.im-z_m.im, z_m.re-z_k.re);
+            let wo = complex::mul_cartesian(self.twiddles[k], o);
+
+            spectrum[k] = Cartesian::new(e.re+wo.re, e.im+wo.im);
+            if k != M-k
+            {
+                spectrum[M-k] = Cartesian::new(e.re-wo.re, wo.im-e.im);
+            }
+        }
+        return exponent;
+    }
+
+    /// Compute the real valued signal from its half-spectrum.
+    ///
+    /// The unscaled inverse is `2*M` times the input of the forward transform.
+    /// Use `FftScaling::PerStage` for the normalized inverse.
+    /// Returns the block floating point exponent, which is zero unless `FftScaling::BlockFloatingPoint` is used.
+    ///
+    /// ## Arguments
+    ///
+    /// * `spectrum` - The `M+1` input bins. Used as scratch, and overwritten. Panics on any other length.
+    /// * `output`   - The `2*M` real output samples. Panics on any other length.
+    /// * `scaling`  - Scaling of the intermediate results.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use mixed_num::*;
+    /// use mixed_num::dsp::*;
+    /// use fixed::{types::extra::U28, FixedI32};
+    ///
+    /// let rfft = RealFft::<FixedI32<U28>, 3>::new().unwrap();
+    ///
+    /// let x = [0.1f32, 0.2f32, 0.3f32, -0.4f32, 0.5f32, 0.6f32].map( |x| FixedI32::<U28>::from_num(x) );
+    /// let mut spectrum = [Cartesian::new(FixedI32::<U28>::from_num(0), FixedI32::<U28>::from_num(0)); 4];
+    /// let mut y = [FixedI32::<U28>::from_num(0); 6];
+    ///
+    /// rfft.rfft(&x, &mut spectrum, FftScaling::None);
+    /// rfft.irfft(&mut spectrum, &mut y, FftScaling::PerStage);
+    ///
+    /// for (x_, y_) in x.iter().zip(y.iter()) {
+    ///     assert!{ (*x_-*y_).abs() < 1e-6 };
+    /// }
+    /// ```
+    pub fn irfft( &self, spectrum: &mut [Cartesian<T>], output: &mut [T], scaling: FftScaling ) -> i32
+    {
+        assert_eq!{ spectrum.len(), M+1, "The spectrum length must be M+1." };
+        assert_eq!{ output.len(), 2*M, "The output length must be 2*M." };
+
+        let mut exponent = 0;
+        let mut scale = T::mixed_from_num(1f64);
+        match scaling {
+            FftScaling::None => {},
+            FftScaling::PerStage => {
+                scale = T::mixed_from_num(0.5f64);
+            },
+            FftScaling::BlockFloatingPoint => {
+                exponent = block_shift(spectrum, 2);
+                scale = T::mixed_from_num(libm::exp2(-(exponent as f64)));
+            },
+        }
+
+        for k in 0..=M/2
+        {
+            let x_k = scale_cartesian(spectrum[k], scale);
+            let x_m = conj(scale_cartesian(spectrum[M-k], scale));
+
+            // Even and odd spectra.
+            let e = Cartesian::new(x_k.re+x_m.re, x_k.im+x_m.im);
+            let d = Cartesian::new(x_k.re-x_m.re, x_k.im-x_m.im);
+            let o = complex::mul_cartesian(conj(self.twiddles[k]), d);
+
+            spectrum[k] = Cartesian::new(e.re-o.im, e.im+o.re);
+            if k != 0 && k != M-k
+            {
+                spectrum[M-k] = Cartesian::new(e.re+o.im, o.re-e.im);
+            }
+        }
+
+        exponent += self.fft.ifft(&mut spectrum[..M], scaling);
+        for (x, z) in output.chunks_mut(2).zip(spectrum.iter())
+        {
+            x[0] = z.re;
+            x[1] = z.im;
+        }
+        return exponent;
+    }
+}
+
+#[inline(always)]
+pub(super) fn scale_cartesian<T: MixedNum + MixedOps>( x: Cartesian<T>, scale: T ) -> Cartesian<T>
+{
+    return Cartesian::new(x.re*scale, x.im*scale);
+}
+
+#[inline(always)]
+pub(super) fn conj<T: MixedNumSigned>( x: Cartesian<T> ) -> Cartesian<T>
+{
+    return Cartesian::new(x.re, -x.im);
+}
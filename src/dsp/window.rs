@@ -0,0 +1,161 @@
+//! Window functions for spectral analysis and filter design, generated in the sample type.
+//!
+//! Symmetric windows are used for filter design, and periodic windows for spectral analysis, where the window of length `N` is one period of a window of length `N+1`.
+//!
+//! ## Example
+//!
+//! ```
+//! use mixed_num::*;
+//! use mixed_num::dsp::window::*;
+//!
+//! let mut w = [0f32; 64];
+//! hann(&mut w, WindowSymmetry::Periodic);
+//! assert_eq!{ w[0], 0f32 };
+//! assert!{ (w[32]-1f32).abs() < 1e-6 };
+//!
+//! assert!{ (coherent_gain(&w)-0.5f32).abs() < 1e-6 };
+//! assert!{ (enbw(&w)-1.5f32).abs() < 1e-5 };
+//! ```
+
+use super::*;
+
+/// Symmetry of a window.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum WindowSymmetry {
+    /// `w[n] = w[N-1-n]`, for filter design.
+    Symmetric,
+    /// The first `N` samples of the symmetric window of length `N+1`, for spectral analysis.
+    Periodic,
+}
+
+/// The denominator `D` of the normalized window position `n/D`.
+fn denominator( len: usize, symmetry: WindowSymmetry ) -> usize
+{
+    return match symmetry {
+        WindowSymmetry::Symmetric => len-1,
+        WindowSymmetry::Periodic  => len,
+    };
+}
+
+/// Fill `window` with the generalized cosine window `w[n] = Σ_k (-1)^k*a_k*cos(2π*k*n/D)`.
+///
+/// ## Arguments
+///
+/// * `window`    - The window to fill.
+/// * `coeffs`    - The coefficients `a_k`.
+/// * `symmetry`  - Symmetric or periodic window.
+pub fn cosine_sum<T>( window: &mut [T], coeffs: &[f64], symmetry: WindowSymmetry )
+    where T: MixedReal + MixedOps + MixedZero + MixedOne + MixedCos + MixedPi
+{
+    if window.len() <= 1
+    {
+        window.fill(T::mixed_one());
+        return;
+    }
+
+    let d = denominator(window.len(), symmetry);
+    for (n, w) in window.iter_mut().enumerate()
+    {
+        let mut sum = T::mixed_zero();
+        for (k, a) in coeffs.iter().enumerate()
+        {
+            // The angle 2π*k*n/D, reduced to the -π=<x<π range before conversion.
+            let mut turns = ((k*n) % d) as f64/(d as f64);
+            if 0.5f64 <= turns
+            {
+                turns -= 1f64;
+            }
+            let cos = (T::mixed_pi()*T::mixed_from_num(2f64*turns)).mixed_cos();
+
+            let a = if k % 2 == 0 { *a } else { -*a };
+            sum += T::mixed_from_num(a)*cos;
+        }
+        *w = sum;
+    }
+}
+
+/// Fill `window` with the Hann window.
+pub fn hann<T>( window: &mut [T], symmetry: WindowSymmetry )
+    where T: MixedReal + MixedOps + MixedZero + MixedOne + MixedCos + MixedPi
+{
+    cosine_sum(window, &[0.5f64, 0.5f64], symmetry);
+}
+
+/// Fill `window` with the Hamming window.
+pub fn hamming<T>( window: &mut [T], symmetry: WindowSymmetry )
+    where T: MixedReal + MixedOps + MixedZero + MixedOne + MixedCos + MixedPi
+{
+    cosine_sum(window, &[0.54f64, 0.46f64], symmetry);
+}
+
+/// Fill `window` with the four term Blackman-Harris window, with sidelobes below -92 dB.
+pub fn blackman_harris<T>( window: &mut [T], symmetry: WindowSymmetry )
+    where T: MixedReal + MixedOps + MixedZero + MixedOne + MixedCos + MixedPi
+{
+    cosine_sum(window, &[0.35875f64, 0.48829f64, 0.14128f64, 0.01168f64], symmetry);
+}
+
+/// Fill `window` with the five term flat-top window, for accurate amplitude measurements.
+///
+/// The window has small negative values, so use a signed type.
+pub fn flat_top<T>( window: &mut [T], symmetry: WindowSymmetry )
+    where T: MixedReal + MixedOps + MixedZero + MixedOne + MixedCos + MixedPi
+{
+    cosine_sum(window, &[0.21557895f64, 0.41663158f64, 0.277263158f64, 0.083578947f64, 0.006947368f64], symmetry);
+}
+
+/// Fill `window` with the Kaiser window `w[n] = I0(β*√(1-(2n/D-1)^2))/I0(β)`.
+///
+/// For fixed-point types, the integer bits must hold `I0(β)`, which is about 400 for β=8.
+///
+/// ## Arguments
+///
+/// * `window`   - The window to fill.
+/// * `beta`     - The shape parameter `β`. Zero yields a rectangular window.
+/// * `symmetry` - Symmetric or periodic window.
+pub fn kaiser<T>( window: &mut [T], beta: T, symmetry: WindowSymmetry )
+    where T: MixedReal + MixedOps + MixedZero + MixedOne + MixedSqrt + MixedBesselI0
+{
+    if window.len() <= 1
+    {
+        window.fill(T::mixed_one());
+        return;
+    }
+
+    let d = denominator(window.len(), symmetry);
+    let i0_beta = beta.mixed_bessel_i0();
+    for (n, w) in window.iter_mut().enumerate()
+    {
+        let r = T::mixed_from_num( (2*n) as f64/(d as f64) - 1f64 );
+        *w = (beta*(T::mixed_one()-r*r).mixed_sqrt()).mixed_bessel_i0()/i0_beta;
+    }
+}
+
+/// Coherent gain of a window, `Σ w[n]/N`, the gain of the window for a tone at the center of a bin.
+pub fn coherent_gain<T>( window: &[T] ) -> T
+    where T: MixedReal + MixedOps + MixedZero
+{
+    let scale = T::mixed_from_num(1f64/window.len() as f64);
+    let mut sum = T::mixed_zero();
+    for w in window.iter()
+    {
+        sum += *w*scale;
+    }
+    return sum;
+}
+
+/// Equivalent noise bandwidth of a window in bins, `N*Σ w[n]^2/(Σ w[n])^2`.
+///
+/// Calculated from the mean values, which keeps the sums in the range of fixed-point types.
+pub fn enbw<T>( window: &[T] ) -> T
+    where T: MixedReal + MixedOps + MixedZero
+{
+    let scale = T::mixed_from_num(1f64/window.len() as f64);
+    let mut power = T::mixed_zero();
+    for w in window.iter()
+    {
+        power += *w*(*w*scale);
+    }
+    let gain = coherent_gain(window);
+    return power/(gain*gain);
+}
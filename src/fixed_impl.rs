@@ -31,6 +31,42 @@ macro_rules! impl_mixed_num_conversion{
     }
 }
 
+/// Round a constant in `fixed::consts` to nearest, with `frac_nbits` fractional bits.
+/// Returns the bits of the rounded constant.
+fn round_const<Frac>( constant: fixed::FixedU128<Frac>, frac_nbits: u32 ) -> u128
+    where Frac: fixed::types::extra::LeEqU128
+{
+    let shift = fixed::FixedU128::<Frac>::FRAC_NBITS - frac_nbits;
+    let bits = constant.to_bits();
+    if shift == 0
+    {
+        return bits;
+    }
+    if 128 <= shift
+    {
+        return bits >> 127;
+    }
+    return (bits >> shift) + ((bits >> (shift-1)) & 1);
+}
+
+/// Raw bits from a magnitude and a sign, saturating at the raw bits `min` and `max` of the type.
+fn saturate_bits( magnitude: u128, negative: bool, min: i128, max: i128 ) -> i128
+{
+    if negative
+    {
+        if min.unsigned_abs() <= magnitude
+        {
+            return min;
+        }
+        return -(magnitude as i128);
+    }
+    if (max as u128) <= magnitude
+    {
+        return max;
+    }
+    return magnitude as i128;
+}
+
 macro_rules! impl_mixed_num_for_fixed{
     ( $T:ty ) => {
 
@@ -128,6 +164,239 @@ macro_rules! impl_mixed_num_for_fixed{
             }
         }
 
+        impl MixedE for $T
+        {
+            #[inline(always)]
+            fn mixed_e() -> Self {
+                return Self::from_bits( round_const(fixed::consts::E, Self::FRAC_NBITS) as _ );
+            }
+        }
+
+        impl MixedLn2 for $T
+        {
+            #[inline(always)]
+            fn mixed_ln2() -> Self {
+                return Self::from_bits( round_const(fixed::consts::LN_2, Self::FRAC_NBITS) as _ );
+            }
+        }
+
+        impl MixedLn10 for $T
+        {
+            #[inline(always)]
+            fn mixed_ln10() -> Self {
+                return Self::from_bits( round_const(fixed::consts::LN_10, Self::FRAC_NBITS) as _ );
+            }
+        }
+
+        impl MixedSqrt2 for $T
+        {
+            #[inline(always)]
+            fn mixed_sqrt2() -> Self {
+                return Self::from_bits( round_const(fixed::consts::SQRT_2, Self::FRAC_NBITS) as _ );
+            }
+        }
+
+        impl MixedFrac1Sqrt2 for $T
+        {
+            #[inline(always)]
+            fn mixed_frac_1_sqrt2() -> Self {
+                return Self::from_bits( round_const(fixed::consts::FRAC_1_SQRT_2, Self::FRAC_NBITS) as _ );
+            }
+        }
+
+        impl MixedFracPi2 for $T
+        {
+            #[inline(always)]
+            fn mixed_frac_pi_2() -> Self {
+                return Self::from_bits( round_const(fixed::consts::FRAC_PI_2, Self::FRAC_NBITS) as _ );
+            }
+        }
+
+        impl MixedFrac1Pi for $T
+        {
+            #[inline(always)]
+            fn mixed_frac_1_pi() -> Self {
+                return Self::from_bits( round_const(fixed::consts::FRAC_1_PI, Self::FRAC_NBITS) as _ );
+            }
+        }
+
+        impl MixedEpsilon for $T
+        {
+            /// The lsb of the type.
+            #[inline(always)]
+            fn mixed_epsilon() -> Self {
+                return Self::DELTA;
+            }
+        }
+
+        impl MixedHypot for $T
+        {
+            #[inline(always)]
+            fn mixed_hypot(&self, other:Self) -> Self {
+                let hypot = trigonometry::sqrt::hypot_bits((self.to_bits() as i128).unsigned_abs(), (other.to_bits() as i128).unsigned_abs());
+                return Self::from_bits( saturate_bits(hypot, false, Self::MIN.to_bits() as i128, Self::MAX.to_bits() as i128) as _ );
+            }
+            #[inline(always)]
+            fn mixed_hypot_fast(&self, other:Self) -> Self {
+                let hypot = trigonometry::sqrt::hypot_fast_bits((self.to_bits() as i128).unsigned_abs(), (other.to_bits() as i128).unsigned_abs());
+                return Self::from_bits( saturate_bits(hypot, false, Self::MIN.to_bits() as i128, Self::MAX.to_bits() as i128) as _ );
+            }
+        }
+
+        impl MixedRecip for $T
+        {
+            /// The number of iterations is chosen from the bits of the type, see `recip::recip_iterations`.
+            #[inline(always)]
+            fn mixed_recip(&self) -> Self {
+                let bits = self.to_bits() as i128;
+                let magnitude = trigonometry::recip::recip_bits(bits.unsigned_abs() as u64, Self::FRAC_NBITS, trigonometry::recip::recip_iterations::<Self>());
+                return Self::from_bits( saturate_bits(magnitude, bits < 0, Self::MIN.to_bits() as i128, Self::MAX.to_bits() as i128) as _ );
+            }
+            #[inline(always)]
+            fn mixed_recip_fast(&self) -> Self {
+                let bits = self.to_bits() as i128;
+                let magnitude = trigonometry::recip::recip_bits(bits.unsigned_abs() as u64, Self::FRAC_NBITS, 2);
+                return Self::from_bits( saturate_bits(magnitude, bits < 0, Self::MIN.to_bits() as i128, Self::MAX.to_bits() as i128) as _ );
+            }
+        }
+
+        impl MixedRsqrt for $T
+        {
+            /// The number of iterations is chosen from the bits of the type, see `recip::rsqrt_iterations`.
+            #[inline(always)]
+            fn mixed_rsqrt(&self) -> Self {
+                let bits = self.to_bits() as i128;
+                let magnitude = trigonometry::recip::rsqrt_bits(bits.unsigned_abs() as u64, Self::FRAC_NBITS, trigonometry::recip::rsqrt_iterations::<Self>());
+                return Self::from_bits( saturate_bits(magnitude, false, Self::MIN.to_bits() as i128, Self::MAX.to_bits() as i128) as _ );
+            }
+            #[inline(always)]
+            fn mixed_rsqrt_fast(&self) -> Self {
+                let bits = self.to_bits() as i128;
+                let magnitude = trigonometry::recip::rsqrt_bits(bits.unsigned_abs() as u64, Self::FRAC_NBITS, 2);
+                return Self::from_bits( saturate_bits(magnitude, false, Self::MIN.to_bits() as i128, Self::MAX.to_bits() as i128) as _ );
+            }
+        }
+
+        impl MixedRound for $T
+        {
+            #[inline(always)]
+            fn mixed_round(&self) -> Self {
+                return self.saturating_round();
+            }
+            #[inline(always)]
+            fn mixed_round_ties_even(&self) -> Self {
+                return self.saturating_round_ties_even();
+            }
+        }
+
+        impl MixedTrunc for $T
+        {
+            #[inline(always)]
+            fn mixed_trunc(&self) -> Self {
+                return self.round_to_zero();
+            }
+            #[inline(always)]
+            fn mixed_fract(&self) -> Self {
+                return *self - self.round_to_zero();
+            }
+        }
+
+        impl MixedEuclid for $T
+        {
+            #[inline(always)]
+            fn mixed_div_euclid(&self, rhs:Self) -> Self {
+                return self.saturating_div_euclid(rhs);
+            }
+            #[inline(always)]
+            fn mixed_rem_euclid(&self, rhs:Self) -> Self {
+                return self.rem_euclid(rhs);
+            }
+        }
+
+        impl MixedMinMax for $T
+        {
+            #[inline(always)]
+            fn mixed_min(&self, other:Self) -> Self {
+                return core::cmp::Ord::min(*self, other);
+            }
+            #[inline(always)]
+            fn mixed_max(&self, other:Self) -> Self {
+                return core::cmp::Ord::max(*self, other);
+            }
+            #[inline(always)]
+            fn mixed_clamp(&self, min:Self, max:Self) -> Self {
+                return self.mixed_max(min).mixed_min(max);
+            }
+            /// Compares the distance to zero, which does not overflow at `MIN`.
+            #[inline(always)]
+            fn mixed_min_by_mag(&self, other:Self) -> Self {
+                if self.abs_diff(Self::ZERO) <= other.abs_diff(Self::ZERO)
+                {
+                    return *self;
+                }
+                return other;
+            }
+            /// Compares the distance to zero, which does not overflow at `MIN`.
+            #[inline(always)]
+            fn mixed_max_by_mag(&self, other:Self) -> Self {
+                if other.abs_diff(Self::ZERO) <= self.abs_diff(Self::ZERO)
+                {
+                    return *self;
+                }
+                return other;
+            }
+        }
+
+        impl MixedClassify for $T
+        {
+            #[inline(always)]
+            fn mixed_is_nan(&self) -> bool {
+                return false;
+            }
+            #[inline(always)]
+            fn mixed_is_infinite(&self) -> bool {
+                return false;
+            }
+            #[inline(always)]
+            fn mixed_is_finite(&self) -> bool {
+                return true;
+            }
+            /// At `MIN` or `MAX`, except zero of unsigned types.
+            #[inline(always)]
+            fn mixed_is_saturated(&self) -> bool {
+                return *self == <$T>::MAX || (*self == <$T>::MIN && <$T>::IS_SIGNED);
+            }
+            #[inline(always)]
+            fn mixed_classify(&self) -> MixedCategory {
+                if self.mixed_is_saturated()
+                {
+                    return MixedCategory::Saturated;
+                }
+                if *self == <$T>::ZERO
+                {
+                    return MixedCategory::Zero;
+                }
+                return MixedCategory::Normal;
+            }
+        }
+
+        impl MixedTypeInfo for $T
+        {
+            const IS_FIXED: bool   = true;
+            const IS_FLOAT: bool   = false;
+            const IS_INTEGER: bool = false;
+            const IS_SIGNED: bool  = <$T>::IS_SIGNED;
+            const FRAC_BITS: u32   = <$T>::FRAC_NBITS;
+            const INT_BITS: u32    = <$T>::INT_NBITS;
+            const EPSILON: Self    = <$T>::DELTA;
+
+            /// The lsb, for all values.
+            #[inline(always)]
+            fn mixed_ulp(&self) -> Self {
+                return Self::DELTA;
+            }
+        }
+
         impl MixedConsts for $T
         {
         }
@@ -136,8 +405,9 @@ macro_rules! impl_mixed_num_for_fixed{
         {
             /// Take the square root of self.
             #[inline(always)]
+            /// The number of iterations is chosen from the fractional bits, see `sqrt::niirf_iterations`.
             fn mixed_sqrt(&self) -> Self {
-                return trigonometry::sqrt::niirf(*self, 2);
+                return trigonometry::sqrt::niirf(*self, trigonometry::sqrt::niirf_iterations::<Self>());
             }
             /// Take the square root of self.
             #[inline(always)]
@@ -145,6 +415,22 @@ macro_rules! impl_mixed_num_for_fixed{
                 return trigonometry::sqrt::niirf(*self, 2);
             }
         }
+
+        impl MixedSqrtSlice for $T
+        {
+            /// Take the square root of each element in `input`, writing the result to `output`.
+            /// 
+            /// The NIIRF lookup-table is shared by all elements.
+            #[inline(always)]
+            fn mixed_sqrt_into( input: &[Self], output: &mut [Self] ) {
+                trigonometry::sqrt::niirf_slice(input, output, trigonometry::sqrt::niirf_iterations::<Self>());
+            }
+            /// Take the NIIRF square root of each element in `input`, writing the result to `output`.
+            #[inline(always)]
+            fn mixed_niirf_into( input: &[Self], output: &mut [Self] ) {
+                trigonometry::sqrt::niirf_slice(input, output, 2);
+            }
+        }
     }
 }
 
@@ -254,21 +540,31 @@ macro_rules! impl_mixed_num_for_fixed_signed{
         {
             #[inline(always)]
             fn mixed_wrap_phase(&self) -> Self {
-                return trigonometry::wrap_phase(*self);
+                return trigonometry::wrap_half_period(*self, core::f64::consts::PI);
             }
         }
 
         impl MixedSin for $T
         {
             /// Take the sin of self. Implementation varies with type.
+            /// 
+            /// Types of up to 16 bits use the lookup table in `trigonometry::lut`, and wider types `cordic`.
             #[inline(always)]
             fn mixed_sin(&self) -> Self {
+                if Self::INT_NBITS + Self::FRAC_NBITS <= 16
+                {
+                    return Self::from_bits( trigonometry::lut::sin_bits(self.to_bits() as i64, Self::FRAC_NBITS) as _ );
+                }
                 return cordic::sin(*self);
             }
             #[inline(always)]
             fn mixed_sincos(&self) -> (Self, Self) 
                 where Self: Sized
             {
+                if Self::INT_NBITS + Self::FRAC_NBITS <= 16
+                {
+                    return (self.mixed_sin(), self.mixed_cos());
+                }
                 return cordic::sin_cos(*self);
             }
             /// Take the arcsin of self. Implementation varies with type.
@@ -283,6 +579,10 @@ macro_rules! impl_mixed_num_for_fixed_signed{
             /// Take the cos of self. Implementation varies with type.
             #[inline(always)]
             fn mixed_cos(&self) -> Self {
+                if Self::INT_NBITS + Self::FRAC_NBITS <= 16
+                {
+                    return Self::from_bits( trigonometry::lut::cos_bits(self.to_bits() as i64, Self::FRAC_NBITS) as _ );
+                }
                 return cordic::cos(*self);
             }
             /// Take the arccos of self. Implementation varies with type.
@@ -296,16 +596,104 @@ macro_rules! impl_mixed_num_for_fixed_signed{
         {    
         }
 
+        impl MixedTrigonometryAccuracy for $T
+        {
+            #[inline(always)]
+            fn mixed_sin_fast(&self) -> Self {
+                return trigonometry::sin(*self);
+            }
+            #[inline(always)]
+            fn mixed_sin_precise(&self) -> Self {
+                return self.mixed_sin();
+            }
+            /// cos(x) = sin(π/2-|x|), which stays in the range of the polynomial.
+            #[inline(always)]
+            fn mixed_cos_fast(&self) -> Self {
+                return trigonometry::sin(Self::mixed_pi()/Self::mixed_from_num(2) - self.mixed_abs());
+            }
+            #[inline(always)]
+            fn mixed_cos_precise(&self) -> Self {
+                return self.mixed_cos();
+            }
+            #[inline(always)]
+            fn mixed_atan2_fast(&self, other:Self) -> Self {
+                return trigonometry::atan::atan2(*self, other);
+            }
+            /// The octants are reduced to `cordic::atan` on the 0=<x=<1 range, which avoids overflow in `y/x`.
+            #[inline(always)]
+            fn mixed_atan2_precise(&self, other:Self) -> Self {
+                return trigonometry::atan::atan2_with(*self, other, &|r: Self| cordic::atan(r));
+            }
+        }
+
+        impl MixedAngleUnits for $T
+        {
+            #[inline(always)]
+            fn mixed_to_degrees(&self) -> Self {
+                return trigonometry::to_degrees(*self);
+            }
+            #[inline(always)]
+            fn mixed_to_radians(&self) -> Self {
+                return *self*Self::mixed_from_num(core::f64::consts::PI/180f64);
+            }
+            #[inline(always)]
+            fn mixed_to_turns(&self) -> Self {
+                return *self*Self::mixed_from_num(1f64/core::f64::consts::TAU);
+            }
+            #[inline(always)]
+            fn mixed_sind(&self) -> Self {
+                return trigonometry::sind(*self);
+            }
+            #[inline(always)]
+            fn mixed_cosd(&self) -> Self {
+                return trigonometry::cosd(*self);
+            }
+            #[inline(always)]
+            fn mixed_sincosd(&self) -> (Self, Self) {
+                return trigonometry::sincosd(*self);
+            }
+            #[inline(always)]
+            fn mixed_atan2d(&self, other:Self) -> Self {
+                return trigonometry::atan2d(*self, other);
+            }
+        }
+
+        impl MixedSinSlice for $T
+        {
+        }
+
+        impl MixedCosSlice for $T
+        {
+        }
+
+        impl MixedAtanSlice for $T
+        {
+        }
+
+        impl MixedCartesianSlice for $T
+        {
+        }
+
         impl MixedAtan for $T
         {
             /// Take the atan of self. Implementation varies with type.
             #[inline(always)]
             fn mixed_atan(&self) -> Self {
+                if Self::INT_NBITS + Self::FRAC_NBITS <= 16
+                {
+                    return Self::from_bits( trigonometry::lut::atan2_bits(self.to_bits() as i64, 1i64 << Self::FRAC_NBITS, Self::FRAC_NBITS) as _ );
+                }
                 return cordic::atan(*self);
             }
             /// Take the atan of self. Implementation varies with type.
+            /// 
+            /// Types of up to 16 bits use the lookup table in `trigonometry::lut`, and wider types `cordic`.
             #[inline(always)]
             fn mixed_atan2(&self, other:Self) -> Self {
+                if Self::INT_NBITS + Self::FRAC_NBITS <= 16
+                {
+                    return Self::from_bits( trigonometry::lut::atan2_bits(self.to_bits() as i64, other.to_bits() as i64, Self::FRAC_NBITS) as _ );
+                }
                 return cordic::atan2(*self, other);
             }
             /// Take the atan of self. Implementation varies with type.
@@ -323,6 +711,38 @@ macro_rules! impl_mixed_num_for_fixed_signed{
                 return cordic::exp(*self);
             }
         }
+
+        impl MixedBesselI0 for $T
+        {
+            #[inline(always)]
+            fn mixed_bessel_i0(&self) -> Self {
+                return special::bessel_i0(*self);
+            }
+        }
+
+        impl MixedGamma for $T
+        {
+            #[inline(always)]
+            fn mixed_gamma(&self) -> Self {
+                return special::gamma(*self);
+            }
+            #[inline(always)]
+            fn mixed_lgamma(&self) -> Self {
+                return special::lgamma(*self);
+            }
+        }
+
+        impl MixedErf for $T
+        {
+            #[inline(always)]
+            fn mixed_erf(&self) -> Self {
+                return special::erf(*self);
+            }
+            #[inline(always)]
+            fn mixed_erfc(&self) -> Self {
+                return special::erfc(*self);
+            }
+        }
     }
 }
 
@@ -584,3 +1004,47 @@ impl_mixed_num_for_fixed_signed!(fixed::FixedI64<fixed::types::extra::U61>);
 //impl_mixed_num_for_fixed_signed!(fixed::FixedI64<fixed::types::extra::U62>);
 //impl_mixed_num_for_fixed_signed!(fixed::FixedI64<fixed::types::extra::U63>);
 //impl_mixed_num_for_fixed_signed!(fixed::FixedI64<fixed::types::extra::U64>);
+
+macro_rules! impl_mixed_num_conversion_fixed_to_fixed{
+    ( $T1:ident, $L1:ident, $T2:ident, $L2:ident ) => {
+        impl <F1, F2> MixedNumConversion<fixed::$T2<F2>> for fixed::$T1<F1>
+            where F1: fixed::types::extra::$L1,
+                  F2: fixed::types::extra::$L2
+        {
+            /// Convert between fixed-point types, truncating the fractional bits.
+            /// Panics in debug builds if the value does not fit the target type.
+            #[inline(always)]
+            fn mixed_from_num( number:fixed::$T2<F2> ) -> Self {
+                return Self::from_num(number);
+            }
+            /// Convert between fixed-point types, truncating the fractional bits.
+            /// Panics in debug builds if the value does not fit the target type.
+            #[inline(always)]
+            fn mixed_to_num( &self ) -> fixed::$T2<F2> {
+                return self.to_num::<fixed::$T2<F2>>();
+            }
+        }
+    }
+}
+
+macro_rules! impl_mixed_num_conversion_fixed_to_all_fixed{
+    ( $T1:ident, $L1:ident ) => {
+        impl_mixed_num_conversion_fixed_to_fixed!($T1, $L1, FixedI8,  LeEqU8);
+        impl_mixed_num_conversion_fixed_to_fixed!($T1, $L1, FixedI16, LeEqU16);
+        impl_mixed_num_conversion_fixed_to_fixed!($T1, $L1, FixedI32, LeEqU32);
+        impl_mixed_num_conversion_fixed_to_fixed!($T1, $L1, FixedI64, LeEqU64);
+        impl_mixed_num_conversion_fixed_to_fixed!($T1, $L1, FixedU8,  LeEqU8);
+        impl_mixed_num_conversion_fixed_to_fixed!($T1, $L1, FixedU16, LeEqU16);
+        impl_mixed_num_conversion_fixed_to_fixed!($T1, $L1, FixedU32, LeEqU32);
+        impl_mixed_num_conversion_fixed_to_fixed!($T1, $L1, FixedU64, LeEqU64);
+    }
+}
+
+impl_mixed_num_conversion_fixed_to_all_fixed!(FixedI8,  LeEqU8);
+impl_mixed_num_conversion_fixed_to_all_fixed!(FixedI16, LeEqU16);
+impl_mixed_num_conversion_fixed_to_all_fixed!(FixedI32, LeEqU32);
+impl_mixed_num_conversion_fixed_to_all_fixed!(FixedI64, LeEqU64);
+impl_mixed_num_conversion_fixed_to_all_fixed!(FixedU8,  LeEqU8);
+impl_mixed_num_conversion_fixed_to_all_fixed!(FixedU16, LeEqU16);
+impl_mixed_num_conversion_fixed_to_all_fixed!(FixedU32, LeEqU32);
+impl_mixed_num_conversion_fixed_to_all_fixed!(FixedU64, LeEqU64);
@@ -92,7 +92,7 @@ macro_rules! impl_mixed_num_for_primitive{
         {
             #[inline(always)]
             fn mixed_wrap_phase(&self) -> Self {
-                return trigonometry::wrap_phase(*self);
+                return trigonometry::wrap_half_period(*self, core::f64::consts::PI);
             }
         }
 
@@ -171,6 +171,160 @@ macro_rules! impl_mixed_num_for_primitive{
             }
         }
 
+        impl MixedE for $T
+        {
+            #[inline(always)]
+            fn mixed_e() -> Self {
+                return core::f64::consts::E as $T;
+            }
+        }
+
+        impl MixedLn2 for $T
+        {
+            #[inline(always)]
+            fn mixed_ln2() -> Self {
+                return core::f64::consts::LN_2 as $T;
+            }
+        }
+
+        impl MixedLn10 for $T
+        {
+            #[inline(always)]
+            fn mixed_ln10() -> Self {
+                return core::f64::consts::LN_10 as $T;
+            }
+        }
+
+        impl MixedSqrt2 for $T
+        {
+            #[inline(always)]
+            fn mixed_sqrt2() -> Self {
+                return core::f64::consts::SQRT_2 as $T;
+            }
+        }
+
+        impl MixedFrac1Sqrt2 for $T
+        {
+            #[inline(always)]
+            fn mixed_frac_1_sqrt2() -> Self {
+                return core::f64::consts::FRAC_1_SQRT_2 as $T;
+            }
+        }
+
+        impl MixedFracPi2 for $T
+        {
+            #[inline(always)]
+            fn mixed_frac_pi_2() -> Self {
+                return core::f64::consts::FRAC_PI_2 as $T;
+            }
+        }
+
+        impl MixedFrac1Pi for $T
+        {
+            #[inline(always)]
+            fn mixed_frac_1_pi() -> Self {
+                return core::f64::consts::FRAC_1_PI as $T;
+            }
+        }
+
+        impl MixedEpsilon for $T
+        {
+            #[inline(always)]
+            fn mixed_epsilon() -> Self {
+                return <$T>::EPSILON;
+            }
+        }
+
+        impl MixedMinMax for $T
+        {
+            #[inline(always)]
+            fn mixed_min(&self, other:Self) -> Self {
+                return <$T>::min(*self, other);
+            }
+            #[inline(always)]
+            fn mixed_max(&self, other:Self) -> Self {
+                return <$T>::max(*self, other);
+            }
+            #[inline(always)]
+            fn mixed_clamp(&self, min:Self, max:Self) -> Self {
+                return self.mixed_max(min).mixed_min(max);
+            }
+            #[inline(always)]
+            fn mixed_min_by_mag(&self, other:Self) -> Self {
+                if other.is_nan() || (!self.is_nan() && self.abs() <= other.abs())
+                {
+                    return *self;
+                }
+                return other;
+            }
+            #[inline(always)]
+            fn mixed_max_by_mag(&self, other:Self) -> Self {
+                if other.is_nan() || (!self.is_nan() && other.abs() <= self.abs())
+                {
+                    return *self;
+                }
+                return other;
+            }
+        }
+
+        impl MixedClassify for $T
+        {
+            #[inline(always)]
+            fn mixed_is_nan(&self) -> bool {
+                return self.is_nan();
+            }
+            #[inline(always)]
+            fn mixed_is_infinite(&self) -> bool {
+                return self.is_infinite();
+            }
+            #[inline(always)]
+            fn mixed_is_finite(&self) -> bool {
+                return self.is_finite();
+            }
+            /// Floats overflow to infinity.
+            #[inline(always)]
+            fn mixed_is_saturated(&self) -> bool {
+                return self.is_infinite();
+            }
+            #[inline(always)]
+            fn mixed_classify(&self) -> MixedCategory {
+                return match self.classify() {
+                    core::num::FpCategory::Nan       => MixedCategory::Nan,
+                    core::num::FpCategory::Infinite  => MixedCategory::Infinite,
+                    core::num::FpCategory::Zero      => MixedCategory::Zero,
+                    core::num::FpCategory::Subnormal => MixedCategory::Subnormal,
+                    core::num::FpCategory::Normal    => MixedCategory::Normal,
+                };
+            }
+        }
+
+        impl MixedTypeInfo for $T
+        {
+            const IS_FIXED: bool   = false;
+            const IS_FLOAT: bool   = true;
+            const IS_INTEGER: bool = false;
+            const IS_SIGNED: bool  = true;
+            const FRAC_BITS: u32   = <$T>::MANTISSA_DIGITS-1;
+            const INT_BITS: u32    = <$T>::MAX_EXP as u32;
+            const EPSILON: Self    = <$T>::EPSILON;
+
+            /// The spacing to the next float of larger magnitude.
+            /// The ulp of `MAX` is the spacing below it, and infinite and NaN values return themselves.
+            #[inline(always)]
+            fn mixed_ulp(&self) -> Self {
+                let x = self.abs();
+                if !x.is_finite()
+                {
+                    return x;
+                }
+                if x == <$T>::MAX
+                {
+                    return x - <$T>::from_bits(x.to_bits()-1);
+                }
+                return <$T>::from_bits(x.to_bits()+1) - x;
+            }
+        }
+
         impl MixedConsts for $T
         {
         }
@@ -200,6 +354,76 @@ macro_rules! impl_mixed_num_for_primitive{
                 return exponent.mixed_exp10();
             }
         }
+
+        impl MixedTrigonometryAccuracy for $T
+        {
+            #[inline(always)]
+            fn mixed_sin_fast(&self) -> Self {
+                return trigonometry::sin(*self);
+            }
+            #[inline(always)]
+            fn mixed_sin_precise(&self) -> Self {
+                return self.mixed_sin();
+            }
+            /// cos(x) = sin(π/2-|x|), which stays in the range of the polynomial.
+            #[inline(always)]
+            fn mixed_cos_fast(&self) -> Self {
+                return trigonometry::sin(Self::mixed_pi()/Self::mixed_from_num(2) - self.mixed_abs());
+            }
+            #[inline(always)]
+            fn mixed_cos_precise(&self) -> Self {
+                return self.mixed_cos();
+            }
+            #[inline(always)]
+            fn mixed_atan2_fast(&self, other:Self) -> Self {
+                return trigonometry::atan::atan2(*self, other);
+            }
+            #[inline(always)]
+            fn mixed_atan2_precise(&self, other:Self) -> Self {
+                return self.mixed_atan2(other);
+            }
+        }
+
+        impl MixedAngleUnits for $T
+        {
+            #[inline(always)]
+            fn mixed_to_degrees(&self) -> Self {
+                return *self*Self::mixed_from_num(180f64/core::f64::consts::PI);
+            }
+            #[inline(always)]
+            fn mixed_to_radians(&self) -> Self {
+                return *self*Self::mixed_from_num(core::f64::consts::PI/180f64);
+            }
+            #[inline(always)]
+            fn mixed_to_turns(&self) -> Self {
+                return *self*Self::mixed_from_num(1f64/core::f64::consts::TAU);
+            }
+            #[inline(always)]
+            fn mixed_sind(&self) -> Self {
+                return trigonometry::sind(*self);
+            }
+            #[inline(always)]
+            fn mixed_cosd(&self) -> Self {
+                return trigonometry::cosd(*self);
+            }
+            #[inline(always)]
+            fn mixed_sincosd(&self) -> (Self, Self) {
+                return trigonometry::sincosd(*self);
+            }
+            #[inline(always)]
+            fn mixed_atan2d(&self, other:Self) -> Self {
+                return trigonometry::atan2d(*self, other);
+            }
+        }
+
+        impl MixedBesselI0 for $T
+        {
+            /// Not available in `libm`, calculated by its power series.
+            #[inline(always)]
+            fn mixed_bessel_i0(&self) -> Self {
+                return special::bessel_i0(*self);
+            }
+        }
     }
 }
 
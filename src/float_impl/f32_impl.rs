@@ -58,6 +58,38 @@ impl MixedTrigonometry for f32
 { 
 }
 
+#[cfg(not(all(feature = "simd", target_arch = "x86_64")))]
+impl MixedSinSlice for f32
+{
+}
+
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+impl MixedSinSlice for f32
+{
+    #[inline(always)]
+    fn mixed_sin_slice( input: &[Self], output: &mut [Self] ) {
+        crate::simd::sin_f32(input, output);
+    }
+    #[inline(always)]
+    fn mixed_sincos_slice( input: &[Self], sin_output: &mut [Self], cos_output: &mut [Self] ) {
+        crate::simd::sincos_f32(input, sin_output, cos_output);
+    }
+}
+
+#[cfg(not(all(feature = "simd", target_arch = "x86_64")))]
+impl MixedCosSlice for f32
+{
+}
+
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+impl MixedCosSlice for f32
+{
+    #[inline(always)]
+    fn mixed_cos_slice( input: &[Self], output: &mut [Self] ) {
+        crate::simd::cos_f32(input, output);
+    }
+}
+
 impl MixedTan for f32
 {
     #[inline(always)]
@@ -107,6 +139,48 @@ impl MixedSqrt for f32
     }
 }
 
+#[cfg(not(all(feature = "simd", target_arch = "x86_64")))]
+impl MixedAtanSlice for f32
+{
+}
+
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+impl MixedAtanSlice for f32
+{
+    #[inline(always)]
+    fn mixed_atan2_poly_slice( y: &[Self], x: &[Self], output: &mut [Self] ) {
+        crate::simd::atan2_poly_f32(y, x, output);
+    }
+}
+
+#[cfg(not(all(feature = "simd", target_arch = "x86_64")))]
+impl MixedSqrtSlice for f32
+{
+}
+
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+impl MixedSqrtSlice for f32
+{
+    #[inline(always)]
+    fn mixed_sqrt_into( input: &[Self], output: &mut [Self] ) {
+        crate::simd::sqrt_f32(input, output);
+    }
+}
+
+#[cfg(not(all(feature = "simd", target_arch = "x86_64")))]
+impl MixedCartesianSlice for f32
+{
+}
+
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+impl MixedCartesianSlice for f32
+{
+    #[inline(always)]
+    fn mixed_mul_slice( a: &[Cartesian<Self>], b: &[Cartesian<Self>], output: &mut [Cartesian<Self>] ) {
+        crate::simd::cartesian_mul_f32(a, b, output);
+    }
+}
+
 impl MixedCbrt for f32
 {
     #[inline(always)]
@@ -130,6 +204,113 @@ impl MixedFloor for f32
     }
 }
 
+impl MixedHypot for f32
+{
+    #[inline(always)]
+    fn mixed_hypot(&self, other:Self) -> Self {
+        return libm::hypotf(*self, other);
+    }
+    #[inline(always)]
+    fn mixed_hypot_fast(&self, other:Self) -> Self {
+        let a = self.abs();
+        let b = other.abs();
+        let (max, min) = if a < b { (b, a) } else { (a, b) };
+        return (max + 0.15625*min).max(0.84375*max + 0.5546875*min);
+    }
+}
+
+impl MixedRecip for f32
+{
+    #[inline(always)]
+    fn mixed_recip(&self) -> Self {
+        return 1.0 / *self;
+    }
+    /// The magic constant subtracted from the bits estimates the reciprocal, refined by one Newton-Raphson iteration.
+    #[inline(always)]
+    fn mixed_recip_fast(&self) -> Self {
+        let x = self.abs();
+        // Zero, subnormal and non-finite values, and values from 2^126 with a subnormal reciprocal, are out of reach of the estimate.
+        if !x.is_normal() || 0x7E80_0000u32 <= x.to_bits()
+        {
+            return 1.0 / *self;
+        }
+        let mut y = f32::from_bits( 0x7EF3_11C7u32 - x.to_bits() );
+        y = y*(2.0 - x*y);
+        return y.copysign(*self);
+    }
+}
+
+impl MixedRsqrt for f32
+{
+    #[inline(always)]
+    fn mixed_rsqrt(&self) -> Self {
+        return 1.0 / libm::sqrtf(*self);
+    }
+    /// The magic constant subtracted from half the bits estimates the reciprocal square root, refined by one Newton-Raphson iteration.
+    #[inline(always)]
+    fn mixed_rsqrt_fast(&self) -> Self {
+        // Negative, zero, subnormal and non-finite values are out of reach of the estimate.
+        if !self.is_normal() || self.is_sign_negative()
+        {
+            return self.mixed_rsqrt();
+        }
+        let mut y = f32::from_bits( 0x5F37_5A86u32 - (self.to_bits() >> 1) );
+        y = y*(1.5 - 0.5*self*y*y);
+        return y;
+    }
+}
+
+impl MixedRound for f32
+{
+    #[inline(always)]
+    fn mixed_round(&self) -> Self {
+        return libm::roundf(*self);
+    }
+    /// Uses `rint`, which rounds ties to even in the default rounding mode.
+    #[inline(always)]
+    fn mixed_round_ties_even(&self) -> Self {
+        return libm::rintf(*self);
+    }
+}
+
+impl MixedTrunc for f32
+{
+    #[inline(always)]
+    fn mixed_trunc(&self) -> Self {
+        return libm::truncf(*self);
+    }
+    #[inline(always)]
+    fn mixed_fract(&self) -> Self {
+        return *self - self.mixed_trunc();
+    }
+}
+
+impl MixedEuclid for f32
+{
+    #[inline(always)]
+    fn mixed_div_euclid(&self, rhs:Self) -> Self {
+        let quotient = libm::truncf(*self / rhs);
+        if *self % rhs < 0.0
+        {
+            if rhs > 0.0
+            {
+                return quotient - 1.0;
+            }
+            return quotient + 1.0;
+        }
+        return quotient;
+    }
+    #[inline(always)]
+    fn mixed_rem_euclid(&self, rhs:Self) -> Self {
+        let remainder = *self % rhs;
+        if remainder < 0.0
+        {
+            return remainder + rhs.abs();
+        }
+        return remainder;
+    }
+}
+
 impl MixedExp for f32
 {
     #[inline(always)]
@@ -184,4 +365,28 @@ impl Mixedlog2 for f32
     fn mixed_log2(&self) -> Self {
         return libm::log2f(*self);
     }
-}
\ No newline at end of file
+}
+
+impl MixedGamma for f32
+{
+    #[inline(always)]
+    fn mixed_gamma(&self) -> Self {
+        return libm::tgammaf(*self);
+    }
+    #[inline(always)]
+    fn mixed_lgamma(&self) -> Self {
+        return libm::lgammaf(*self);
+    }
+}
+
+impl MixedErf for f32
+{
+    #[inline(always)]
+    fn mixed_erf(&self) -> Self {
+        return libm::erff(*self);
+    }
+    #[inline(always)]
+    fn mixed_erfc(&self) -> Self {
+        return libm::erfcf(*self);
+    }
+}
@@ -58,6 +58,14 @@ impl MixedTrigonometry for f64
 { 
 }
 
+impl MixedSinSlice for f64
+{
+}
+
+impl MixedCosSlice for f64
+{
+}
+
 impl MixedTan for f64
 {
     #[inline(always)]
@@ -78,6 +86,18 @@ impl MixedTanh for f64
     }
 }
 
+impl MixedAtanSlice for f64
+{
+}
+
+impl MixedSqrtSlice for f64
+{
+}
+
+impl MixedCartesianSlice for f64
+{
+}
+
 impl MixedCbrt for f64
 {
     #[inline(always)]
@@ -130,6 +150,113 @@ impl MixedFloor for f64
     }
 }
 
+impl MixedHypot for f64
+{
+    #[inline(always)]
+    fn mixed_hypot(&self, other:Self) -> Self {
+        return libm::hypot(*self, other);
+    }
+    #[inline(always)]
+    fn mixed_hypot_fast(&self, other:Self) -> Self {
+        let a = self.abs();
+        let b = other.abs();
+        let (max, min) = if a < b { (b, a) } else { (a, b) };
+        return (max + 0.15625*min).max(0.84375*max + 0.5546875*min);
+    }
+}
+
+impl MixedRecip for f64
+{
+    #[inline(always)]
+    fn mixed_recip(&self) -> Self {
+        return 1.0 / *self;
+    }
+    /// The magic constant subtracted from the bits estimates the reciprocal, refined by one Newton-Raphson iteration.
+    #[inline(always)]
+    fn mixed_recip_fast(&self) -> Self {
+        let x = self.abs();
+        // Zero, subnormal and non-finite values, and values from 2^1022 with a subnormal reciprocal, are out of reach of the estimate.
+        if !x.is_normal() || 0x7FD0_0000_0000_0000u64 <= x.to_bits()
+        {
+            return 1.0 / *self;
+        }
+        let mut y = f64::from_bits( 0x7FDE_6238_E000_0000u64 - x.to_bits() );
+        y = y*(2.0 - x*y);
+        return y.copysign(*self);
+    }
+}
+
+impl MixedRsqrt for f64
+{
+    #[inline(always)]
+    fn mixed_rsqrt(&self) -> Self {
+        return 1.0 / libm::sqrt(*self);
+    }
+    /// The magic constant subtracted from half the bits estimates the reciprocal square root, refined by one Newton-Raphson iteration.
+    #[inline(always)]
+    fn mixed_rsqrt_fast(&self) -> Self {
+        // Negative, zero, subnormal and non-finite values are out of reach of the estimate.
+        if !self.is_normal() || self.is_sign_negative()
+        {
+            return self.mixed_rsqrt();
+        }
+        let mut y = f64::from_bits( 0x5FE6_EB50_C7B5_37A9u64 - (self.to_bits() >> 1) );
+        y = y*(1.5 - 0.5*self*y*y);
+        return y;
+    }
+}
+
+impl MixedRound for f64
+{
+    #[inline(always)]
+    fn mixed_round(&self) -> Self {
+        return libm::round(*self);
+    }
+    /// Uses `rint`, which rounds ties to even in the default rounding mode.
+    #[inline(always)]
+    fn mixed_round_ties_even(&self) -> Self {
+        return libm::rint(*self);
+    }
+}
+
+impl MixedTrunc for f64
+{
+    #[inline(always)]
+    fn mixed_trunc(&self) -> Self {
+        return libm::trunc(*self);
+    }
+    #[inline(always)]
+    fn mixed_fract(&self) -> Self {
+        return *self - self.mixed_trunc();
+    }
+}
+
+impl MixedEuclid for f64
+{
+    #[inline(always)]
+    fn mixed_div_euclid(&self, rhs:Self) -> Self {
+        let quotient = libm::trunc(*self / rhs);
+        if *self % rhs < 0.0
+        {
+            if rhs > 0.0
+            {
+                return quotient - 1.0;
+            }
+            return quotient + 1.0;
+        }
+        return quotient;
+    }
+    #[inline(always)]
+    fn mixed_rem_euclid(&self, rhs:Self) -> Self {
+        let remainder = *self % rhs;
+        if remainder < 0.0
+        {
+            return remainder + rhs.abs();
+        }
+        return remainder;
+    }
+}
+
 impl MixedExp for f64
 {
     #[inline(always)]
@@ -183,4 +310,28 @@ impl Mixedlog2 for f64
     fn mixed_log2(&self) -> Self {
         return libm::log2(*self);
     }
-}
\ No newline at end of file
+}
+
+impl MixedGamma for f64
+{
+    #[inline(always)]
+    fn mixed_gamma(&self) -> Self {
+        return libm::tgamma(*self);
+    }
+    #[inline(always)]
+    fn mixed_lgamma(&self) -> Self {
+        return libm::lgamma(*self);
+    }
+}
+
+impl MixedErf for f64
+{
+    #[inline(always)]
+    fn mixed_erf(&self) -> Self {
+        return libm::erf(*self);
+    }
+    #[inline(always)]
+    fn mixed_erfc(&self) -> Self {
+        return libm::erfc(*self);
+    }
+}
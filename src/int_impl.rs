@@ -68,6 +68,145 @@ macro_rules! impl_mixed_num_for_primitive{
                 return 1 as $T;
             }
         }
+
+        impl MixedRound for $T
+        {
+            #[inline(always)]
+            fn mixed_round(&self) -> Self {
+                return *self;
+            }
+            #[inline(always)]
+            fn mixed_round_ties_even(&self) -> Self {
+                return *self;
+            }
+        }
+
+        impl MixedTrunc for $T
+        {
+            #[inline(always)]
+            fn mixed_trunc(&self) -> Self {
+                return *self;
+            }
+            #[inline(always)]
+            fn mixed_fract(&self) -> Self {
+                return 0;
+            }
+        }
+
+        impl MixedEuclid for $T
+        {
+            /// Only `MIN/-1` overflows, which saturates to `MAX`.
+            #[inline(always)]
+            fn mixed_div_euclid(&self, rhs:Self) -> Self {
+                if rhs == 0
+                {
+                    panic!("attempt to divide by zero");
+                }
+                return self.checked_div_euclid(rhs).unwrap_or(<$T>::MAX);
+            }
+            /// The remainder of `MIN/-1` is zero, without overflow.
+            #[inline(always)]
+            fn mixed_rem_euclid(&self, rhs:Self) -> Self {
+                return self.wrapping_rem_euclid(rhs);
+            }
+        }
+
+        impl MixedMinMax for $T
+        {
+            #[inline(always)]
+            fn mixed_min(&self, other:Self) -> Self {
+                return core::cmp::Ord::min(*self, other);
+            }
+            #[inline(always)]
+            fn mixed_max(&self, other:Self) -> Self {
+                return core::cmp::Ord::max(*self, other);
+            }
+            #[inline(always)]
+            fn mixed_clamp(&self, min:Self, max:Self) -> Self {
+                return self.mixed_max(min).mixed_min(max);
+            }
+            /// Compares the distance to zero, which does not overflow at `MIN`.
+            #[inline(always)]
+            fn mixed_min_by_mag(&self, other:Self) -> Self {
+                if self.abs_diff(0) <= other.abs_diff(0)
+                {
+                    return *self;
+                }
+                return other;
+            }
+            /// Compares the distance to zero, which does not overflow at `MIN`.
+            #[inline(always)]
+            fn mixed_max_by_mag(&self, other:Self) -> Self {
+                if other.abs_diff(0) <= self.abs_diff(0)
+                {
+                    return *self;
+                }
+                return other;
+            }
+        }
+
+        impl MixedClassify for $T
+        {
+            #[inline(always)]
+            fn mixed_is_nan(&self) -> bool {
+                return false;
+            }
+            #[inline(always)]
+            fn mixed_is_infinite(&self) -> bool {
+                return false;
+            }
+            #[inline(always)]
+            fn mixed_is_finite(&self) -> bool {
+                return true;
+            }
+            /// At `MIN` or `MAX`, except zero of unsigned types.
+            #[inline(always)]
+            fn mixed_is_saturated(&self) -> bool {
+                return *self == <$T>::MAX || (*self == <$T>::MIN && <$T>::MIN != 0);
+            }
+            #[inline(always)]
+            fn mixed_classify(&self) -> MixedCategory {
+                if self.mixed_is_saturated()
+                {
+                    return MixedCategory::Saturated;
+                }
+                if *self == 0
+                {
+                    return MixedCategory::Zero;
+                }
+                return MixedCategory::Normal;
+            }
+        }
+
+        impl MixedTypeInfo for $T
+        {
+            const IS_FIXED: bool   = false;
+            const IS_FLOAT: bool   = false;
+            const IS_INTEGER: bool = true;
+            const IS_SIGNED: bool  = <$T>::MIN != 0;
+            const FRAC_BITS: u32   = 0;
+            const INT_BITS: u32    = <$T>::BITS;
+            const EPSILON: Self    = 1;
+
+            #[inline(always)]
+            fn mixed_ulp(&self) -> Self {
+                return 1;
+            }
+        }
+
+        impl MixedPi for $T
+        {
+            /// π rounded to an integer, 3.
+            #[inline(always)]
+            fn mixed_pi() -> Self {
+                return 3 as $T;
+            }
+            /// 2π rounded to an integer, 6.
+            #[inline(always)]
+            fn mixed_tau() -> Self {
+                return 6 as $T;
+            }
+        }
     }
 }
 
@@ -103,6 +242,20 @@ macro_rules! impl_mixed_num_unsigned{
                 return *self;
             }
         }
+
+        impl MixedHypot for $T
+        {
+            #[inline(always)]
+            fn mixed_hypot(&self, other:Self) -> Self {
+                let hypot = trigonometry::sqrt::hypot_bits(*self as u128, other as u128);
+                return hypot.min(<$T>::MAX as u128) as $T;
+            }
+            #[inline(always)]
+            fn mixed_hypot_fast(&self, other:Self) -> Self {
+                let hypot = trigonometry::sqrt::hypot_fast_bits(*self as u128, other as u128);
+                return hypot.min(<$T>::MAX as u128) as $T;
+            }
+        }
     }
 }
 
@@ -145,10 +298,66 @@ macro_rules! impl_mixed_num_signed{
                 }
             }
         }
+
+        impl MixedHypot for $T
+        {
+            #[inline(always)]
+            fn mixed_hypot(&self, other:Self) -> Self {
+                let hypot = trigonometry::sqrt::hypot_bits(self.unsigned_abs() as u128, other.unsigned_abs() as u128);
+                return hypot.min(<$T>::MAX as u128) as $T;
+            }
+            #[inline(always)]
+            fn mixed_hypot_fast(&self, other:Self) -> Self {
+                let hypot = trigonometry::sqrt::hypot_fast_bits(self.unsigned_abs() as u128, other.unsigned_abs() as u128);
+                return hypot.min(<$T>::MAX as u128) as $T;
+            }
+        }
         
         impl MixedNumSigned for $T
         {   
         }
+
+        impl MixedAngleUnits for $T
+        {
+            #[inline(always)]
+            fn mixed_to_degrees(&self) -> Self {
+                return libm::round(*self as f64*180f64/core::f64::consts::PI) as $T;
+            }
+            #[inline(always)]
+            fn mixed_to_radians(&self) -> Self {
+                return libm::round(*self as f64*core::f64::consts::PI/180f64) as $T;
+            }
+            #[inline(always)]
+            fn mixed_to_turns(&self) -> Self {
+                return libm::round(*self as f64/core::f64::consts::TAU) as $T;
+            }
+            #[inline(always)]
+            fn mixed_sind(&self) -> Self {
+                return self.mixed_sincosd().0;
+            }
+            #[inline(always)]
+            fn mixed_cosd(&self) -> Self {
+                return self.mixed_sincosd().1;
+            }
+            /// Reduced to the -180=<x<180 range before the conversion to `f64`.
+            #[inline(always)]
+            fn mixed_sincosd(&self) -> (Self, Self) {
+                let (sin, cos) = trigonometry::sincosd(self.mixed_wrap_degrees() as f64);
+                return (libm::round(sin) as $T, libm::round(cos) as $T);
+            }
+            #[inline(always)]
+            fn mixed_atan2d(&self, other:Self) -> Self {
+                return libm::round(trigonometry::atan2d(*self as f64, other as f64)) as $T;
+            }
+        }
+
+        impl MixedWrapPhase for $T
+        {
+            #[inline(always)]
+            fn mixed_wrap_phase(&self) -> Self {
+                return trigonometry::wrap_half_period(*self, core::f64::consts::PI);
+            }
+        }
     }
 }
 
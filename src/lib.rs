@@ -78,6 +78,11 @@
 extern crate std;
 
 pub mod trigonometry;
+pub mod special;
+pub mod polynomial;
+
+pub mod angle;
+pub use angle::*;
 
 pub mod traits;
 pub use traits::*;
@@ -92,5 +97,10 @@ pub mod complex;
 #[allow(unused)]
 pub use complex::*;
 
+pub mod dsp;
+
 mod int_impl;
-pub use int_impl::*;
\ No newline at end of file
+pub use int_impl::*;
+
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+mod simd;
\ No newline at end of file
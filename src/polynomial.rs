@@ -0,0 +1,369 @@
+//! Polynomials generic over the number type, for evaluating and designing function approximations.
+//!
+//! The coefficients are stored in ascending order, `p(x) = c[0] + c[1]*x + ... + c[N-1]*x^(N-1)`.
+//!
+//! ## Example
+//!
+//! ```
+//! use mixed_num::*;
+//! use mixed_num::polynomial::*;
+//! use fixed::{types::extra::U24, FixedI32};
+//!
+//! // exp(x) on 0=<x<1, fitted in f64 and emitted as fixed-point coefficients.
+//! let p = Polynomial::<FixedI32<U24>, 8>::chebyshev_fit( libm::exp, 0f64, 1f64 );
+//!
+//! let y = p.horner( FixedI32::<U24>::from_num(0.5) );
+//! assert!{ (y.to_num::<f64>()-0.5f64.exp()).abs() < 1e-6 };
+//! ```
+
+use crate::*;
+
+/// A function approximation, which can be plugged into the generic trigonometric functions, e.g. `trigonometry::sin_with`.
+///
+/// Implemented for polynomials, and for closures and functions `Fn(T) -> T`.
+pub trait MixedApproximation<T> {
+    /// Evaluate the approximation at `x`.
+    fn approximate( &self, x: T ) -> T;
+}
+
+impl <T, F> MixedApproximation<T> for F
+    where F: Fn(T) -> T
+{
+    #[inline(always)]
+    fn approximate( &self, x: T ) -> T
+    {
+        return self(x);
+    }
+}
+
+/// Polynomial with `N` coefficients, of degree `N-1`.
+///
+/// ## Example
+///
+/// ```
+/// use mixed_num::polynomial::*;
+///
+/// // 1 - 3x + 2x^2 = (1-x)(1-2x)
+/// let p = Polynomial::new([1f64, -3f64, 2f64]);
+/// assert_eq!{ p.horner(3f64), 10f64 };
+/// assert_eq!{ p.estrin(3f64), 10f64 };
+///
+/// let dp: Polynomial<f64, 2> = p.derivative();
+/// assert_eq!{ dp.coeffs, [-3f64, 4f64] };
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Polynomial<T, const N: usize> {
+    /// The coefficients in ascending order.
+    pub coeffs: [T; N],
+}
+
+impl <T, const N: usize> Polynomial<T, N>
+{
+    /// Create a polynomial from coefficients in ascending order.
+    pub fn new( coeffs: [T; N] ) -> Self
+    {
+        return Polynomial{ coeffs };
+    }
+}
+
+impl <T, const N: usize> Polynomial<T, N>
+    where T: MixedReal + MixedOps + MixedZero
+{
+    /// Create a polynomial from `f64` coefficients in ascending order.
+    /// The coefficients are rounded to the nearest value of `T` for fixed-point types.
+    pub fn from_f64( coeffs: [f64; N] ) -> Self
+    {
+        return Polynomial{ coeffs: coeffs.map( |c| T::mixed_from_num(c) ) };
+    }
+
+    /// The degree of the polynomial, the index of the highest non-zero coefficient.
+    /// Zero for the zero polynomial.
+    pub fn degree( &self ) -> usize
+    {
+        return self.coeffs.iter().rposition( |c| *c != T::mixed_zero() ).unwrap_or(0);
+    }
+
+    /// Evaluate the polynomial at `x` with Horner's method.
+    ///
+    /// Uses `N-1` multiplications, and no powers of `x`, which keeps the intermediate values in range for fixed-point types.
+    pub fn horner( &self, x: T ) -> T
+    {
+        let mut y = T::mixed_zero();
+        for c in self.coeffs.iter().rev()
+        {
+            y = y*x + *c;
+        }
+        return y;
+    }
+
+    /// Evaluate the polynomial at `x` with Estrin's scheme.
+    ///
+    /// Pairs of terms are combined with increasing powers `x, x^2, x^4, ...`.
+    /// The products within each level are independent, which suits pipelined and SIMD processors.
+    /// The powers of `x` must be representable by `T`.
+    pub fn estrin( &self, x: T ) -> T
+    {
+        if N == 0
+        {
+            return T::mixed_zero();
+        }
+
+        let mut terms = self.coeffs;
+        let mut len = N;
+        let mut power = x;
+        while 1 < len
+        {
+            for i in 0..len/2
+            {
+                terms[i] = terms[2*i] + terms[2*i+1]*power;
+            }
+            if len % 2 == 1
+            {
+                terms[len/2] = terms[len-1];
+            }
+            len = len.div_ceil(2);
+            if 1 < len
+            {
+                power = power*power;
+            }
+        }
+        return terms[0];
+    }
+
+    /// The derivative of the polynomial.
+    ///
+    /// `M` must be at least `N-1`, and the coefficients above the degree of the derivative are zero.
+    pub fn derivative<const M: usize>( &self ) -> Polynomial<T, M>
+    {
+        assert!{ N <= M+1, "The derivative needs {} coefficients.", N.saturating_sub(1) };
+
+        let mut coeffs = [T::mixed_zero(); M];
+        for k in 1..N
+        {
+            // k*c[k] by repeated addition, as k may not be representable by T.
+            for _ in 0..k
+            {
+                coeffs[k-1] += self.coeffs[k];
+            }
+        }
+        return Polynomial{ coeffs };
+    }
+
+    /// The integral of the polynomial, with the integration constant `constant`.
+    ///
+    /// `M` must be at least `N+1`, and the coefficients above the degree of the integral are zero.
+    pub fn integral<const M: usize>( &self, constant: T ) -> Polynomial<T, M>
+    {
+        assert!{ N < M, "The integral needs {} coefficients.", N+1 };
+
+        let mut coeffs = [T::mixed_zero(); M];
+        coeffs[0] = constant;
+        for k in 0..N
+        {
+            coeffs[k+1] = self.coeffs[k]*T::mixed_from_num(1f64/((k+1) as f64));
+        }
+        return Polynomial{ coeffs };
+    }
+
+    /// Fit the polynomial to `f` on the `a=<x=<b` interval, by interpolation in the `N` Chebyshev nodes.
+    ///
+    /// The fit is calculated in `f64`, and is close to the minimax polynomial of degree `N-1`.
+    /// The coefficients are converted to `T`, which must hold them.
+    /// The conversion to ascending powers is ill-conditioned for high degrees on intervals far from zero.
+    ///
+    /// ## Arguments
+    ///
+    /// * `f` - The function to approximate.
+    /// * `a` - The start of the interval.
+    /// * `b` - The end of the interval.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use mixed_num::polynomial::*;
+    ///
+    /// let p = Polynomial::<f64, 12>::chebyshev_fit( libm::sin, -1.6f64, 1.6f64 );
+    /// for i in 0..=100 {
+    ///     let x = -1.6f64+3.2f64*(i as f64)/100f64;
+    ///     assert!{ (p.horner(x)-x.sin()).abs() < 1e-9 };
+    /// }
+    /// ```
+    pub fn chebyshev_fit( f: fn(f64) -> f64, a: f64, b: f64 ) -> Self
+    {
+        if N == 0
+        {
+            return Polynomial{ coeffs: [T::mixed_zero(); N] };
+        }
+
+        // Chebyshev series coefficients, from the function values in the nodes cos(π(j+1/2)/N).
+        let n = N as f64;
+        let mut series = [0f64; N];
+        for j in 0..N
+        {
+            let theta = core::f64::consts::PI*(j as f64 + 0.5f64)/n;
+            let y = f( 0.5f64*(a+b) + 0.5f64*(b-a)*libm::cos(theta) );
+            for (k, c) in series.iter_mut().enumerate()
+            {
+                *c += 2f64/n*y*libm::cos(k as f64*theta);
+            }
+        }
+        series[0] *= 0.5f64;
+
+        // Ascending powers of t, with the recurrence T_k+1(t) = 2t*T_k(t) - T_k-1(t).
+        let mut powers_t = [0f64; N];
+        let mut t_prev = [0f64; N];
+        let mut t_cur  = [0f64; N];
+        t_cur[0] = 1f64;
+        for (k, s) in series.iter().enumerate()
+        {
+            for i in 0..N
+            {
+                powers_t[i] += *s*t_cur[i];
+            }
+            let mut t_next = [0f64; N];
+            for i in 1..N
+            {
+                // T_1(t) = t.
+                t_next[i] = if k == 0 { t_cur[i-1] } else { 2f64*t_cur[i-1] - t_prev[i] };
+            }
+            if 0 < k
+            {
+                t_next[0] = -t_prev[0];
+            }
+            t_prev = t_cur;
+            t_cur  = t_next;
+        }
+
+        // Substitute t = alpha*x + beta, with Horner's method on the coefficients.
+        let alpha = 2f64/(b-a);
+        let beta  = -(a+b)/(b-a);
+        let mut powers_x = [0f64; N];
+        for d in powers_t.iter().rev()
+        {
+            for i in (0..N).rev()
+            {
+                powers_x[i] *= beta;
+                if 0 < i
+                {
+                    powers_x[i] += alpha*powers_x[i-1];
+                }
+            }
+            powers_x[0] += *d;
+        }
+        return Self::from_f64(powers_x);
+    }
+}
+
+impl <T, const N: usize> Polynomial<T, N>
+    where T: MixedReal + MixedNumSigned + MixedOps + MixedZero + MixedOne
+{
+    /// Find the complex roots of the polynomial with the Durand-Kerner method.
+    ///
+    /// All roots are refined simultaneously, until they no longer change, or for at most `iterations` iterations.
+    /// The roots must be representable by `T`, and multiple roots converge slowly.
+    /// Estimates which coincide, or whose distance underflows `T`, are not updated in that iteration.
+    ///
+    /// ## Arguments
+    ///
+    /// * `roots`      - Output buffer for the roots, of at least the degree of the polynomial.
+    /// * `iterations` - The maximum number of iterations.
+    ///
+    /// Returns the number of roots, which is the degree of the polynomial, and zero for constant polynomials.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use mixed_num::*;
+    /// use mixed_num::polynomial::*;
+    ///
+    /// // x^2 + 1
+    /// let p = Polynomial::new([1f64, 0f64, 1f64]);
+    /// let mut roots = [Cartesian::new(0f64, 0f64); 2];
+    /// assert_eq!{ p.roots(&mut roots, 100), 2 };
+    ///
+    /// assert!{ roots.iter().any( |r| r.re.abs() < 1e-12 && (r.im-1f64).abs() < 1e-12 ) };
+    /// assert!{ roots.iter().any( |r| r.re.abs() < 1e-12 && (r.im+1f64).abs() < 1e-12 ) };
+    /// ```
+    pub fn roots( &self, roots: &mut [Cartesian<T>], iterations: usize ) -> usize
+    {
+        let degree = self.degree();
+        assert!{ degree <= roots.len(), "The polynomial has {} roots.", degree };
+        if degree == 0
+        {
+            return 0;
+        }
+
+        // Monic coefficients.
+        let mut monic = self.coeffs;
+        for c in monic.iter_mut()
+        {
+            *c = *c/self.coeffs[degree];
+        }
+
+        // Initial values on a spiral, the powers of 0.4+0.9i.
+        let seed = Cartesian::new(T::mixed_from_num(0.4f64), T::mixed_from_num(0.9f64));
+        let mut z = Cartesian::new(T::mixed_one(), T::mixed_zero());
+        for root in roots[..degree].iter_mut()
+        {
+            *root = z;
+            z = complex::mul_cartesian(z, seed);
+        }
+
+        let zero = Cartesian::new(T::mixed_zero(), T::mixed_zero());
+        for _ in 0..iterations
+        {
+            let mut converged = true;
+            for i in 0..degree
+            {
+                let zi = roots[i];
+
+                let mut numerator = Cartesian::new(T::mixed_one(), T::mixed_zero());
+                for c in monic[..degree].iter().rev()
+                {
+                    numerator = complex::add(complex::mul_cartesian(numerator, zi), Cartesian::new(*c, T::mixed_zero()));
+                }
+
+                let mut denominator = Cartesian::new(T::mixed_one(), T::mixed_zero());
+                for (j, zj) in roots[..degree].iter().enumerate()
+                {
+                    if i != j
+                    {
+                        denominator = complex::mul_cartesian(denominator, complex::sub(zi, *zj));
+                    }
+                }
+
+                // numerator/denominator
+                let scale = denominator.re*denominator.re + denominator.im*denominator.im;
+                if scale == T::mixed_zero()
+                {
+                    converged = false;
+                    continue;
+                }
+                let product = complex::mul_cartesian(numerator, Cartesian::new(denominator.re, -denominator.im));
+                let delta = Cartesian::new(product.re/scale, product.im/scale);
+
+                if delta != zero
+                {
+                    converged = false;
+                }
+                roots[i] = complex::sub(zi, delta);
+            }
+            if converged
+            {
+                break;
+            }
+        }
+        return degree;
+    }
+}
+
+impl <T, const N: usize> MixedApproximation<T> for Polynomial<T, N>
+    where T: MixedReal + MixedOps + MixedZero
+{
+    /// Evaluate the polynomial with Horner's method.
+    #[inline(always)]
+    fn approximate( &self, x: T ) -> T
+    {
+        return self.horner(x);
+    }
+}
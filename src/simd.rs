@@ -0,0 +1,680 @@
+// SIMD implementations of the `f32` slice traits for x86_64.
+//
+// The SSE2 versions are always available on x86_64.
+// The AVX2 versions are selected at runtime with the `std` feature, and at compile time (`target_feature = "avx2"`) without it.
+// Lanes and tails which can not be handled by the vector kernels fall back to the scalar implementations.
+
+use crate::*;
+
+use core::arch::x86_64::*;
+use core::f32::consts::FRAC_2_PI;
+
+/// Inputs with larger magnitude are handled by the scalar implementation, to keep the range reduction accurate.
+const SINCOS_MAX_ARG: f32 = 8192.0;
+
+/// Three part split of π/2 for the Cody-Waite range reduction.
+const PIO2_1: f32 = 1.570_312_5;
+const PIO2_2: f32 = 4.837_513e-4;
+const PIO2_3: f32 = 7.549_79e-8;
+
+/// Minimax polynomial coefficients for sin and cos in the -π/4=<x<π/4 range.
+const S1: f32 = -1.666_665_5e-1;
+const S2: f32 =  8.332_161e-3;
+const S3: f32 = -1.951_529_6e-4;
+const C1: f32 =  4.166_664_6e-2;
+const C2: f32 = -1.388_731_6e-3;
+const C3: f32 =  2.443_315_7e-5;
+
+/// Coefficient of the octant polynomial in `trigonometry::atan`.
+const ATAN_K: f32 = 0.28125;
+
+#[inline(always)]
+fn has_avx2() -> bool
+{
+    #[cfg(feature = "std")]
+    {
+        return std::is_x86_feature_detected!("avx2");
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        return cfg!(target_feature = "avx2");
+    }
+}
+
+/// Calculate the sin of each element in `input`, writing the result to `output`.
+pub fn sin_f32( input: &[f32], output: &mut [f32] )
+{
+    if has_avx2() {
+        unsafe { avx2::sincos(input, Some(output), None) };
+    }
+    else {
+        unsafe { sse2::sincos(input, Some(output), None) };
+    }
+}
+
+/// Calculate the cos of each element in `input`, writing the result to `output`.
+pub fn cos_f32( input: &[f32], output: &mut [f32] )
+{
+    if has_avx2() {
+        unsafe { avx2::sincos(input, None, Some(output)) };
+    }
+    else {
+        unsafe { sse2::sincos(input, None, Some(output)) };
+    }
+}
+
+/// Calculate the sin and cos of each element in `input`, writing the results to `sin_output` and `cos_output`.
+pub fn sincos_f32( input: &[f32], sin_output: &mut [f32], cos_output: &mut [f32] )
+{
+    if has_avx2() {
+        unsafe { avx2::sincos(input, Some(sin_output), Some(cos_output)) };
+    }
+    else {
+        unsafe { sse2::sincos(input, Some(sin_output), Some(cos_output)) };
+    }
+}
+
+/// Calculate the polynomial atan2 of each pair `y[n]`/`x[n]`, writing the result to `output`.
+///
+/// Bit exact with `trigonometry::atan::atan2`.
+pub fn atan2_poly_f32( y: &[f32], x: &[f32], output: &mut [f32] )
+{
+    if has_avx2() {
+        unsafe { avx2::atan2_poly(y, x, output) };
+    }
+    else {
+        unsafe { sse2::atan2_poly(y, x, output) };
+    }
+}
+
+/// Calculate the square root of each element in `input`, writing the result to `output`.
+pub fn sqrt_f32( input: &[f32], output: &mut [f32] )
+{
+    if has_avx2() {
+        unsafe { avx2::sqrt(input, output) };
+    }
+    else {
+        unsafe { sse2::sqrt(input, output) };
+    }
+}
+
+/// Multiply the elements of `a` and `b` pairwise, writing the result to `output`.
+pub fn cartesian_mul_f32( a: &[Cartesian<f32>], b: &[Cartesian<f32>], output: &mut [Cartesian<f32>] )
+{
+    if has_avx2() {
+        unsafe { avx2::cartesian_mul(a, b, output) };
+    }
+    else {
+        unsafe { sse2::cartesian_mul(a, b, output) };
+    }
+}
+
+/// Scalar sin and cos, used for tails and lanes outside the range of the vector kernels.
+#[inline(always)]
+fn sincos_scalar( input: &[f32], sin_output: &mut Option<&mut [f32]>, cos_output: &mut Option<&mut [f32]>, idx: usize )
+{
+    let x = input[idx];
+    if let Some(s) = sin_output {
+        s[idx] = libm::sinf(x);
+    }
+    if let Some(c) = cos_output {
+        c[idx] = libm::cosf(x);
+    }
+}
+
+/// Number of elements to process for the `sincos` kernels.
+#[inline(always)]
+fn sincos_len( input: &[f32], sin_output: &Option<&mut [f32]>, cos_output: &Option<&mut [f32]> ) -> usize
+{
+    let mut n = input.len();
+    if let Some(s) = sin_output {
+        n = n.min(s.len());
+    }
+    if let Some(c) = cos_output {
+        n = n.min(c.len());
+    }
+    return n;
+}
+
+pub mod sse2
+{
+    use super::*;
+
+    /// Calculate sin and cos of four lanes, for |x| =< `SINCOS_MAX_ARG`.
+    #[inline(always)]
+    unsafe fn sincos4( x: __m128 ) -> (__m128, __m128)
+    {
+        // Range reduction to -π/4=<r<π/4 and quadrant q.
+        let q  = _mm_cvtps_epi32(_mm_mul_ps(x, _mm_set1_ps(FRAC_2_PI)));
+        let qf = _mm_cvtepi32_ps(q);
+        let mut r = _mm_sub_ps(x, _mm_mul_ps(qf, _mm_set1_ps(PIO2_1)));
+        r = _mm_sub_ps(r, _mm_mul_ps(qf, _mm_set1_ps(PIO2_2)));
+        r = _mm_sub_ps(r, _mm_mul_ps(qf, _mm_set1_ps(PIO2_3)));
+
+        let r2 = _mm_mul_ps(r, r);
+
+        // sin(r) = r + r³(S1 + r²(S2 + r²S3))
+        let mut sp = _mm_add_ps(_mm_set1_ps(S2), _mm_mul_ps(r2, _mm_set1_ps(S3)));
+        sp = _mm_add_ps(_mm_set1_ps(S1), _mm_mul_ps(r2, sp));
+        let s = _mm_add_ps(r, _mm_mul_ps(_mm_mul_ps(r, r2), sp));
+
+        // cos(r) = 1 - r²/2 + r⁴(C1 + r²(C2 + r²C3))
+        let mut cp = _mm_add_ps(_mm_set1_ps(C2), _mm_mul_ps(r2, _mm_set1_ps(C3)));
+        cp = _mm_add_ps(_mm_set1_ps(C1), _mm_mul_ps(r2, cp));
+        let c = _mm_add_ps(_mm_sub_ps(_mm_set1_ps(1.0), _mm_mul_ps(r2, _mm_set1_ps(0.5))), _mm_mul_ps(_mm_mul_ps(r2, r2), cp));
+
+        // Select by quadrant.
+        let one   = _mm_set1_epi32(1);
+        let two   = _mm_set1_epi32(2);
+        let swap  = _mm_castsi128_ps(_mm_cmpeq_epi32(_mm_and_si128(q, one), one));
+        let sin_sign = _mm_castsi128_ps(_mm_slli_epi32(_mm_and_si128(q, two), 30));
+        let cos_sign = _mm_castsi128_ps(_mm_slli_epi32(_mm_and_si128(_mm_add_epi32(q, one), two), 30));
+
+        let sin_x = _mm_or_ps(_mm_and_ps(swap, c), _mm_andnot_ps(swap, s));
+        let cos_x = _mm_or_ps(_mm_and_ps(swap, s), _mm_andnot_ps(swap, c));
+        return (_mm_xor_ps(sin_x, sin_sign), _mm_xor_ps(cos_x, cos_sign));
+    }
+
+    /// Calculate sin and/or cos of `input`.
+    ///
+    /// ## Safety
+    ///
+    /// Requires SSE2, which is always available on x86_64.
+    pub unsafe fn sincos( input: &[f32], mut sin_output: Option<&mut [f32]>, mut cos_output: Option<&mut [f32]> )
+    {
+        let n = sincos_len(input, &sin_output, &cos_output);
+        let max_arg = _mm_set1_ps(SINCOS_MAX_ARG);
+        let abs_mask = _mm_castsi128_ps(_mm_set1_epi32(0x7FFF_FFFF));
+
+        let mut i = 0;
+        while i+4 <= n
+        {
+            let x = _mm_loadu_ps(input.as_ptr().add(i));
+            // NaN compares as out of range, and is handled by the scalar implementation.
+            let out_of_range = _mm_cmpnle_ps(_mm_and_ps(x, abs_mask), max_arg);
+            if _mm_movemask_ps(out_of_range) != 0
+            {
+                for j in i..i+4 {
+                    sincos_scalar(input, &mut sin_output, &mut cos_output, j);
+                }
+            }
+            else
+            {
+                let (s, c) = sincos4(x);
+                if let Some(out) = &mut sin_output {
+                    _mm_storeu_ps(out.as_mut_ptr().add(i), s);
+                }
+                if let Some(out) = &mut cos_output {
+                    _mm_storeu_ps(out.as_mut_ptr().add(i), c);
+                }
+            }
+            i += 4;
+        }
+        for j in i..n {
+            sincos_scalar(input, &mut sin_output, &mut cos_output, j);
+        }
+    }
+
+    /// Calculate the polynomial atan2 of four lanes.
+    #[inline(always)]
+    unsafe fn atan2_poly4( y: __m128, x: __m128 ) -> __m128
+    {
+        let zero      = _mm_setzero_ps();
+        let sign_mask = _mm_set1_ps(-0.0);
+        let k         = _mm_set1_ps(ATAN_K);
+        let pi        = _mm_set1_ps(f32::mixed_pi());
+        let pi_half   = _mm_set1_ps(f32::mixed_pi()/2f32);
+
+        let xy = _mm_mul_ps(x, y);
+        let x2 = _mm_mul_ps(x, x);
+        let y2 = _mm_mul_ps(y, y);
+        let p1 = _mm_div_ps(xy, _mm_add_ps(x2, _mm_mul_ps(k, y2)));
+        let p2 = _mm_div_ps(xy, _mm_add_ps(y2, _mm_mul_ps(k, x2)));
+
+        let x_zero = _mm_cmpeq_ps(x, zero);
+        let y_zero = _mm_cmpeq_ps(y, zero);
+        let x_neg  = _mm_castsi128_ps(_mm_srai_epi32(_mm_castps_si128(x), 31));
+        let y_neg  = _mm_castsi128_ps(_mm_srai_epi32(_mm_castps_si128(y), 31));
+
+        let d      = _mm_sub_ps(_mm_andnot_ps(sign_mask, y), _mm_andnot_ps(sign_mask, x));
+        let use_p1 = _mm_cmplt_ps(d, zero);
+
+        // Octants using the first polynomial, ±π offset when x is negative.
+        let offset    = _mm_xor_ps(pi, _mm_and_ps(y_neg, sign_mask));
+        let p1_offset = _mm_add_ps(offset, p1);
+        let res_p1    = _mm_or_ps(_mm_and_ps(x_neg, p1_offset), _mm_andnot_ps(x_neg, p1));
+
+        // Octants using the second polynomial.
+        let signed_pi_half = _mm_xor_ps(pi_half, _mm_and_ps(y_neg, sign_mask));
+        let res_p2 = _mm_sub_ps(signed_pi_half, p2);
+
+        let mut res = _mm_or_ps(_mm_and_ps(use_p1, res_p1), _mm_andnot_ps(use_p1, res_p2));
+        // x is zero.
+        res = _mm_or_ps(_mm_and_ps(x_zero, signed_pi_half), _mm_andnot_ps(x_zero, res));
+        // y is zero.
+        let res_y_zero = _mm_and_ps(x_neg, pi);
+        res = _mm_or_ps(_mm_and_ps(y_zero, res_y_zero), _mm_andnot_ps(y_zero, res));
+        return res;
+    }
+
+    /// Calculate the polynomial atan2 of `y`/`x`.
+    ///
+    /// ## Safety
+    ///
+    /// Requires SSE2, which is always available on x86_64.
+    pub unsafe fn atan2_poly( y: &[f32], x: &[f32], output: &mut [f32] )
+    {
+        let n = y.len().min(x.len()).min(output.len());
+        let mut i = 0;
+        while i+4 <= n
+        {
+            let y4 = _mm_loadu_ps(y.as_ptr().add(i));
+            let x4 = _mm_loadu_ps(x.as_ptr().add(i));
+            // NaN lanes are handled by the scalar implementation.
+            if _mm_movemask_ps(_mm_or_ps(_mm_cmpunord_ps(y4, y4), _mm_cmpunord_ps(x4, x4))) != 0
+            {
+                for j in i..i+4 {
+                    output[j] = trigonometry::atan::atan2(y[j], x[j]);
+                }
+            }
+            else
+            {
+                _mm_storeu_ps(output.as_mut_ptr().add(i), atan2_poly4(y4, x4));
+            }
+            i += 4;
+        }
+        for j in i..n {
+            output[j] = trigonometry::atan::atan2(y[j], x[j]);
+        }
+    }
+
+    /// Calculate the square root of `input`.
+    ///
+    /// ## Safety
+    ///
+    /// Requires SSE2, which is always available on x86_64.
+    pub unsafe fn sqrt( input: &[f32], output: &mut [f32] )
+    {
+        let n = input.len().min(output.len());
+        let mut i = 0;
+        while i+4 <= n
+        {
+            let x = _mm_loadu_ps(input.as_ptr().add(i));
+            _mm_storeu_ps(output.as_mut_ptr().add(i), _mm_sqrt_ps(x));
+            i += 4;
+        }
+        for j in i..n {
+            output[j] = libm::sqrtf(input[j]);
+        }
+    }
+
+    /// Multiply `a` and `b` pairwise.
+    ///
+    /// ## Safety
+    ///
+    /// Requires SSE2, which is always available on x86_64.
+    pub unsafe fn cartesian_mul( a: &[Cartesian<f32>], b: &[Cartesian<f32>], output: &mut [Cartesian<f32>] )
+    {
+        let n = a.len().min(b.len()).min(output.len());
+        // `Cartesian<f32>` is `repr(C)`, i.e. interleaved re, im.
+        let a_ptr   = a.as_ptr() as *const f32;
+        let b_ptr   = b.as_ptr() as *const f32;
+        let out_ptr = output.as_mut_ptr() as *mut f32;
+
+        let mut i = 0;
+        while i+4 <= n
+        {
+            let a0 = _mm_loadu_ps(a_ptr.add(2*i));
+            let a1 = _mm_loadu_ps(a_ptr.add(2*i+4));
+            let b0 = _mm_loadu_ps(b_ptr.add(2*i));
+            let b1 = _mm_loadu_ps(b_ptr.add(2*i+4));
+
+            // Deinterleave to four real and four imaginary parts.
+            let a_re = _mm_shuffle_ps(a0, a1, 0b10_00_10_00);
+            let a_im = _mm_shuffle_ps(a0, a1, 0b11_01_11_01);
+            let b_re = _mm_shuffle_ps(b0, b1, 0b10_00_10_00);
+            let b_im = _mm_shuffle_ps(b0, b1, 0b11_01_11_01);
+
+            let re = _mm_sub_ps(_mm_mul_ps(a_re, b_re), _mm_mul_ps(a_im, b_im));
+            let im = _mm_add_ps(_mm_mul_ps(a_re, b_im), _mm_mul_ps(a_im, b_re));
+
+            _mm_storeu_ps(out_ptr.add(2*i),   _mm_unpacklo_ps(re, im));
+            _mm_storeu_ps(out_ptr.add(2*i+4), _mm_unpackhi_ps(re, im));
+            i += 4;
+        }
+        for j in i..n {
+            output[j] = complex::mul_cartesian(a[j], b[j]);
+        }
+    }
+}
+
+pub mod avx2
+{
+    use super::*;
+
+    /// Calculate sin and cos of eight lanes, for |x| =< `SINCOS_MAX_ARG`.
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn sincos8( x: __m256 ) -> (__m256, __m256)
+    {
+        // Range reduction to -π/4=<r<π/4 and quadrant q.
+        let q  = _mm256_cvtps_epi32(_mm256_mul_ps(x, _mm256_set1_ps(FRAC_2_PI)));
+        let qf = _mm256_cvtepi32_ps(q);
+        let mut r = _mm256_sub_ps(x, _mm256_mul_ps(qf, _mm256_set1_ps(PIO2_1)));
+        r = _mm256_sub_ps(r, _mm256_mul_ps(qf, _mm256_set1_ps(PIO2_2)));
+        r = _mm256_sub_ps(r, _mm256_mul_ps(qf, _mm256_set1_ps(PIO2_3)));
+
+        let r2 = _mm256_mul_ps(r, r);
+
+        // sin(r) = r + r³(S1 + r²(S2 + r²S3))
+        let mut sp = _mm256_add_ps(_mm256_set1_ps(S2), _mm256_mul_ps(r2, _mm256_set1_ps(S3)));
+        sp = _mm256_add_ps(_mm256_set1_ps(S1), _mm256_mul_ps(r2, sp));
+        let s = _mm256_add_ps(r, _mm256_mul_ps(_mm256_mul_ps(r, r2), sp));
+
+        // cos(r) = 1 - r²/2 + r⁴(C1 + r²(C2 + r²C3))
+        let mut cp = _mm256_add_ps(_mm256_set1_ps(C2), _mm256_mul_ps(r2, _mm256_set1_ps(C3)));
+        cp = _mm256_add_ps(_mm256_set1_ps(C1), _mm256_mul_ps(r2, cp));
+        let c = _mm256_add_ps(_mm256_sub_ps(_mm256_set1_ps(1.0), _mm256_mul_ps(r2, _mm256_set1_ps(0.5))), _mm256_mul_ps(_mm256_mul_ps(r2, r2), cp));
+
+        // Select by quadrant.
+        let one   = _mm256_set1_epi32(1);
+        let two   = _mm256_set1_epi32(2);
+        let swap  = _mm256_castsi256_ps(_mm256_cmpeq_epi32(_mm256_and_si256(q, one), one));
+        let sin_sign = _mm256_castsi256_ps(_mm256_slli_epi32(_mm256_and_si256(q, two), 30));
+        let cos_sign = _mm256_castsi256_ps(_mm256_slli_epi32(_mm256_and_si256(_mm256_add_epi32(q, one), two), 30));
+
+        let sin_x = _mm256_blendv_ps(s, c, swap);
+        let cos_x = _mm256_blendv_ps(c, s, swap);
+        return (_mm256_xor_ps(sin_x, sin_sign), _mm256_xor_ps(cos_x, cos_sign));
+    }
+
+    /// Calculate sin and/or cos of `input`.
+    ///
+    /// ## Safety
+    ///
+    /// Requires AVX2.
+    #[target_feature(enable = "avx2")]
+    pub unsafe fn sincos( input: &[f32], mut sin_output: Option<&mut [f32]>, mut cos_output: Option<&mut [f32]> )
+    {
+        let n = sincos_len(input, &sin_output, &cos_output);
+        let max_arg = _mm256_set1_ps(SINCOS_MAX_ARG);
+        let abs_mask = _mm256_castsi256_ps(_mm256_set1_epi32(0x7FFF_FFFF));
+
+        let mut i = 0;
+        while i+8 <= n
+        {
+            let x = _mm256_loadu_ps(input.as_ptr().add(i));
+            // NaN compares as out of range, and is handled by the scalar implementation.
+            let out_of_range = _mm256_cmp_ps(_mm256_and_ps(x, abs_mask), max_arg, _CMP_NLE_UQ);
+            if _mm256_movemask_ps(out_of_range) != 0
+            {
+                for j in i..i+8 {
+                    sincos_scalar(input, &mut sin_output, &mut cos_output, j);
+                }
+            }
+            else
+            {
+                let (s, c) = sincos8(x);
+                if let Some(out) = &mut sin_output {
+                    _mm256_storeu_ps(out.as_mut_ptr().add(i), s);
+                }
+                if let Some(out) = &mut cos_output {
+                    _mm256_storeu_ps(out.as_mut_ptr().add(i), c);
+                }
+            }
+            i += 8;
+        }
+        for j in i..n {
+            sincos_scalar(input, &mut sin_output, &mut cos_output, j);
+        }
+    }
+
+    /// Calculate the polynomial atan2 of eight lanes.
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn atan2_poly8( y: __m256, x: __m256 ) -> __m256
+    {
+        let zero      = _mm256_setzero_ps();
+        let sign_mask = _mm256_set1_ps(-0.0);
+        let k         = _mm256_set1_ps(ATAN_K);
+        let pi        = _mm256_set1_ps(f32::mixed_pi());
+        let pi_half   = _mm256_set1_ps(f32::mixed_pi()/2f32);
+
+        let xy = _mm256_mul_ps(x, y);
+        let x2 = _mm256_mul_ps(x, x);
+        let y2 = _mm256_mul_ps(y, y);
+        let p1 = _mm256_div_ps(xy, _mm256_add_ps(x2, _mm256_mul_ps(k, y2)));
+        let p2 = _mm256_div_ps(xy, _mm256_add_ps(y2, _mm256_mul_ps(k, x2)));
+
+        let x_zero = _mm256_cmp_ps(x, zero, _CMP_EQ_OQ);
+        let y_zero = _mm256_cmp_ps(y, zero, _CMP_EQ_OQ);
+        let x_neg  = _mm256_castsi256_ps(_mm256_srai_epi32(_mm256_castps_si256(x), 31));
+        let y_neg  = _mm256_castsi256_ps(_mm256_srai_epi32(_mm256_castps_si256(y), 31));
+
+        let d      = _mm256_sub_ps(_mm256_andnot_ps(sign_mask, y), _mm256_andnot_ps(sign_mask, x));
+        let use_p1 = _mm256_cmp_ps(d, zero, _CMP_LT_OQ);
+
+        // Octants using the first polynomial, ±π offset when x is negative.
+        let offset    = _mm256_xor_ps(pi, _mm256_and_ps(y_neg, sign_mask));
+        let res_p1    = _mm256_blendv_ps(p1, _mm256_add_ps(offset, p1), x_neg);
+
+        // Octants using the second polynomial.
+        let signed_pi_half = _mm256_xor_ps(pi_half, _mm256_and_ps(y_neg, sign_mask));
+        let res_p2 = _mm256_sub_ps(signed_pi_half, p2);
+
+        let mut res = _mm256_blendv_ps(res_p2, res_p1, use_p1);
+        // x is zero.
+        res = _mm256_blendv_ps(res, signed_pi_half, x_zero);
+        // y is zero.
+        res = _mm256_blendv_ps(res, _mm256_and_ps(x_neg, pi), y_zero);
+        return res;
+    }
+
+    /// Calculate the polynomial atan2 of `y`/`x`.
+    ///
+    /// ## Safety
+    ///
+    /// Requires AVX2.
+    #[target_feature(enable = "avx2")]
+    pub unsafe fn atan2_poly( y: &[f32], x: &[f32], output: &mut [f32] )
+    {
+        let n = y.len().min(x.len()).min(output.len());
+        let mut i = 0;
+        while i+8 <= n
+        {
+            let y8 = _mm256_loadu_ps(y.as_ptr().add(i));
+            let x8 = _mm256_loadu_ps(x.as_ptr().add(i));
+            // NaN lanes are handled by the scalar implementation.
+            let nan = _mm256_or_ps(_mm256_cmp_ps(y8, y8, _CMP_UNORD_Q), _mm256_cmp_ps(x8, x8, _CMP_UNORD_Q));
+            if _mm256_movemask_ps(nan) != 0
+            {
+                for j in i..i+8 {
+                    output[j] = trigonometry::atan::atan2(y[j], x[j]);
+                }
+            }
+            else
+            {
+                _mm256_storeu_ps(output.as_mut_ptr().add(i), atan2_poly8(y8, x8));
+            }
+            i += 8;
+        }
+        sse2::atan2_poly(&y[i..n], &x[i..n], &mut output[i..n]);
+    }
+
+    /// Calculate the square root of `input`.
+    ///
+    /// ## Safety
+    ///
+    /// Requires AVX2.
+    #[target_feature(enable = "avx2")]
+    pub unsafe fn sqrt( input: &[f32], output: &mut [f32] )
+    {
+        let n = input.len().min(output.len());
+        let mut i = 0;
+        while i+8 <= n
+        {
+            let x = _mm256_loadu_ps(input.as_ptr().add(i));
+            _mm256_storeu_ps(output.as_mut_ptr().add(i), _mm256_sqrt_ps(x));
+            i += 8;
+        }
+        sse2::sqrt(&input[i..n], &mut output[i..n]);
+    }
+
+    /// Multiply `a` and `b` pairwise.
+    ///
+    /// ## Safety
+    ///
+    /// Requires AVX2.
+    #[target_feature(enable = "avx2")]
+    pub unsafe fn cartesian_mul( a: &[Cartesian<f32>], b: &[Cartesian<f32>], output: &mut [Cartesian<f32>] )
+    {
+        let n = a.len().min(b.len()).min(output.len());
+        // `Cartesian<f32>` is `repr(C)`, i.e. interleaved re, im.
+        let a_ptr   = a.as_ptr() as *const f32;
+        let b_ptr   = b.as_ptr() as *const f32;
+        let out_ptr = output.as_mut_ptr() as *mut f32;
+
+        let mut i = 0;
+        while i+4 <= n
+        {
+            let a4 = _mm256_loadu_ps(a_ptr.add(2*i));
+            let b4 = _mm256_loadu_ps(b_ptr.add(2*i));
+
+            // Duplicate the real and imaginary parts of b within each complex pair.
+            let b_re = _mm256_moveldup_ps(b4);
+            let b_im = _mm256_movehdup_ps(b4);
+            // Swap the real and imaginary parts of a.
+            let a_swap = _mm256_permute_ps(a4, 0b10_11_00_01);
+
+            // re: a.re*b.re - a.im*b.im, im: a.im*b.re + a.re*b.im
+            let res = _mm256_addsub_ps(_mm256_mul_ps(a4, b_re), _mm256_mul_ps(a_swap, b_im));
+
+            _mm256_storeu_ps(out_ptr.add(2*i), res);
+            i += 4;
+        }
+        sse2::cartesian_mul(&a[i..n], &b[i..n], &mut output[i..n]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const N: usize = 203;
+
+    fn test_input( scale: f32 ) -> [f32; N] {
+        let mut x = [0f32; N];
+        for (i, x_) in x.iter_mut().enumerate() {
+            *x_ = scale*((i as f32) - (N as f32)/2f32)/(N as f32);
+        }
+        return x;
+    }
+
+    fn assert_sincos( x: &[f32], s: &[f32], c: &[f32] ) {
+        for ((x_, s_), c_) in x.iter().zip(s.iter()).zip(c.iter()) {
+            assert!{ (s_ - libm::sinf(*x_)).abs() <= 1e-6, "sin({}) = {}", x_, s_ };
+            assert!{ (c_ - libm::cosf(*x_)).abs() <= 1e-6, "cos({}) = {}", x_, c_ };
+        }
+    }
+
+    #[test]
+    fn sincos() {
+        for scale in [1f32, 7f32, 100f32, 2e4f32] {
+            let x = test_input(scale);
+            let mut s = [0f32; N];
+            let mut c = [0f32; N];
+
+            unsafe { sse2::sincos(&x, Some(&mut s), Some(&mut c)) };
+            assert_sincos(&x, &s, &c);
+
+            if has_avx2() {
+                unsafe { avx2::sincos(&x, Some(&mut s), Some(&mut c)) };
+                assert_sincos(&x, &s, &c);
+            }
+        }
+    }
+
+    #[test]
+    fn sincos_special_values() {
+        let x = [0f32, -0f32, f32::NAN, f32::INFINITY, 1e30, f32::mixed_pi(), -f32::mixed_pi()/2f32, 1f32];
+        let mut s = [0f32; 8];
+        let mut c = [0f32; 8];
+        sincos_f32(&x, &mut s, &mut c);
+        assert!{ s[2].is_nan() && s[3].is_nan() };
+        assert_eq!{ s[4], libm::sinf(1e30) };
+        assert_sincos(&x[..2], &s[..2], &c[..2]);
+        assert_sincos(&x[5..], &s[5..], &c[5..]);
+    }
+
+    #[test]
+    fn atan2_poly() {
+        let y = test_input(5f32);
+        let mut x = test_input(-3f32);
+        x.reverse();
+        let mut special_y = [0f32, -0f32, 0f32, -0f32, 1f32, -1f32, 2f32, -2f32, 1f32, -1f32, 1f32, -1f32];
+        let mut special_x = [1f32, 1f32, -1f32, -1f32, 0f32, 0f32, -0f32, -0f32, 1f32, 1f32, -1f32, -1f32];
+
+        for (y, x) in [(&y[..], &x[..]), (&mut special_y[..], &mut special_x[..])] {
+            let mut expected = [0f32; N];
+            let mut phi = [0f32; N];
+            let n = y.len();
+            for i in 0..n {
+                expected[i] = trigonometry::atan::atan2(y[i], x[i]);
+            }
+
+            unsafe { sse2::atan2_poly(y, x, &mut phi) };
+            assert_eq!{ phi[..n], expected[..n] };
+
+            if has_avx2() {
+                unsafe { avx2::atan2_poly(y, x, &mut phi) };
+                assert_eq!{ phi[..n], expected[..n] };
+            }
+        }
+    }
+
+    #[test]
+    fn sqrt() {
+        let mut x = test_input(1e3f32);
+        for x_ in x.iter_mut() {
+            *x_ = x_.abs();
+        }
+        let mut y = [0f32; N];
+
+        unsafe { sse2::sqrt(&x, &mut y) };
+        for (x_, y_) in x.iter().zip(y.iter()) {
+            assert_eq!{ *y_, libm::sqrtf(*x_) };
+        }
+
+        if has_avx2() {
+            unsafe { avx2::sqrt(&x, &mut y) };
+            for (x_, y_) in x.iter().zip(y.iter()) {
+                assert_eq!{ *y_, libm::sqrtf(*x_) };
+            }
+        }
+    }
+
+    #[test]
+    fn cartesian_mul() {
+        let re = test_input(3f32);
+        let im = test_input(-7f32);
+        let mut a = [Cartesian::new(0f32, 0f32); N];
+        let mut b = a;
+        for i in 0..N {
+            a[i] = Cartesian::new(re[i], im[i]);
+            b[i] = Cartesian::new(im[N-1-i], re[(i*7) % N]);
+        }
+        let mut c = [Cartesian::new(0f32, 0f32); N];
+
+        unsafe { sse2::cartesian_mul(&a, &b, &mut c) };
+        for i in 0..N {
+            assert_eq!{ c[i], complex::mul_cartesian(a[i], b[i]) };
+        }
+
+        if has_avx2() {
+            unsafe { avx2::cartesian_mul(&a, &b, &mut c) };
+            for i in 0..N {
+                assert_eq!{ c[i], complex::mul_cartesian(a[i], b[i]) };
+            }
+        }
+    }
+}
@@ -0,0 +1,288 @@
+//! Special functions, implemented generically with series and rational approximations.
+//!
+//! These are the implementations of `MixedBesselI0`, `MixedGamma` and `MixedErf` for fixed-point types.
+//! Floating-point types use `libm` where available.
+
+/// Modified Bessel function of the first kind and order zero, `I0(x)`, by its power series.
+///
+/// `I0(x) = Σ_k ((x/2)^k/k!)^2`
+///
+/// The terms are all positive, and the series is summed until the terms no longer contribute.
+/// `I0(x)` grows as `e^|x|/√(2π|x|)`, which must fit the integer bits of fixed-point types, e.g. `I0(8) ≈ 427`.
+///
+/// ## Example
+///
+/// ```
+/// use mixed_num::special::*;
+/// use fixed::{types::extra::U20, FixedI32};
+///
+/// let y = bessel_i0( FixedI32::<U20>::from_num(2) );
+/// assert!{ (y.to_num::<f64>()-2.2795853023360673).abs() < 1e-5 };
+/// ```
+pub fn bessel_i0<T>( x: T ) -> T
+    where T: crate::MixedReal + crate::MixedOps + crate::MixedOne
+{
+    let half_x = x*T::mixed_from_num(0.5f64);
+    let mut term = T::mixed_one();
+    let mut sum  = T::mixed_one();
+    for k in 1..1000
+    {
+        let ratio = half_x*T::mixed_from_num(1f64/(k as f64));
+        term = term*ratio*ratio;
+        sum += term;
+        if term <= sum*T::mixed_from_num(1e-17f64)
+        {
+            break;
+        }
+    }
+    return sum;
+}
+
+/// Natural logarithm of a positive `x`.
+///
+/// `x` is scaled by powers of two to `1=<m<2`, and `ln(m) = 2*atanh((m-1)/(m+1))` by its series.
+/// Returns the minimum value of the type for `x=<0`.
+fn ln<T>( x: T ) -> T
+    where T: crate::MixedReal + crate::MixedOps + crate::MixedZero + crate::MixedOne
+{
+    if x <= T::mixed_zero()
+    {
+        return T::mixed_min_value();
+    }
+
+    let one  = T::mixed_one();
+    let two  = T::mixed_from_num(2f64);
+    let half = T::mixed_from_num(0.5f64);
+
+    let mut m = x;
+    let mut k = 0i32;
+    while two <= m
+    {
+        m = m*half;
+        k += 1;
+    }
+    while m < one
+    {
+        m = m*two;
+        k -= 1;
+    }
+
+    // z =< 1/3, so twelve terms reach below 1e-12.
+    let z  = (m-one)/(m+one);
+    let z2 = z*z;
+    let mut power = z;
+    let mut sum   = T::mixed_zero();
+    for j in 0..12
+    {
+        sum += power*T::mixed_from_num(1f64/((2*j+1) as f64));
+        power = power*z2;
+    }
+    return sum+sum + T::mixed_from_num((k as f64)*core::f64::consts::LN_2);
+}
+
+/// `Γ(1+x)` for `0=<x=<1`, by the polynomial of Abramowitz and Stegun 6.1.36, with an error below 3e-7.
+fn gamma_poly<T>( x: T ) -> T
+    where T: crate::MixedReal + crate::MixedOps + crate::MixedZero
+{
+    const B: [f64; 9] = [1f64, -0.577191652f64, 0.988205891f64, -0.897056937f64, 0.918206857f64,
+                         -0.756704078f64, 0.482199394f64, -0.193527818f64, 0.035868343f64];
+    let mut y = T::mixed_zero();
+    for b in B.iter().rev()
+    {
+        y = y*x + T::mixed_from_num(*b);
+    }
+    return y;
+}
+
+/// The gamma function `Γ(x)`.
+///
+/// The argument is reduced to `1=<x<2` with the recurrence `Γ(x+1) = x*Γ(x)`, where `Γ(x)` is approximated by a polynomial with an error below 3e-7.
+/// The poles at `x = 0, -1, -2, ...` divide by zero.
+/// The cost grows linearly with the distance of `x` from the `1=<x<2` range.
+///
+/// ## Example
+///
+/// ```
+/// use mixed_num::special::*;
+/// use fixed::{types::extra::U20, FixedI32};
+///
+/// let y = gamma( FixedI32::<U20>::from_num(4.5) );
+/// assert!{ (y.to_num::<f64>()-11.631728396567448).abs() < 1e-4 };
+/// ```
+pub fn gamma<T>( x: T ) -> T
+    where T: crate::MixedReal + crate::MixedOps + crate::MixedZero + crate::MixedOne
+{
+    let one = T::mixed_one();
+    let two = T::mixed_from_num(2f64);
+
+    // Reduce to 1=<r<2.
+    let mut r = x;
+    while r < one
+    {
+        r += one;
+    }
+    while two <= r
+    {
+        r -= one;
+    }
+    let mut y = gamma_poly(r-one);
+
+    // Γ(x) = (x-1)*...*(r+1)*r*Γ(r), in increasing order to avoid intermediate overflow.
+    let mut z = r;
+    while z < x
+    {
+        y *= z;
+        z += one;
+    }
+    // Γ(x) = Γ(r)/(x*(x+1)*...*(r-1))
+    let mut z = x;
+    while z < one
+    {
+        y = y/z;
+        z += one;
+    }
+    return y;
+}
+
+/// The natural logarithm of the absolute value of the gamma function, `ln|Γ(x)|`.
+///
+/// Large arguments use the Stirling series, when the type holds them.
+/// Other arguments are reduced to `1=<x<2` as in `gamma`, accumulating the logarithms of the factors.
+/// Returns the maximum value of the type at the poles `x = 0, -1, -2, ...`.
+///
+/// ## Example
+///
+/// ```
+/// use mixed_num::special::*;
+/// use fixed::{types::extra::U20, FixedI32};
+///
+/// let y = lgamma( FixedI32::<U20>::from_num(100) );
+/// assert!{ (y.to_num::<f64>()-359.1342053695754).abs() < 1e-3 };
+/// ```
+pub fn lgamma<T>( x: T ) -> T
+    where T: crate::MixedReal + crate::MixedNumSigned + crate::MixedOps + crate::MixedZero + crate::MixedOne
+{
+    let one = T::mixed_one();
+    let two = T::mixed_from_num(2f64);
+
+    // The Stirling series is accurate to 1e-9 from 7, for the types that can represent it.
+    let max: f64 = T::mixed_max_value().mixed_to_num();
+    if 8f64 <= max && T::mixed_from_num(7f64) <= x
+    {
+        return stirling(x);
+    }
+
+    let mut x = x;
+    let mut sum = T::mixed_zero();
+    while x < one
+    {
+        let abs = if x < T::mixed_zero() { -x } else { x };
+        if abs == T::mixed_zero()
+        {
+            return T::mixed_max_value();
+        }
+        sum -= ln(abs);
+        x += one;
+    }
+    while two <= x
+    {
+        x -= one;
+        sum += ln(x);
+    }
+    return sum + ln(gamma_poly(x-one));
+}
+
+/// `ln(Γ(x))` by the Stirling series, for `x >= 7`.
+fn stirling<T>( x: T ) -> T
+    where T: crate::MixedReal + crate::MixedOps + crate::MixedZero + crate::MixedOne
+{
+    let r  = T::mixed_one()/x;
+    let r2 = r*r;
+    let series = r*(T::mixed_from_num(1f64/12f64) - r2*(T::mixed_from_num(1f64/360f64) - r2*T::mixed_from_num(1f64/1260f64)));
+    return (x-T::mixed_from_num(0.5f64))*ln(x) - x + T::mixed_from_num(0.9189385332046728f64) + series;
+}
+
+/// `erfc(x)` is 2e-10 from this argument, below the error of the approximation.
+const ERFC_CUTOFF: f64 = 4.5f64;
+
+/// `erfc(x)` for `x >= 0`, by the rational approximation of Abramowitz and Stegun 7.1.26, with an error below 1.5e-7.
+fn erfc_positive<T>( x: T ) -> T
+    where T: crate::MixedReal + crate::MixedNumSigned + crate::MixedOps + crate::MixedZero + crate::MixedOne + crate::MixedExp
+{
+    // Returning zero from the cutoff also avoids (x/2)^2 overflowing the range of fixed-point types.
+    let x_f64: f64 = x.mixed_to_num();
+    if ERFC_CUTOFF <= x_f64
+    {
+        return T::mixed_zero();
+    }
+
+    const A: [f64; 5] = [0.254829592f64, -0.284496736f64, 1.421413741f64, -1.453152027f64, 1.061405429f64];
+    let t = T::mixed_one()/(T::mixed_one() + T::mixed_from_num(0.3275911f64)*x);
+    let mut poly = T::mixed_zero();
+    for a in A.iter().rev()
+    {
+        poly = (poly + T::mixed_from_num(*a))*t;
+    }
+
+    // e^(-x^2) = (e^(-(x/2)^2))^4, where (x/2)^2 is at most 5.1 for x < 4.5, in the range of the types that can represent 4.5.
+    let half_x = x*T::mixed_from_num(0.5f64);
+    let mut e = (-(half_x*half_x)).mixed_exp();
+    e = e*e;
+    e = e*e;
+    return poly*e;
+}
+
+/// The error function `erf(x)`, with an error below 1.5e-7.
+///
+/// ## Example
+///
+/// ```
+/// use mixed_num::special::*;
+/// use fixed::{types::extra::U28, FixedI32};
+///
+/// let y = erf( FixedI32::<U28>::from_num(0.5) );
+/// assert!{ (y.to_num::<f64>()-0.5204998778130465).abs() < 1e-6 };
+/// ```
+pub fn erf<T>( x: T ) -> T
+    where T: crate::MixedReal + crate::MixedNumSigned + crate::MixedOps + crate::MixedZero + crate::MixedOne + crate::MixedExp
+{
+    // MIN can not be negated. It is at most -4 for the signed fixed-point types, where erfc(-MIN) is below the error.
+    let x_f64: f64 = x.mixed_to_num();
+    if x_f64 <= -ERFC_CUTOFF || x == T::mixed_min_value()
+    {
+        return -T::mixed_one();
+    }
+    if x < T::mixed_zero()
+    {
+        return erfc_positive(-x) - T::mixed_one();
+    }
+    return T::mixed_one() - erfc_positive(x);
+}
+
+/// The complementary error function `erfc(x) = 1-erf(x)`, with an error below 1.5e-7.
+///
+/// The error is absolute, and calculating `erfc` directly avoids the cancellation in `1-erf(x)` for floating-point types.
+///
+/// ## Example
+///
+/// ```
+/// use mixed_num::special::*;
+///
+/// let y = erfc( 3f64 );
+/// assert!{ (y-2.209049699858544e-5).abs() < 1e-7 };
+/// ```
+pub fn erfc<T>( x: T ) -> T
+    where T: crate::MixedReal + crate::MixedNumSigned + crate::MixedOps + crate::MixedZero + crate::MixedOne + crate::MixedExp
+{
+    // MIN can not be negated. It is at most -4 for the signed fixed-point types, where erfc(-MIN) is below the error.
+    let x_f64: f64 = x.mixed_to_num();
+    if x_f64 <= -ERFC_CUTOFF || x == T::mixed_min_value()
+    {
+        return T::mixed_from_num(2f64);
+    }
+    if x < T::mixed_zero()
+    {
+        return T::mixed_from_num(2f64) - erfc_positive(-x);
+    }
+    return erfc_positive(x);
+}
@@ -4,6 +4,9 @@ pub use math_traits::*;
 mod complex_traits;
 pub use complex_traits::*;
 
+mod slice_traits;
+pub use slice_traits::*;
+
 pub trait MixedCeil
 {
     /// Round Self up to the closest nteger.
@@ -16,6 +19,87 @@ pub trait MixedFloor
     fn mixed_floor(&self) -> Self;
 }
 
+/// Rounding to the closest integer.
+/// 
+/// Fixed point types saturate at `MIN` or `MAX` if the rounded integer is out of range.
+/// 
+/// ## Example
+/// 
+/// ```
+/// use mixed_num::*;
+/// use fixed::{types::extra::U5, FixedI8};
+/// 
+/// assert_eq!{ (-2.5f32).mixed_round(), -3f32 };
+/// assert_eq!{ (-2.5f32).mixed_round_ties_even(), -2f32 };
+/// 
+/// let x = FixedI8::<U5>::from_num(2.5);
+/// assert_eq!{ x.mixed_round(), 3 };
+/// assert_eq!{ x.mixed_round_ties_even(), 2 };
+/// // 3.97 rounds to 4, which is out of range.
+/// assert_eq!{ FixedI8::<U5>::MAX.mixed_round(), FixedI8::<U5>::MAX };
+/// ```
+pub trait MixedRound
+{
+    /// Round `self` to the closest integer, with ties away from zero.
+    fn mixed_round(&self) -> Self;
+    /// Round `self` to the closest integer, with ties to the even integer.
+    fn mixed_round_ties_even(&self) -> Self;
+}
+
+/// The integer and fractional parts.
+/// 
+/// The parts have the sign of `self`, so that `x.mixed_trunc() + x.mixed_fract() == x`.
+/// 
+/// ## Example
+/// 
+/// ```
+/// use mixed_num::*;
+/// use fixed::{types::extra::U16, FixedI32};
+/// 
+/// let x = FixedI32::<U16>::from_num(-2.75);
+/// assert_eq!{ x.mixed_trunc(), -2 };
+/// assert_eq!{ x.mixed_fract(), -0.75 };
+/// assert_eq!{ 7i32.mixed_fract(), 0 };
+/// ```
+pub trait MixedTrunc
+{
+    /// Round `self` towards zero to an integer.
+    fn mixed_trunc(&self) -> Self;
+    /// The fractional part of `self`, `self - self.mixed_trunc()`.
+    fn mixed_fract(&self) -> Self;
+}
+
+/// Euclidean division, where the remainder is never negative.
+/// 
+/// `self == rhs*self.mixed_div_euclid(rhs) + self.mixed_rem_euclid(rhs)`, with `0=<r<|rhs|`.
+/// This wraps phase accumulators and indices, e.g. to the `0=<x<2π` range.
+/// 
+/// Floats return NaN for a zero `rhs`, while fixed point types and integers panic like their `/` operators.
+/// The quotient of fixed point types and integers saturates on overflow.
+/// 
+/// ## Example
+/// 
+/// ```
+/// use mixed_num::*;
+/// use fixed::{types::extra::U16, FixedI32};
+/// 
+/// assert_eq!{ (-7i32).mixed_rem_euclid(3), 2 };
+/// assert_eq!{ (-7i32).mixed_div_euclid(3), -3 };
+/// assert_eq!{ (-7f64).mixed_rem_euclid(-3f64), 2f64 };
+/// assert_eq!{ (-7f64).mixed_div_euclid(-3f64), 3f64 };
+/// 
+/// let phase = FixedI32::<U16>::from_num(-0.5);
+/// let one = FixedI32::<U16>::ONE;
+/// assert_eq!{ phase.mixed_rem_euclid(one), 0.5 };
+/// ```
+pub trait MixedEuclid
+{
+    /// The quotient of the Euclidean division of `self` by `rhs`, an integer.
+    fn mixed_div_euclid(&self, rhs:Self) -> Self;
+    /// The remainder of the Euclidean division of `self` by `rhs`, in the `0=<r<|rhs|` range.
+    fn mixed_rem_euclid(&self, rhs:Self) -> Self;
+}
+
 pub trait MixedNumConversion<T> {
     /// Generic type cast from numeric type T.
     fn mixed_from_num( number:T ) -> Self;
@@ -42,8 +126,121 @@ pub trait MixedPi
     fn mixed_tau() -> Self;
 }
 
+pub trait MixedE
+{
+    /// Euler's number, e = 2.718...
+    fn mixed_e() -> Self;
+}
+
+pub trait MixedLn2
+{
+    /// The natural logarithm of 2, ln(2) = 0.693...
+    fn mixed_ln2() -> Self;
+}
+
+pub trait MixedLn10
+{
+    /// The natural logarithm of 10, ln(10) = 2.302...
+    fn mixed_ln10() -> Self;
+}
+
+pub trait MixedSqrt2
+{
+    /// The square root of 2, √2 = 1.414...
+    fn mixed_sqrt2() -> Self;
+}
+
+pub trait MixedFrac1Sqrt2
+{
+    /// 1/√2 = 0.707...
+    fn mixed_frac_1_sqrt2() -> Self;
+}
+
+pub trait MixedFracPi2
+{
+    /// π/2 = 1.570...
+    fn mixed_frac_pi_2() -> Self;
+}
+
+pub trait MixedFrac1Pi
+{
+    /// 1/π = 0.318...
+    fn mixed_frac_1_pi() -> Self;
+}
+
+pub trait MixedEpsilon
+{
+    /// The smallest increment of 1 of type Self. The machine epsilon for floats, and the lsb for fixed point.
+    fn mixed_epsilon() -> Self;
+}
+
+/// Precision and range of a type, for generic algorithms, e.g. to choose the number of iterations.
+/// 
+/// Implemented for floats, fixed-point and integer types.
+/// 
+/// | Type          | `FRAC_BITS`                  | `INT_BITS`                  | `EPSILON`         |
+/// |---------------|------------------------------|-----------------------------|-------------------|
+/// | Floats        | The mantissa bits, 23 or 52  | The maximum exponent        | Machine epsilon   |
+/// | Fixed point   | The fractional bits          | The integer bits, with sign | The lsb           |
+/// | Integers      | 0                            | The bits, with sign         | 1                 |
+/// 
+/// ## Example
+/// 
+/// ```
+/// use mixed_num::*;
+/// use fixed::{types::extra::U28, FixedI32};
+/// 
+/// assert_eq!{ FixedI32::<U28>::FRAC_BITS, 28 };
+/// assert_eq!{ FixedI32::<U28>::INT_BITS, 4 };
+/// assert_eq!{ f32::FRAC_BITS, 23 };
+/// assert!{ !i16::IS_FIXED && i16::IS_INTEGER && i16::IS_SIGNED };
+/// 
+/// assert_eq!{ 1024f64.mixed_ulp(), 1024f64*f64::EPSILON };
+/// ```
+pub trait MixedTypeInfo
+    where Self: Sized
+{
+    /// The type is fixed point.
+    const IS_FIXED: bool;
+    /// The type is floating point.
+    const IS_FLOAT: bool;
+    /// The type is an integer.
+    const IS_INTEGER: bool;
+    /// The type can represent negative values.
+    const IS_SIGNED: bool;
+    /// The number of fractional bits, or the mantissa bits of floats.
+    const FRAC_BITS: u32;
+    /// The number of integer bits, including the sign, or the maximum exponent of floats.
+    const INT_BITS: u32;
+    /// The difference between 1 and the next larger value of the type.
+    const EPSILON: Self;
+
+    /// The spacing between `self` and the next value of larger magnitude, the unit in the last place.
+    fn mixed_ulp(&self) -> Self;
+}
+
+/// Mathematical constants of type Self.
+/// 
+/// Fixed-point types round the constants to nearest from the 128 bit values in `fixed::consts`, which is accurate to the lsb for all widths.
+/// 
+/// ## Example
+/// 
+/// ```
+/// use mixed_num::*;
+/// use fixed::{types::extra::U60, FixedI64};
+/// 
+/// // The 3 dB bandwidth of a first order filter, in radians per second.
+/// fn cutoff<T: MixedConsts + MixedOps>( time_constant: T ) -> T {
+///     return T::mixed_one()/time_constant;
+/// }
+/// assert_eq!{ cutoff(f64::mixed_frac_1_pi()), core::f64::consts::PI };
+/// assert_eq!{ f32::mixed_e(), core::f32::consts::E };
+/// 
+/// assert_eq!{ FixedI64::<U60>::mixed_ln2().to_bits(), 0x0B17_217F_7D1C_F79B };
+/// ```
 pub trait MixedConsts
     where Self: MixedPi + MixedZero + MixedOne
+              + MixedE + MixedLn2 + MixedLn10 + MixedSqrt2 + MixedFrac1Sqrt2 + MixedFracPi2 + MixedFrac1Pi + MixedEpsilon
 {
 }
 
@@ -74,6 +271,67 @@ pub trait MixedReal
     fn mixed_is_negative( &self ) -> bool;
 }
 
+/// The category of a value, see `MixedClassify`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum MixedCategory {
+    /// Not a number, floats only.
+    Nan,
+    /// Positive or negative infinity, floats only.
+    Infinite,
+    /// At the limit of the range, `MIN` or `MAX`, where fixed-point and integer types saturate.
+    Saturated,
+    /// Zero.
+    Zero,
+    /// Subnormal, floats only.
+    Subnormal,
+    /// Any other value.
+    Normal,
+}
+
+/// Classification of special values, for validating the results of generic algorithms.
+/// 
+/// The semantics are consistent across the types:
+/// 
+/// | Type        | `mixed_is_nan` | `mixed_is_finite`       | `mixed_is_saturated`       |
+/// |-------------|----------------|-------------------------|----------------------------|
+/// | Floats      | NaN            | Not NaN or infinite     | Infinite, where floats overflow to |
+/// | Fixed point | Never          | Always                  | At `MIN` or `MAX`          |
+/// | Integers    | Never          | Always                  | At `MIN` or `MAX`          |
+/// | `Cartesian` | Either part    | Both parts              | Either part                |
+/// 
+/// Zero is not saturated for unsigned types, where it is `MIN`.
+/// 
+/// `mixed_classify` returns the first matching category in the order of `MixedCategory`.
+/// For `Cartesian` it is the first category of either part, and `Zero` only when both parts are zero.
+/// Infinite floats are `Infinite`, and not `Saturated`.
+/// 
+/// ## Example
+/// 
+/// ```
+/// use mixed_num::*;
+/// use fixed::{types::extra::U28, FixedI32};
+/// 
+/// let x = FixedI32::<U28>::MAX;
+/// assert!{ x.mixed_is_saturated() && x.mixed_is_finite() };
+/// assert_eq!{ x.mixed_classify(), MixedCategory::Saturated };
+/// 
+/// assert_eq!{ (1f32/0f32).mixed_classify(), MixedCategory::Infinite };
+/// assert_eq!{ Cartesian::new(1f64, f64::NAN).mixed_classify(), MixedCategory::Nan };
+/// ```
+pub trait MixedClassify
+{
+    /// Returns true if `self` is not a number.
+    fn mixed_is_nan(&self) -> bool;
+    /// Returns true if `self` is positive or negative infinity.
+    fn mixed_is_infinite(&self) -> bool;
+    /// Returns true if `self` is neither infinite nor NaN.
+    fn mixed_is_finite(&self) -> bool;
+    /// Returns true if `self` is at the limit of the range of the type.
+    fn mixed_is_saturated(&self) -> bool;
+    /// The category of `self`.
+    fn mixed_classify(&self) -> MixedCategory;
+}
+
 pub trait MixedComplex
 {
 
@@ -37,6 +37,14 @@ pub trait Mag<T>
     fn mag( &self ) -> T;
     /// Magnitude of the complex number.
     fn abs( &self ) -> T;
+    /// A fast estimate of the magnitude of the complex number.
+    /// 
+    /// Defaults to `mag`, which gives no speedup, as the trait has no access to the parts.
+    /// `Cartesian<T>` overrides it with `mixed_hypot_fast`, and `Polar<T>` returns the stored magnitude.
+    fn mag_fast( &self ) -> T
+    {
+        return self.mag();
+    }
 }
 
 pub trait Arg<T> 
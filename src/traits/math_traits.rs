@@ -25,10 +25,47 @@ pub trait MixedPowi
     fn mixed_powi( &self, exp: i32 ) -> Self;
 }
 
+/// Phase wrapping in constant time.
+/// 
+/// The implementations use the remainder of the division by the period, which is exact and can not overflow, also for values near `MAX`.
+/// For fixed-point types, the rounding of π adds an error of up to half an lsb per period wrapped.
+/// Integers use π rounded to 3, and the turn wraps them to zero.
+/// 
+/// ## Example
+/// 
+/// ```
+/// use mixed_num::*;
+/// use fixed::{types::extra::U28, FixedI32};
+/// 
+/// assert_eq!{ 540f32.mixed_wrap_degrees(), -180f32 };
+/// assert_eq!{ (-0.75f64).mixed_wrap_turns(), 0.25f64 };
+/// assert_eq!{ 370i32.mixed_wrap_degrees(), 10i32 };
+/// 
+/// let phi = FixedI32::<U28>::MAX.mixed_wrap_phase_positive();
+/// assert!{ (phi.to_num::<f64>()-(8f64-2f64*core::f64::consts::PI)).abs() < 1e-8 };
+/// ```
 pub trait MixedWrapPhase
 {
     /// Wrapps `self` to the -π=<x<π range.
     fn mixed_wrap_phase(&self) -> Self;
+    /// Wrapps `self` to the 0=<x<2π range. Types which can not represent 2π saturate at their maximum.
+    fn mixed_wrap_phase_positive(&self) -> Self
+        where Self: MixedReal + MixedNumSigned + MixedOps + MixedZero
+    {
+        return trigonometry::wrap_period_positive(*self, core::f64::consts::PI);
+    }
+    /// Wrapps `self` in degrees to the -180=<x<180 range.
+    fn mixed_wrap_degrees(&self) -> Self
+        where Self: MixedReal + MixedNumSigned + MixedOps + MixedZero
+    {
+        return trigonometry::wrap_half_period(*self, 180f64);
+    }
+    /// Wrapps `self` in turns to the -0.5=<x<0.5 range.
+    fn mixed_wrap_turns(&self) -> Self
+        where Self: MixedReal + MixedNumSigned + MixedOps + MixedZero
+    {
+        return trigonometry::wrap_half_period(*self, 0.5f64);
+    }
 }
 
 pub trait MixedTan
@@ -53,7 +90,7 @@ pub trait MixedAtan
     fn mixed_atan2(&self, other:Self) -> Self;
     /// Calculate atan2(y,x) using a selection of polynomial approximations, one for each octant in the unit circle.
     /// 
-    /// The method is accurat within 0.028 degrees.
+    /// The method is accurate within 0.28 degrees (5e-3 rad).
     /// 
     /// \[1\] R. G. Lyons, Streamlining Digital Signal Processing, Second Edition, IEEE Press, 2012.
     /// 
@@ -105,6 +142,162 @@ pub trait MixedTrigonometry: MixedSin + MixedCos + MixedAtan
 {
 }
 
+/// Trigonometric functions with a selectable accuracy tier.
+/// 
+/// The `fast` tier uses the polynomial approximations in `trigonometry`, while the `precise` tier uses `libm` for floats, and `cordic` or the lookup tables in `trigonometry::lut` for fixed point.
+/// The precise `atan2` of fixed-point types evaluates `cordic::atan` on the reduced 0=<r=<1 range, and is defined for all arguments.
+/// 
+/// | Tier      | Implementation                                   | Max. error `sin`, `cos` | Max. error `atan2` |
+/// |-----------|--------------------------------------------------|-------------------------|--------------------|
+/// | `fast`    | Taylor polynomial, and octant polynomial for `atan2` | 4e-6                | 5e-3 rad           |
+/// | `precise` | `libm` for floats, `cordic` for fixed point      | 4 ulp                   | 4 ulp              |
+/// 
+/// Fixed-point types add up to 32 lsb of rounding to both tiers, and the `ulp` of floats is the machine epsilon, as the results are at most π.
+/// The bounds are verified in the `trigonometry_accuracy` tests.
+/// 
+/// ## Example
+/// 
+/// ```
+/// use mixed_num::*;
+/// use fixed::{types::extra::U28, FixedI32};
+/// 
+/// let x = FixedI32::<U28>::from_num(1.2);
+/// assert!{ (x.mixed_sin_fast().to_num::<f64>()-1.2f64.sin()).abs() < 4e-6 };
+/// assert!{ (x.mixed_sin_precise().to_num::<f64>()-1.2f64.sin()).abs() < 1e-7 };
+/// ```
+pub trait MixedTrigonometryAccuracy
+{
+    /// Take the sin of `self`, in the -π=<x<π range, with the fast tier.
+    fn mixed_sin_fast(&self) -> Self;
+    /// Take the sin of `self`, with the precise tier.
+    fn mixed_sin_precise(&self) -> Self;
+    /// Take the cos of `self`, in the -π=<x<π range, with the fast tier.
+    fn mixed_cos_fast(&self) -> Self;
+    /// Take the cos of `self`, with the precise tier.
+    fn mixed_cos_precise(&self) -> Self;
+    /// Take the atan2 of `self`/other with the fast tier.
+    fn mixed_atan2_fast(&self, other:Self) -> Self;
+    /// Take the atan2 of `self`/other with the precise tier.
+    fn mixed_atan2_precise(&self, other:Self) -> Self;
+    /// Take the sin of `self`, in the -π=<x<π range, with a custom approximation of `sin(x)` on the -π/2=<x=<π/2 range.
+    /// 
+    /// See `trigonometry::sin_with`.
+    /// 
+    /// ## Example
+    /// 
+    /// ```
+    /// use mixed_num::*;
+    /// use mixed_num::polynomial::*;
+    /// use fixed::{types::extra::U28, FixedI32};
+    /// 
+    /// let approximation = Polynomial::<FixedI32<U28>, 10>::chebyshev_fit( libm::sin, -1.6f64, 1.6f64 );
+    /// 
+    /// let x = FixedI32::<U28>::from_num(-2.5);
+    /// assert!{ (x.mixed_sin_with(&approximation).to_num::<f64>()-(-2.5f64).sin()).abs() < 1e-6 };
+    /// assert!{ (x.mixed_cos_with(&approximation).to_num::<f64>()-(-2.5f64).cos()).abs() < 1e-6 };
+    /// ```
+    fn mixed_sin_with<A>(&self, approximation: &A) -> Self
+        where Self: MixedNum + MixedOps + MixedNumSigned + MixedPi,
+              A: polynomial::MixedApproximation<Self>
+    {
+        return trigonometry::sin_with(*self, approximation);
+    }
+    /// Take the cos of `self` with a custom approximation of `sin(x)` on the -π/2=<x=<π/2 range.
+    /// 
+    /// See `trigonometry::cos_with`.
+    fn mixed_cos_with<A>(&self, approximation: &A) -> Self
+        where Self: MixedNum + MixedOps + MixedNumSigned + MixedPi + MixedWrapPhase,
+              A: polynomial::MixedApproximation<Self>
+    {
+        return trigonometry::cos_with(*self, approximation);
+    }
+    /// Take the atan2 of `self`/other with a custom approximation of `atan(x)` on the 0=<x=<1 range.
+    /// 
+    /// See `trigonometry::atan::atan2_with`.
+    fn mixed_atan2_with<A>(&self, other:Self, approximation: &A) -> Self
+        where Self: MixedReal + MixedNumSigned + MixedOps + MixedZero + MixedPi + MixedAbs,
+              A: polynomial::MixedApproximation<Self>
+    {
+        return trigonometry::atan::atan2_with(*self, other, approximation);
+    }
+}
+
+/// Angle unit conversions, and trigonometric functions of angles in degrees.
+/// 
+/// The degree functions are exact at multiples of 90°, where the radian functions are off by the rounding of π.
+/// Integers round the results to the nearest integer.
+/// Fixed-point types must represent the angles in degrees, e.g. 180 for `atan2d`.
+/// 
+/// ## Example
+/// 
+/// ```
+/// use mixed_num::*;
+/// use fixed::{types::extra::U22, FixedI32};
+/// 
+/// assert_eq!{ 180f64.mixed_sind(), 0f64 };
+/// assert_eq!{ 180f64.mixed_to_radians().mixed_sin() == 0f64, false };
+/// 
+/// let x = FixedI32::<U22>::from_num(-270);
+/// assert_eq!{ x.mixed_sincosd(), (FixedI32::<U22>::ONE, FixedI32::<U22>::ZERO) };
+/// assert_eq!{ 1i32.mixed_atan2d(-1i32), 135i32 };
+/// ```
+pub trait MixedAngleUnits
+{
+    /// Convert `self` from radians to degrees.
+    fn mixed_to_degrees(&self) -> Self;
+    /// Convert `self` from degrees to radians.
+    fn mixed_to_radians(&self) -> Self;
+    /// Convert `self` from radians to turns.
+    fn mixed_to_turns(&self) -> Self;
+    /// Take the sin of `self` in degrees.
+    fn mixed_sind(&self) -> Self;
+    /// Take the cos of `self` in degrees.
+    fn mixed_cosd(&self) -> Self;
+    /// Calculate the sin and cos of `self` in degrees.
+    fn mixed_sincosd(&self) -> (Self, Self) where Self:Sized;
+    /// Take the atan2 of `self`/other, in degrees in the -180<x=<180 range.
+    fn mixed_atan2d(&self, other:Self) -> Self;
+}
+
+/// Minimum, maximum and clamping, with consistent NaN handling.
+/// 
+/// Floats follow the IEEE 754 minNum and maxNum semantics, where a NaN argument is ignored, and the result is NaN only if both are NaN.
+/// `mixed_clamp` is `self.mixed_max(min).mixed_min(max)`, so NaN is clamped to `min`, NaN bounds are ignored, and `max` wins if `min > max`.
+/// It never panics, unlike `clamp` of the standard library.
+/// 
+/// The `by_mag` variants compare the magnitudes, and return `self` on ties.
+/// `Cartesian<T>` compares the magnitudes for all methods, and `mixed_clamp` limits the magnitude while keeping the phase.
+/// 
+/// ## Example
+/// 
+/// ```
+/// use mixed_num::*;
+/// use fixed::{types::extra::U28, FixedI32};
+/// 
+/// assert_eq!{ f32::NAN.mixed_max(1f32), 1f32 };
+/// assert_eq!{ f64::NAN.mixed_clamp(-1f64, 1f64), -1f64 };
+/// assert_eq!{ (-3i32).mixed_min_by_mag(2i32), 2i32 };
+/// 
+/// let x = FixedI32::<U28>::MAX;
+/// assert_eq!{ x.mixed_clamp(FixedI32::<U28>::NEG_ONE, FixedI32::<U28>::ONE), FixedI32::<U28>::ONE };
+/// 
+/// let z = Cartesian::new(3f64, 4f64).mixed_clamp(Cartesian::new(0f64, 0f64), Cartesian::new(0f64, 1f64));
+/// assert!{ (z.re-0.6f64).abs() < 1e-15 && (z.im-0.8f64).abs() < 1e-15 };
+/// ```
+pub trait MixedMinMax
+{
+    /// The minimum of `self` and `other`.
+    fn mixed_min(&self, other:Self) -> Self;
+    /// The maximum of `self` and `other`.
+    fn mixed_max(&self, other:Self) -> Self;
+    /// Restrict `self` to the `min=<x=<max` range.
+    fn mixed_clamp(&self, min:Self, max:Self) -> Self;
+    /// The one of `self` and `other` with the smallest magnitude.
+    fn mixed_min_by_mag(&self, other:Self) -> Self;
+    /// The one of `self` and `other` with the largest magnitude.
+    fn mixed_max_by_mag(&self, other:Self) -> Self;
+}
+
 pub trait MixedExp
 {
     /// Take the exponential, base e, of `self`.
@@ -114,6 +307,7 @@ pub trait MixedExp
 pub trait MixedSqrt
 {
     /// The generic square root implementation for the `MixedSqrt` trait.
+    /// Fixed-point types use the NIIRF method, with the iterations chosen from the fractional bits.
     fn mixed_sqrt(&self) -> Self;
     /// A fast implementation of the square root using the Nonlinear IIR Filter (NIIRF) method \[1\].
     /// 
@@ -150,6 +344,100 @@ pub trait MixedSqrt
     fn mixed_niirf(&self) -> Self;
 }
 
+/// The reciprocal, `1/x`.
+/// 
+/// Fixed-point types use Newton-Raphson iterations seeded from the leading zeros, see `trigonometry::recip`, instead of a division.
+/// Results out of range saturate at `MAX`, or `MIN` for negative values, including the reciprocal of zero.
+/// `Cartesian<T>` uses Smith's method, which avoids overflow of the squared magnitude.
+/// 
+/// ## Example
+/// 
+/// ```
+/// use mixed_num::*;
+/// use fixed::{types::extra::U16, FixedI32};
+/// 
+/// let x = FixedI32::<U16>::from_num(-2.5);
+/// assert_eq!{ x.mixed_recip(), FixedI32::<U16>::from_num(-0.4) };
+/// assert_eq!{ FixedI32::<U16>::ZERO.mixed_recip(), FixedI32::<U16>::MAX };
+/// 
+/// assert!{ (4f32.mixed_recip_fast()-0.25f32).abs() < 1e-3 };
+/// 
+/// let z = Cartesian::new(0f64, 2f64).mixed_recip();
+/// assert_eq!{ z, Cartesian::new(0f64, -0.5f64) };
+/// ```
+pub trait MixedRecip
+{
+    /// The reciprocal of `self`.
+    fn mixed_recip(&self) -> Self;
+    /// A fast approximation of the reciprocal of `self`.
+    /// 
+    /// Floats use an estimate from the exponent bits and one Newton-Raphson iteration, with a relative error below 3e-3.
+    /// Zero, subnormal, non-finite and very large values, where the estimate does not apply, use the division of `mixed_recip`.
+    /// Fixed-point types use two iterations, accurate to about 16 bits.
+    fn mixed_recip_fast(&self) -> Self;
+}
+
+/// The reciprocal square root, `1/sqrt(x)`.
+/// 
+/// Fixed-point types use Newton-Raphson iterations seeded from the leading zeros, see `trigonometry::recip`, instead of a root and a division.
+/// As `mixed_niirf`, negative values are forced positive. Results out of range saturate at `MAX`, including the reciprocal square root of zero.
+/// Floats return NaN for negative values.
+/// 
+/// ## Example
+/// 
+/// ```
+/// use mixed_num::*;
+/// use fixed::{types::extra::U28, FixedI32};
+/// 
+/// let x = FixedI32::<U28>::from_num(0.25);
+/// assert_eq!{ x.mixed_rsqrt(), 2 };
+/// 
+/// assert!{ (2f64.mixed_rsqrt()-0.5f64.sqrt()).abs() < 1e-15 };
+/// assert!{ (2f64.mixed_rsqrt_fast()-0.5f64.sqrt()).abs() < 2e-3 };
+/// ```
+pub trait MixedRsqrt
+{
+    /// The reciprocal square root of `self`.
+    fn mixed_rsqrt(&self) -> Self;
+    /// A fast approximation of the reciprocal square root of `self`.
+    /// 
+    /// Floats use an estimate from the exponent bits and one Newton-Raphson iteration, with a relative error below 2e-3.
+    /// Negative, zero, subnormal and non-finite values, where the estimate does not apply, use `mixed_rsqrt`, so negative values return NaN.
+    /// Fixed-point types use two iterations, accurate to about 19 bits.
+    fn mixed_rsqrt_fast(&self) -> Self;
+}
+
+/// The hypotenuse, `sqrt(x²+y²)`, without overflow of the squares.
+/// 
+/// Fixed-point types and integers calculate the exact root of the sum of squares in 128 bit integer arithmetic, scaled down for magnitudes above 63 bits.
+/// The result is rounded to nearest, and saturates at `MAX` where out of range. Floats use `hypot` of `libm`.
+/// 
+/// `mixed_hypot_fast` is a two segment alpha max plus beta min estimate, `max(max+5/32*min, 27/32*max+71/128*min)`, with an error below 1.3%.
+/// The factors are small integers over powers of two, so fixed-point types and integers need no division.
+/// 
+/// ## Example
+/// 
+/// ```
+/// use mixed_num::*;
+/// use fixed::{types::extra::U28, FixedI32};
+/// 
+/// // The squares overflow, but the result is in range.
+/// let x = FixedI32::<U28>::from_num(3);
+/// let y = FixedI32::<U28>::from_num(-4);
+/// assert_eq!{ x.mixed_hypot(y), 5 };
+/// assert!{ (x.mixed_hypot_fast(y).to_num::<f64>()-5f64).abs() < 5f64*0.013f64 };
+/// 
+/// assert_eq!{ i32::MAX.mixed_hypot(1), i32::MAX };
+/// assert_eq!{ 3e300f64.mixed_hypot(4e300f64), 5e300f64 };
+/// ```
+pub trait MixedHypot
+{
+    /// The hypotenuse of `self` and `other`.
+    fn mixed_hypot(&self, other:Self) -> Self;
+    /// A fast estimate of the hypotenuse of `self` and `other`.
+    fn mixed_hypot_fast(&self, other:Self) -> Self;
+}
+
 pub trait MixedCbrt
 {
     /// Take the cube root of self.
@@ -192,6 +480,28 @@ pub trait Mixedlog2
     fn mixed_log2(&self) -> Self;
 }
 
+pub trait MixedBesselI0
+{
+    /// Take the modified Bessel function of the first kind and order zero, `I0(self)`.
+    fn mixed_bessel_i0(&self) -> Self;
+}
+
+pub trait MixedGamma
+{
+    /// Take the gamma function, `Γ(self)`.
+    fn mixed_gamma(&self) -> Self;
+    /// Take the natural logarithm of the absolute value of the gamma function, `ln|Γ(self)|`.
+    fn mixed_lgamma(&self) -> Self;
+}
+
+pub trait MixedErf
+{
+    /// Take the error function, `erf(self)`.
+    fn mixed_erf(&self) -> Self;
+    /// Take the complementary error function, `erfc(self) = 1-erf(self)`.
+    fn mixed_erfc(&self) -> Self;
+}
+
 pub trait DbMag
 {
     /// Convert between magnitude in linear scale and Decibel (dB).
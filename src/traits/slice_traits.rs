@@ -0,0 +1,229 @@
+// The slice traits come in two layers.
+// The element traits (e.g. `MixedSinSlice`) are implemented per type, which allows type specific optimizations of the bulk operation.
+// The `MixedSlice` and `CartesianSlice` extension traits are implemented on slices, and dispatch to the element traits.
+//
+// All slice operations write to a caller-provided output buffer, and process the first `min(input.len(), output.len())` elements.
+
+use crate::*;
+
+pub trait MixedSinSlice
+    where Self: MixedSin + Copy
+{
+    /// Take the sin of each element in `input`, writing the result to `output`.
+    fn mixed_sin_slice( input: &[Self], output: &mut [Self] )
+    {
+        for (y, x) in output.iter_mut().zip(input.iter())
+        {
+            *y = x.mixed_sin();
+        }
+    }
+    /// Calculate the sin and cos of each element in `input`, writing the results to `sin_output` and `cos_output`.
+    fn mixed_sincos_slice( input: &[Self], sin_output: &mut [Self], cos_output: &mut [Self] )
+    {
+        for ((s, c), x) in sin_output.iter_mut().zip(cos_output.iter_mut()).zip(input.iter())
+        {
+            (*s, *c) = x.mixed_sincos();
+        }
+    }
+}
+
+pub trait MixedCosSlice
+    where Self: MixedCos + Copy
+{
+    /// Take the cos of each element in `input`, writing the result to `output`.
+    fn mixed_cos_slice( input: &[Self], output: &mut [Self] )
+    {
+        for (y, x) in output.iter_mut().zip(input.iter())
+        {
+            *y = x.mixed_cos();
+        }
+    }
+}
+
+pub trait MixedAtanSlice
+    where Self: MixedAtan + Copy
+{
+    /// Take the atan2 of each pair `y[n]`/`x[n]`, writing the result to `output`.
+    fn mixed_atan2_slice( y: &[Self], x: &[Self], output: &mut [Self] )
+    {
+        for (phi, (y_, x_)) in output.iter_mut().zip(y.iter().zip(x.iter()))
+        {
+            *phi = y_.mixed_atan2(*x_);
+        }
+    }
+    /// Take the polynomial atan2 of each pair `y[n]`/`x[n]`, writing the result to `output`.
+    ///
+    /// See `MixedAtan::mixed_atan2_poly`.
+    fn mixed_atan2_poly_slice( y: &[Self], x: &[Self], output: &mut [Self] )
+    {
+        for (phi, (y_, x_)) in output.iter_mut().zip(y.iter().zip(x.iter()))
+        {
+            *phi = y_.mixed_atan2_poly(*x_);
+        }
+    }
+}
+
+pub trait MixedSqrtSlice
+    where Self: MixedSqrt + Copy
+{
+    /// Take the square root of each element in `input`, writing the result to `output`.
+    fn mixed_sqrt_into( input: &[Self], output: &mut [Self] )
+    {
+        for (y, x) in output.iter_mut().zip(input.iter())
+        {
+            *y = x.mixed_sqrt();
+        }
+    }
+    /// Take the NIIRF square root of each element in `input`, writing the result to `output`.
+    ///
+    /// See `MixedSqrt::mixed_niirf`.
+    fn mixed_niirf_into( input: &[Self], output: &mut [Self] )
+    {
+        for (y, x) in output.iter_mut().zip(input.iter())
+        {
+            *y = x.mixed_niirf();
+        }
+    }
+}
+
+pub trait MixedCartesianSlice
+    where Self: MixedNum + MixedNumSigned + MixedHypot + MixedOps
+{
+    /// Calculate the magnitude of each element in `input`, writing the result to `output`.
+    fn mixed_mag_slice( input: &[Cartesian<Self>], output: &mut [Self] )
+    {
+        for (y, x) in output.iter_mut().zip(input.iter())
+        {
+            *y = x.mag();
+        }
+    }
+    /// Multiply the elements of `a` and `b` pairwise, writing the result to `output`.
+    fn mixed_mul_slice( a: &[Cartesian<Self>], b: &[Cartesian<Self>], output: &mut [Cartesian<Self>] )
+    {
+        for (y, (a_, b_)) in output.iter_mut().zip(a.iter().zip(b.iter()))
+        {
+            *y = complex::mul_cartesian(*a_, *b_);
+        }
+    }
+}
+
+/// Slice extension trait for bulk math on real numbers.
+///
+/// ## Example
+///
+/// ```
+/// use mixed_num::*;
+/// use mixed_num::traits::*;
+///
+/// let x = [0f32, 0.25f32, 1f32];
+/// let mut y = [0f32; 3];
+///
+/// x.mixed_sqrt_into(&mut y);
+/// assert_eq!{ y, [0f32, 0.5f32, 1f32] };
+/// ```
+pub trait MixedSlice<T>
+{
+    /// Take the sin of each element in `self`, writing the result to `output`.
+    fn mixed_sin_slice( &self, output: &mut [T] )
+        where T: MixedSinSlice;
+    /// Calculate the sin and cos of each element in `self`, writing the results to `sin_output` and `cos_output`.
+    fn mixed_sincos_slice( &self, sin_output: &mut [T], cos_output: &mut [T] )
+        where T: MixedSinSlice;
+    /// Take the cos of each element in `self`, writing the result to `output`.
+    fn mixed_cos_slice( &self, output: &mut [T] )
+        where T: MixedCosSlice;
+    /// Take the atan2 of each pair `self[n]`/`x[n]`, writing the result to `output`.
+    fn mixed_atan2_slice( &self, x: &[T], output: &mut [T] )
+        where T: MixedAtanSlice;
+    /// Take the polynomial atan2 of each pair `self[n]`/`x[n]`, writing the result to `output`.
+    fn mixed_atan2_poly_slice( &self, x: &[T], output: &mut [T] )
+        where T: MixedAtanSlice;
+    /// Take the square root of each element in `self`, writing the result to `output`.
+    fn mixed_sqrt_into( &self, output: &mut [T] )
+        where T: MixedSqrtSlice;
+    /// Take the NIIRF square root of each element in `self`, writing the result to `output`.
+    fn mixed_niirf_into( &self, output: &mut [T] )
+        where T: MixedSqrtSlice;
+}
+
+impl <T> MixedSlice<T> for [T]
+{
+    #[inline(always)]
+    fn mixed_sin_slice( &self, output: &mut [T] )
+        where T: MixedSinSlice
+    {
+        T::mixed_sin_slice(self, output);
+    }
+    #[inline(always)]
+    fn mixed_sincos_slice( &self, sin_output: &mut [T], cos_output: &mut [T] )
+        where T: MixedSinSlice
+    {
+        T::mixed_sincos_slice(self, sin_output, cos_output);
+    }
+    #[inline(always)]
+    fn mixed_cos_slice( &self, output: &mut [T] )
+        where T: MixedCosSlice
+    {
+        T::mixed_cos_slice(self, output);
+    }
+    #[inline(always)]
+    fn mixed_atan2_slice( &self, x: &[T], output: &mut [T] )
+        where T: MixedAtanSlice
+    {
+        T::mixed_atan2_slice(self, x, output);
+    }
+    #[inline(always)]
+    fn mixed_atan2_poly_slice( &self, x: &[T], output: &mut [T] )
+        where T: MixedAtanSlice
+    {
+        T::mixed_atan2_poly_slice(self, x, output);
+    }
+    #[inline(always)]
+    fn mixed_sqrt_into( &self, output: &mut [T] )
+        where T: MixedSqrtSlice
+    {
+        T::mixed_sqrt_into(self, output);
+    }
+    #[inline(always)]
+    fn mixed_niirf_into( &self, output: &mut [T] )
+        where T: MixedSqrtSlice
+    {
+        T::mixed_niirf_into(self, output);
+    }
+}
+
+/// Slice extension trait for bulk math on cartesian complex numbers.
+///
+/// ## Example
+///
+/// ```
+/// use mixed_num::*;
+/// use mixed_num::traits::*;
+///
+/// let x = [Cartesian::new(3f32, 4f32), Cartesian::new(0f32, -2f32)];
+/// let mut y = [0f32; 2];
+///
+/// x.mag_slice(&mut y);
+/// assert_eq!{ y, [5f32, 2f32] };
+/// ```
+pub trait CartesianSlice<T>
+{
+    /// Calculate the magnitude of each element in `self`, writing the result to `output`.
+    fn mag_slice( &self, output: &mut [T] );
+    /// Multiply the elements of `self` and `other` pairwise, writing the result to `output`.
+    fn mul_slice( &self, other: &[Cartesian<T>], output: &mut [Cartesian<T>] );
+}
+
+impl <T: MixedCartesianSlice> CartesianSlice<T> for [Cartesian<T>]
+{
+    #[inline(always)]
+    fn mag_slice( &self, output: &mut [T] )
+    {
+        T::mixed_mag_slice(self, output);
+    }
+    #[inline(always)]
+    fn mul_slice( &self, other: &[Cartesian<T>], output: &mut [Cartesian<T>] )
+    {
+        T::mixed_mul_slice(self, other, output);
+    }
+}
@@ -1,5 +1,7 @@
 pub mod atan;
 pub mod sqrt;
+pub mod lut;
+pub mod recip;
 
 /// Get the sign of the argument with a unit value.
 /// Zero is of positive sign.
@@ -37,10 +39,12 @@ pub fn sign<T>( x:T ) -> T
 
 /// Calculate sin(x) using a Taylor approximation of `sin(x)`.
 /// 
-/// Sin is calculated using the following polynomial:
+/// Sin is calculated using the following polynomial, evaluated with Horner's method in `x^2`:
 /// 
 /// `sin(x) = x -( x^3/6 )+( x^5/120 )-( x^7/5040 )+( x^9/362880 )`
 /// 
+/// Types with up to 7 or 12 fractional bits use the first three or four terms, see `MixedTypeInfo`.
+/// 
 /// ## Argument
 /// 
 /// * `x` - The value to apply the operation to.
@@ -67,13 +71,55 @@ pub fn sign<T>( x:T ) -> T
 /// 
 #[allow(dead_code)]
 pub fn sin<T>( x: T ) -> T
-    where T: crate::MixedNum + crate::MixedOps + crate::MixedPowi + crate::MixedNumSigned + crate::MixedPi
+    where T: crate::MixedNum + crate::MixedOps + crate::MixedNumSigned + crate::MixedPi + crate::MixedTypeInfo
+{
+    // Taylor coefficients of x, x^3, .. x^9.
+    const COEFFS: [f64; 5] = [1f64, -1f64/6f64, 1f64/120f64, -1f64/5040f64, 1f64/362880f64];
+
+    // Narrow types drop the terms below their resolution, with errors of 4.5e-3 and 1.6e-4 for three and four terms.
+    let terms = if T::FRAC_BITS <= 7 { 3 } else if T::FRAC_BITS <= 12 { 4 } else { 5 };
+
+    return sin_with( x, &|x: T| -> T {
+        let x2 = x*x;
+        let mut y = T::mixed_from_num(COEFFS[terms-1]);
+        for c in COEFFS[..terms-1].iter().rev()
+        {
+            y = y*x2 + T::mixed_from_num(*c);
+        }
+        return y*x;
+    });
+}
+
+/// Calculate sin(x) using a custom approximation of `sin(x)` on the -π/2=<x=<π/2 range.
+/// 
+/// The argument is reflected into the -π/2=<x=<π/2 range, where `approximation` is evaluated.
+/// 
+/// ## Arguments
+/// 
+/// * `x`             - The value to apply the operation to. Must be wrapped to the -π=<x<π range.
+/// * `approximation` - The approximation, e.g. a `Polynomial` fitted with `Polynomial::chebyshev_fit`.
+/// 
+/// ## Example
+/// 
+/// ```
+/// use mixed_num::trigonometry::*;
+/// use mixed_num::polynomial::*;
+/// use fixed::{types::extra::U28, FixedI32};
+/// 
+/// let approximation = Polynomial::<FixedI32<U28>, 10>::chebyshev_fit( libm::sin, -1.6f64, 1.6f64 );
+/// 
+/// let y = sin_with( FixedI32::<U28>::from_num(2.5), &approximation );
+/// assert!{ (y.to_num::<f64>()-2.5f64.sin()).abs() < 1e-6 };
+/// ``` 
+pub fn sin_with<T, A>( x: T, approximation: &A ) -> T
+    where T: crate::MixedNum + crate::MixedOps + crate::MixedNumSigned + crate::MixedPi,
+          A: crate::polynomial::MixedApproximation<T>
 {
     let mixed_pi_half = T::mixed_pi()/T::mixed_from_num(2);
 
     let mut x_ = x;
 
-    // Ensure that the angle is within the accurate range of the tailor series. 
+    // Ensure that the angle is within the accurate range of the approximation.
     if x < -mixed_pi_half
     {   
         let delta:T = x+mixed_pi_half;
@@ -84,13 +130,7 @@ pub fn sin<T>( x: T ) -> T
         let delta:T = x-mixed_pi_half;
         x_ = mixed_pi_half-delta;
     }
-
-    // Calculate sine by using 
-    let mut sinx = x_-( x_.mixed_powi(3)/T::mixed_from_num(6) );
-    sinx += x_.mixed_powi(5)/T::mixed_from_num(120);
-    sinx -= x_.mixed_powi(7)/T::mixed_from_num(5040);
-    sinx += x_.mixed_powi(9)/T::mixed_from_num(362880);
-    return sinx;
+    return approximation.approximate(x_);
 }
 
 /// Calculate cosine using a Taylor approximation of `cos(x)`.
@@ -110,7 +150,7 @@ pub fn sin<T>( x: T ) -> T
 /// 
 /// let mut x = 0f32;
 /// let mut y = cos(x);
-/// assert_eq!{ y, 1.0000035 };
+/// assert_eq!{ y, 1.0000036 };
 /// 
 /// x = 3.1415f32/2.0f32;
 /// y = cos(x);
@@ -134,7 +174,7 @@ pub fn sin<T>( x: T ) -> T
 /// 
 #[allow(dead_code)]
 pub fn cos<T>( x: T ) -> T
-    where T: crate::MixedNum + crate::MixedOps + crate::MixedPowi + crate::MixedWrapPhase + crate::MixedPi + crate::MixedNumSigned
+    where T: crate::MixedNum + crate::MixedOps + crate::MixedPi + crate::MixedNumSigned + crate::MixedWrapPhase + crate::MixedTypeInfo
 {
     // shift to enable use of more accurate sinepolynomial method.
     let mixed_pi_half = T::mixed_pi()/T::mixed_from_num(2);
@@ -144,8 +184,24 @@ pub fn cos<T>( x: T ) -> T
     return sin(x_shifted);
 }
 
+/// Calculate cos(x) using a custom approximation of `sin(x)` on the -π/2=<x=<π/2 range.
+/// 
+/// ## Arguments
+/// 
+/// * `x`             - The value to apply the operation to. Wrapped to the -π=<x<π range in the function.
+/// * `approximation` - The approximation of `sin(x)`, as for `sin_with`.
+pub fn cos_with<T, A>( x: T, approximation: &A ) -> T
+    where T: crate::MixedNum + crate::MixedOps + crate::MixedPi + crate::MixedNumSigned + crate::MixedWrapPhase,
+          A: crate::polynomial::MixedApproximation<T>
+{
+    let mixed_pi_half = T::mixed_pi()/T::mixed_from_num(2);
+    return sin_with(wrap_phase(x+mixed_pi_half), approximation);
+}
+
 /// Wrapps θ to the -π=<x<π range.
 /// 
+/// Runs in constant time, with the remainder of the division by 2π, see `MixedWrapPhase`.
+/// 
 /// ## Arguments 
 ///
 /// * `phi` - The unwrapped phase in radians.
@@ -159,24 +215,262 @@ pub fn cos<T>( x: T ) -> T
 /// let phi =  FixedI32::<U28>::from_num(6);
 /// let wrapped_phi = wrap_phase(phi);
 /// assert_eq!{ wrapped_phi.to_num::<f32>(), -0.2831853 };
+/// 
+/// assert!{ (wrap_phase(1e6f64)-(-0.3575641670518799f64)).abs() < 1e-9 };
 /// ``` 
 pub fn wrap_phase<T>( phi: T ) -> T 
-    where T: crate::MixedNum + crate::MixedOps + crate::MixedPi + crate::MixedNumSigned
+    where T: crate::MixedWrapPhase
+{
+    return phi.mixed_wrap_phase();
+}
+
+/// Wrapps θ to the 0=<x<2π range.
+/// 
+/// ## Arguments 
+///
+/// * `phi` - The unwrapped phase in radians.
+pub fn wrap_phase_positive<T>( phi: T ) -> T 
+    where T: crate::MixedWrapPhase + crate::MixedReal + crate::MixedNumSigned + crate::MixedOps + crate::MixedZero
+{
+    return phi.mixed_wrap_phase_positive();
+}
+
+/// Wrapps an angle in degrees to the -180=<x<180 range.
+/// 
+/// ## Arguments 
+///
+/// * `degrees` - The unwrapped angle in degrees.
+pub fn wrap_degrees<T>( degrees: T ) -> T 
+    where T: crate::MixedWrapPhase + crate::MixedReal + crate::MixedNumSigned + crate::MixedOps + crate::MixedZero
+{
+    return degrees.mixed_wrap_degrees();
+}
+
+/// Wrapps an angle in turns to the -0.5=<x<0.5 range.
+/// 
+/// ## Arguments 
+///
+/// * `turns` - The unwrapped angle in turns.
+pub fn wrap_turns<T>( turns: T ) -> T 
+    where T: crate::MixedWrapPhase + crate::MixedReal + crate::MixedNumSigned + crate::MixedOps + crate::MixedZero
+{
+    return turns.mixed_wrap_turns();
+}
+
+/// Calculate the sin and cos of an angle in degrees.
+/// 
+/// The angle is reduced to the -45=<r=<45 range around the nearest multiple of 90°, which is exact.
+/// The results are exact at multiples of 90°.
+/// 
+/// ## Arguments
+/// 
+/// * `degrees` - The angle in degrees.
+/// 
+/// ## Example
+/// 
+/// ```
+/// use mixed_num::trigonometry::*;
+/// 
+/// assert_eq!{ sincosd(-180f32), (0f32, -1f32) };
+/// assert!{ (sincosd(30f64).0-0.5f64).abs() < 1e-15 };
+/// ```
+pub fn sincosd<T>( degrees: T ) -> (T, T)
+    where T: crate::MixedReal + crate::MixedNumSigned + crate::MixedOps + crate::MixedZero + crate::MixedOne + crate::MixedWrapPhase + crate::MixedSin
+{
+    let d = degrees.mixed_wrap_degrees();
+    let d_: f64 = d.mixed_to_num();
+
+    // The quadrant, and the remainder.
+    let (quadrant, r) = if 135f64 <= d_
+    {
+        (2, d - T::mixed_from_num(180i32))
+    }
+    else if 45f64 <= d_
+    {
+        (1, d - T::mixed_from_num(90i32))
+    }
+    else if d_ < -135f64
+    {
+        (2, d + T::mixed_from_num(180i32))
+    }
+    else if d_ < -45f64
+    {
+        (3, d + T::mixed_from_num(90i32))
+    }
+    else
+    {
+        (0, d)
+    };
+
+    let (sin, cos) = if r == T::mixed_zero()
+    {
+        (T::mixed_zero(), T::mixed_one())
+    }
+    else
+    {
+        (r*T::mixed_from_num(core::f64::consts::PI/180f64)).mixed_sincos()
+    };
+
+    return match quadrant {
+        0 => ( sin,  cos),
+        1 => ( cos, -sin),
+        2 => (-sin, -cos),
+        _ => (-cos,  sin),
+    };
+}
+
+/// Calculate sin(x) of an angle in degrees, exact at multiples of 90°.
+pub fn sind<T>( degrees: T ) -> T
+    where T: crate::MixedReal + crate::MixedNumSigned + crate::MixedOps + crate::MixedZero + crate::MixedOne + crate::MixedWrapPhase + crate::MixedSin
+{
+    return sincosd(degrees).0;
+}
+
+/// Calculate cos(x) of an angle in degrees, exact at multiples of 90°.
+pub fn cosd<T>( degrees: T ) -> T
+    where T: crate::MixedReal + crate::MixedNumSigned + crate::MixedOps + crate::MixedZero + crate::MixedOne + crate::MixedWrapPhase + crate::MixedSin
 {
-    let mixed_pi  = T::mixed_pi();
-    let tau = T::mixed_from_num(2)*mixed_pi;
- 
-    let mut temp_scalar = phi;
-    
-    while temp_scalar < -mixed_pi
+    return sincosd(degrees).1;
+}
+
+/// Calculate atan2(y,x) in degrees, in the -180<x=<180 range.
+/// 
+/// The results are exact on the axes and the diagonals, at multiples of 45°.
+/// 
+/// ## Arguments
+/// 
+/// * `y` - Is the argument along the y or imaginary axis.
+/// * `x` - Is the argument along the x or real axis.
+/// 
+/// ## Example
+/// 
+/// ```
+/// use mixed_num::trigonometry::*;
+/// 
+/// assert_eq!{ atan2d(0f64, -2f64), 180f64 };
+/// assert_eq!{ atan2d(-3f64, -3f64), -135f64 };
+/// ```
+pub fn atan2d<T>( y: T, x: T ) -> T
+    where T: crate::MixedReal + crate::MixedNumSigned + crate::MixedOps + crate::MixedZero + crate::MixedAbs + crate::MixedAtan
+{
+    let zero = T::mixed_zero();
+    if y == zero
+    {
+        if x < zero
+        {
+            return T::mixed_from_num(180i32);
+        }
+        return zero;
+    }
+    if x == zero
+    {
+        if zero < y
+        {
+            return T::mixed_from_num(90i32);
+        }
+        return T::mixed_from_num(-90i32);
+    }
+    if y.mixed_abs() == x.mixed_abs()
+    {
+        let angle = if zero < x { T::mixed_from_num(45i32) } else { T::mixed_from_num(135i32) };
+        if y < zero
+        {
+            return -angle;
+        }
+        return angle;
+    }
+    return to_degrees(y.mixed_atan2(x));
+}
+
+/// Converts radians to degrees, for the `MixedAngleUnits` implementations.
+/// 
+/// Types whose maximum is below 180/π convert through f64, and only fail when the degrees are out of range.
+pub(crate) fn to_degrees<T>( radians: T ) -> T
+    where T: crate::MixedReal + crate::MixedOps
+{
+    let factor = 180f64/core::f64::consts::PI;
+    let max: f64 = T::mixed_max_value().mixed_to_num();
+    if factor < max
+    {
+        return radians*T::mixed_from_num(factor);
+    }
+    let radians: f64 = radians.mixed_to_num();
+    return T::mixed_from_num(radians*factor);
+}
+
+/// Wraps `x` to the -half=<x<half range in constant time, for the `MixedWrapPhase` implementations.
+/// 
+/// Uses the truncated remainder `%`, which is exact for floats, fixed-point and integers, and can not overflow.
+/// Types whose range is within -half=<x<half are returned unchanged.
+/// When the period is not representable by the type, the range is within -2*half=<x<2*half, and a single step of a period wraps `x`.
+/// A period which rounds to zero, e.g. a turn for integers, wraps to zero.
+pub(crate) fn wrap_half_period<T>( x: T, half: f64 ) -> T
+    where T: crate::MixedReal + crate::MixedNumSigned + crate::MixedOps + crate::MixedZero
+{
+    let min: f64 = T::mixed_min_value().mixed_to_num();
+    if -half <= min
+    {
+        return x;
+    }
+
+    let h = T::mixed_from_num(half);
+    if h <= T::mixed_zero()
+    {
+        return T::mixed_zero();
+    }
+
+    let max: f64 = T::mixed_max_value().mixed_to_num();
+    if max < 2f64*half
+    {
+        if x < -h
+        {
+            return x+h+h;
+        }
+        if h <= x
+        {
+            return x-h-h;
+        }
+        return x;
+    }
+
+    let period = h+h;
+    let mut r = x % period;
+    if r < -h
+    {
+        r += period;
+    }
+    else if h <= r
     {
-        temp_scalar = temp_scalar + tau;
+        r -= period;
     }
-    while mixed_pi <= temp_scalar
+    return r;
+}
+
+/// Wraps `x` to the 0=<x<2*half range in constant time, for the `MixedWrapPhase` implementations.
+/// 
+/// Negative values are raised by two half periods, in order, so the intermediate values stay in range.
+/// Types which can not represent the period saturate at their maximum.
+/// Tiny negative floats round up to the period when it is added, and wrap to zero.
+pub(crate) fn wrap_period_positive<T>( x: T, half: f64 ) -> T
+    where T: crate::MixedReal + crate::MixedNumSigned + crate::MixedOps + crate::MixedZero
+{
+    let r = wrap_half_period(x, half);
+    if r < T::mixed_zero()
     {
-        temp_scalar = temp_scalar - tau;
+        let h = T::mixed_from_num(half);
+        // -half=<r<0, so 0=<r+h<h, up to the rounding of floats.
+        let r = r+h;
+        if h <= r
+        {
+            return T::mixed_zero();
+        }
+        if T::mixed_max_value()-h < r
+        {
+            return T::mixed_max_value();
+        }
+        return r+h;
     }
-    return temp_scalar;
+    return r;
 }
 
 /// Rase fixed number to an integer-valued power.
@@ -13,9 +13,9 @@
 /// 
 /// TODO 
 fn atan_poly_1<T>( y: T, x: T ) -> T
-    where T: crate::MixedNum + crate::MixedNumSigned + crate::MixedOps + crate::MixedPowi
+    where T: crate::MixedNum + crate::MixedNumSigned + crate::MixedOps
 {
-    let phi = (x*y) / ( x.mixed_powi(2) + T::mixed_from_num( 0.28125 )*y.mixed_powi(2) );
+    let phi = (x*y) / ( x*x + T::mixed_from_num( 0.28125 )*(y*y) );
     return phi;
 }
 
@@ -32,16 +32,16 @@ fn atan_poly_1<T>( y: T, x: T ) -> T
 /// 
 /// TODO 
 fn atan_poly_2<T>( y: T, x: T ) -> T
-    where T: crate::MixedNum + crate::MixedNumSigned + crate::MixedOps + crate::MixedPowi
+    where T: crate::MixedNum + crate::MixedNumSigned + crate::MixedOps
 {
-    let phi     = (x*y) / ( y.mixed_powi(2) + T::mixed_from_num( 0.28125 )*x.mixed_powi(2) );
+    let phi     = (x*y) / ( y*y + T::mixed_from_num( 0.28125 )*(x*x) );
     return phi;
 }
 
 
 /// Calculate atan2(y,x) using a selection of polynomial approximations, one for each octant in the unit circle.
 /// 
-/// The method is accurat within 0.028 degrees.
+/// The method is accurate within 0.28 degrees (5e-3 rad).
 /// 
 /// \[1\] R. G. Lyons, Streamlining Digital Signal Processing, Second Edition, IEEE Press, 2012.
 /// 
@@ -162,6 +162,59 @@ pub fn atan2<T>( y: T, x: T ) -> T
     }
 }
 
+/// Calculate atan2(y,x) using a custom approximation of `atan(r)` on the 0=<r=<1 range.
+/// 
+/// The ratio `r = min(|y|,|x|)/max(|y|,|x|)` is evaluated in the approximation, and the result is mapped to the octant of `(x,y)`.
+/// 
+/// ## Arguments 
+///
+/// * `y`             - Is the argument along the y or imaginary axis.
+/// * `x`             - Is the argument along the x or real axis.
+/// * `approximation` - The approximation, e.g. a `Polynomial` fitted with `Polynomial::chebyshev_fit`.
+/// 
+/// ## Example
+/// 
+/// ```
+/// use mixed_num::trigonometry::*;
+/// use mixed_num::polynomial::*;
+/// use fixed::{types::extra::U28, FixedI32};
+/// 
+/// let approximation = Polynomial::<FixedI32<U28>, 12>::chebyshev_fit( libm::atan, 0f64, 1f64 );
+/// 
+/// let arg = atan::atan2_with( FixedI32::<U28>::from_num(0.6), FixedI32::<U28>::from_num(-0.4), &approximation );
+/// assert!{ (arg.to_num::<f64>()-0.6f64.atan2(-0.4f64)).abs() < 1e-6 };
+/// ``` 
+pub fn atan2_with<T, A>( y: T, x: T, approximation: &A ) -> T
+    where T: crate::MixedReal + crate::MixedNumSigned + crate::MixedOps + crate::MixedZero + crate::MixedPi + crate::MixedAbs,
+          A: crate::polynomial::MixedApproximation<T>
+{
+    let y_abs = y.mixed_abs();
+    let x_abs = x.mixed_abs();
+    if y_abs == T::mixed_zero() && x_abs == T::mixed_zero()
+    {
+        return T::mixed_zero();
+    }
+
+    let mut phi = if y_abs <= x_abs
+    {
+        approximation.approximate( y_abs/x_abs )
+    }
+    else
+    {
+        T::mixed_pi()/T::mixed_from_num( 2 ) - approximation.approximate( x_abs/y_abs )
+    };
+
+    if x.mixed_is_negative()
+    {
+        phi = T::mixed_pi() - phi;
+    }
+    if y.mixed_is_negative()
+    {
+        phi = -phi;
+    }
+    return phi;
+}
+
 /// Calculate atan(x) using a polynomial approximation of `atan(x)`.
 /// 
 /// Utilizes a polynomial methodto estimate the angle θ \[radians\].
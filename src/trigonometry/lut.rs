@@ -0,0 +1,282 @@
+//! Lookup-table trigonometry, for narrow fixed-point and integer types.
+//!
+//! The functions use integer arithmetic only, with angles as binary angles (BAM), where a full turn is `2^32`.
+//! A binary angle wraps around naturally, so no phase wrapping is needed.
+//! Amplitudes are in the Q30 format, where `1<<30` is one.
+//!
+//! The sine table is a quarter wave of 256 segments, generated at compile time.
+//! With linear interpolation the error is below 5e-6, and with quadratic interpolation below 2e-8.
+//! The atan table covers the first octant with 256 segments and linear interpolation, with an error below 2e-6 rad.
+//!
+//! `FixedI8` and `FixedI16` use these tables for `MixedSin`, `MixedCos` and `MixedAtan`.
+//!
+//! ## Example
+//!
+//! ```
+//! use mixed_num::trigonometry::lut::*;
+//!
+//! // An eighth of a turn, π/4.
+//! let y = sin_bam( 1u32<<29, Interpolation::Linear );
+//! assert!{ (y as f64/(1u64<<30) as f64-0.5f64.sqrt()).abs() < 5e-6 };
+//!
+//! // Q14 samples of a tone, with a 16 bit phase accumulator.
+//! let phase: u16 = 0x4000;
+//! let sample = (sin_bam( (phase as u32)<<16, Interpolation::Linear ) >> 16) as i16;
+//! assert_eq!{ sample, 16384 };
+//! ```
+
+/// Interpolation between the entries of the sine table.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Interpolation {
+    /// Linear interpolation, with an error below 5e-6.
+    Linear,
+    /// Quadratic interpolation through three entries, with an error below 2e-8.
+    Quadratic,
+}
+
+/// Number of segments in the quarter wave sine table, and in the atan table.
+const SEGMENTS_LOG2: u32 = 8;
+const SEGMENTS: usize = 1<<SEGMENTS_LOG2;
+
+/// sin(x) by its Taylor series, for the table generation.
+const fn taylor_sin( x: f64 ) -> f64
+{
+    let mut term = x;
+    let mut sum  = x;
+    let mut k = 1;
+    while k < 20
+    {
+        term = -term*x*x/(((2*k)*(2*k+1)) as f64);
+        sum += term;
+        k += 1;
+    }
+    return sum;
+}
+
+/// atan(x) by Euler's series `Σ 2^2n*(n!)^2/(2n+1)! * x^(2n+1)/(1+x^2)^(n+1)`, for the table generation.
+const fn euler_atan( x: f64 ) -> f64
+{
+    let ratio = x*x/(1f64+x*x);
+    let mut term = x/(1f64+x*x);
+    let mut sum  = term;
+    let mut n = 1;
+    while n < 80
+    {
+        term = term*ratio*((2*n) as f64)/((2*n+1) as f64);
+        sum += term;
+        n += 1;
+    }
+    return sum;
+}
+
+/// Quarter wave sine table in Q30, with guard entries past π/2 for the interpolation.
+const fn sine_table() -> [i32; SEGMENTS+3]
+{
+    let mut table = [0i32; SEGMENTS+3];
+    let mut i = 0;
+    while i < SEGMENTS+3
+    {
+        let x = core::f64::consts::FRAC_PI_2*(i as f64)/(SEGMENTS as f64);
+        table[i] = (taylor_sin(x)*((1u64<<30) as f64) + 0.5f64) as i32;
+        i += 1;
+    }
+    return table;
+}
+
+/// First octant atan table, with binary angles, and a guard entry.
+const fn atan_table() -> [i32; SEGMENTS+2]
+{
+    let mut table = [0i32; SEGMENTS+2];
+    let mut i = 0;
+    while i < SEGMENTS+2
+    {
+        let x = (i as f64)/(SEGMENTS as f64);
+        table[i] = (euler_atan(x)/core::f64::consts::TAU*((1u64<<32) as f64) + 0.5f64) as i32;
+        i += 1;
+    }
+    return table;
+}
+
+/// Quarter wave sine table in Q30, `SINE_TABLE[i] = sin(π/2*i/256)`, including guard entries past π/2.
+pub const SINE_TABLE: [i32; SEGMENTS+3] = sine_table();
+
+/// First octant atan table in binary angles, `ATAN_TABLE[i] = atan(i/256)`, including a guard entry.
+pub const ATAN_TABLE: [i32; SEGMENTS+2] = atan_table();
+
+/// `2^64/2π`, rounded.
+const INV_TAU_Q64: i128 = (18446744073709551616f64/core::f64::consts::TAU) as i128;
+
+/// `2π*2^60`, rounded.
+const TAU_Q60: i128 = (core::f64::consts::TAU*1152921504606846976f64) as i128;
+
+/// Sine of the first quadrant, for `0=<p=<2^30`, a quarter turn.
+fn quarter_sin( p: u32, interpolation: Interpolation ) -> i32
+{
+    let shift = 30-SEGMENTS_LOG2;
+    let index = (p >> shift) as usize;
+    let frac  = (p & ((1<<shift)-1)) as i64;
+
+    let y0 = SINE_TABLE[index] as i64;
+    let y1 = SINE_TABLE[index+1] as i64;
+    let linear = y0 + (((y1-y0)*frac) >> shift);
+    return match interpolation {
+        Interpolation::Linear    => linear as i32,
+        Interpolation::Quadratic => {
+            // Newton's form through three entries, y0 + t*(y1-y0) + t*(t-1)/2*(y2-2y1+y0).
+            let y2 = SINE_TABLE[index+2] as i64;
+            let curvature = ((y2-2*y1+y0)*frac*(frac-(1<<shift))) >> (2*shift+1);
+            (linear + curvature) as i32
+        },
+    };
+}
+
+/// Sine of a binary angle, where a full turn is `2^32`.
+///
+/// ## Arguments
+///
+/// * `angle`         - The binary angle.
+/// * `interpolation` - Linear or quadratic interpolation of the table.
+///
+/// Returns the sine in Q30.
+pub fn sin_bam( angle: u32, interpolation: Interpolation ) -> i32
+{
+    let p = angle & ((1<<30)-1);
+    return match angle >> 30 {
+        0 =>  quarter_sin(p, interpolation),
+        1 =>  quarter_sin((1<<30)-p, interpolation),
+        2 => -quarter_sin(p, interpolation),
+        _ => -quarter_sin((1<<30)-p, interpolation),
+    };
+}
+
+/// Cosine of a binary angle, where a full turn is `2^32`.
+///
+/// Returns the cosine in Q30.
+pub fn cos_bam( angle: u32, interpolation: Interpolation ) -> i32
+{
+    return sin_bam(angle.wrapping_add(1<<30), interpolation);
+}
+
+/// atan of the first octant, for `0=<r=<2^22`, the ratio in Q22.
+fn octant_atan( r: i64 ) -> i64
+{
+    let shift = 22-SEGMENTS_LOG2;
+    let index = (r >> shift) as usize;
+    let frac  = r & ((1<<shift)-1);
+
+    let y0 = ATAN_TABLE[index] as i64;
+    let y1 = ATAN_TABLE[index+1] as i64;
+    return y0 + (((y1-y0)*frac) >> shift);
+}
+
+/// The four-quadrant arctangent of `y/x`, as a binary angle, where a full turn is `2^32`.
+///
+/// The arguments are integers, or the raw bits of fixed-point numbers with the same number of fractional bits.
+/// The octants are reduced to the 0=<r=<1 ratio in the table, which avoids overflow.
+///
+/// ## Arguments
+///
+/// * `y` - Is the argument along the y or imaginary axis.
+/// * `x` - Is the argument along the x or real axis.
+///
+/// Returns the angle in the `-2^31<θ=<2^31` range, where `2^31` is π.
+///
+/// ## Example
+///
+/// ```
+/// use mixed_num::trigonometry::lut::*;
+///
+/// assert_eq!{ atan2_bam( 1, 1 ), 1<<29 };
+/// assert_eq!{ atan2_bam( 0, -5 ), 1<<31 };
+/// assert_eq!{ atan2_bam( -3, 0 ), -(1<<30) };
+/// ```
+pub fn atan2_bam( y: i64, x: i64 ) -> i64
+{
+    let y_abs = (y as i128).abs();
+    let x_abs = (x as i128).abs();
+    if y_abs == 0 && x_abs == 0
+    {
+        return 0;
+    }
+
+    let mut angle = if y_abs <= x_abs
+    {
+        octant_atan( ((y_abs << 22)/x_abs) as i64 )
+    }
+    else
+    {
+        (1<<30) - octant_atan( ((x_abs << 22)/y_abs) as i64 )
+    };
+
+    if x < 0
+    {
+        angle = (1<<31) - angle;
+    }
+    if y < 0
+    {
+        angle = -angle;
+    }
+    return angle;
+}
+
+/// Convert an angle in radians to a binary angle, where a full turn is `2^32`.
+///
+/// Angles outside the -π=<x<π range wrap around.
+///
+/// ## Arguments
+///
+/// * `bits`      - The raw bits of a fixed-point angle, or an integer for `frac_bits = 0`.
+/// * `frac_bits` - The number of fractional bits of `bits`, at most 64.
+pub fn radians_to_bam( bits: i64, frac_bits: u32 ) -> u32
+{
+    let shift = frac_bits + 32;
+    let product = (bits as i128)*INV_TAU_Q64;
+    return ((product + (1i128 << (shift-1))) >> shift) as u32;
+}
+
+/// Convert a binary angle, where a full turn is `2^32`, to the raw bits of a fixed-point angle in radians.
+///
+/// ## Arguments
+///
+/// * `angle`     - The binary angle.
+/// * `frac_bits` - The number of fractional bits of the result, at most 92.
+pub fn bam_to_radians( angle: i64, frac_bits: u32 ) -> i64
+{
+    let shift = 92 - frac_bits;
+    let product = (angle as i128)*TAU_Q60;
+    return ((product + (1i128 << (shift-1))) >> shift) as i64;
+}
+
+/// Convert a Q30 value to the raw bits of a fixed-point value, rounding to nearest.
+///
+/// ## Arguments
+///
+/// * `value`     - The Q30 value.
+/// * `frac_bits` - The number of fractional bits of the result.
+pub fn q30_to_bits( value: i32, frac_bits: u32 ) -> i64
+{
+    if frac_bits < 30
+    {
+        let shift = 30 - frac_bits;
+        return ((value as i64) + (1 << (shift-1))) >> shift;
+    }
+    return (value as i64) << (frac_bits-30);
+}
+
+/// Sine of the raw bits of a fixed-point angle, for the `MixedSin` backend of narrow types.
+pub(crate) fn sin_bits( bits: i64, frac_bits: u32 ) -> i64
+{
+    return q30_to_bits(sin_bam(radians_to_bam(bits, frac_bits), Interpolation::Linear), frac_bits);
+}
+
+/// Cosine of the raw bits of a fixed-point angle, for the `MixedCos` backend of narrow types.
+pub(crate) fn cos_bits( bits: i64, frac_bits: u32 ) -> i64
+{
+    return q30_to_bits(cos_bam(radians_to_bam(bits, frac_bits), Interpolation::Linear), frac_bits);
+}
+
+/// atan2 of the raw bits of fixed-point arguments, for the `MixedAtan` backend of narrow types.
+pub(crate) fn atan2_bits( y: i64, x: i64, frac_bits: u32 ) -> i64
+{
+    return bam_to_radians(atan2_bam(y, x), frac_bits);
+}
@@ -0,0 +1,181 @@
+//! Reciprocal and reciprocal square root by Newton-Raphson iterations, for fixed-point types.
+//!
+//! The functions use integer arithmetic only, on the raw bits of a fixed-point magnitude.
+//! The magnitude is normalized by its leading zeros to a mantissa `d` in the 0.5=<d<1 range, which seeds a linear estimate.
+//! Each iteration doubles the number of accurate bits, up to the Q62 format of the estimate.
+//!
+//! | Function     | Iteration             | Seed accuracy |
+//! |--------------|-----------------------|---------------|
+//! | `recip_bits` | y = y(2-dy)           | 4.1 bits      |
+//! | `rsqrt_bits` | y = y(3-dy²)/2        | 5.2 bits      |
+//!
+//! ## Example
+//!
+//! ```
+//! use mixed_num::trigonometry::recip::*;
+//! use fixed::{types::extra::U16, FixedI32};
+//!
+//! // 1/2.5 = 0.4 in Q16.
+//! let x = FixedI32::<U16>::from_num(2.5);
+//! let y = recip_bits( x.to_bits() as u64, 16, recip_iterations::<FixedI32<U16>>() );
+//! assert_eq!{ y, 26214 };
+//!
+//! // 1/sqrt(4) = 0.5 in Q16.
+//! let y = rsqrt_bits( 4<<16, 16, rsqrt_iterations::<FixedI32<U16>>() );
+//! assert_eq!{ y, 1<<15 };
+//! ```
+
+/// The linear reciprocal seed 48/17-32/17d, in Q62.
+const RECIP_OFFSET_Q62: u64 = 0xB4B4_B4B4_B4B4_B4B5;
+const RECIP_SLOPE_Q62:  u64 = 0x7878_7878_7878_7878;
+/// The linear reciprocal square root seed 1.8016884-0.8284271d, in Q62.
+const RSQRT_OFFSET_Q62: u64 = 0x734E_DCB8_ECE3_9C00;
+const RSQRT_SLOPE_Q62:  u64 = 0x3504_F333_F9DE_6800;
+/// 1/sqrt(2) in Q64.
+const FRAC_1_SQRT_2_Q64: u128 = 0xB504_F333_F9DE_6484;
+
+/// The number of Newton-Raphson iterations of `recip_bits` for a result accurate to the bits of `T`.
+///
+/// ## Example
+///
+/// ```
+/// use mixed_num::trigonometry::recip::*;
+/// use fixed::{types::extra::{U12, U28}, FixedI16, FixedI32};
+///
+/// assert_eq!{ recip_iterations::<FixedI16<U12>>(), 3 };
+/// assert_eq!{ recip_iterations::<FixedI32<U28>>(), 4 };
+/// ```
+pub fn recip_iterations<T>() -> usize
+    where T: crate::MixedTypeInfo
+{
+    // Accurate bits, in tenths.
+    let target = 10*(T::INT_BITS+T::FRAC_BITS+1);
+    let mut bits = 40;
+    let mut iterations = 0;
+    while bits < target && iterations < 5
+    {
+        iterations += 1;
+        bits *= 2;
+    }
+    return iterations;
+}
+
+/// The number of Newton-Raphson iterations of `rsqrt_bits` for a result accurate to the bits of `T`.
+///
+/// ## Example
+///
+/// ```
+/// use mixed_num::trigonometry::recip::*;
+/// use fixed::{types::extra::{U12, U28}, FixedI16, FixedI32};
+///
+/// assert_eq!{ rsqrt_iterations::<FixedI16<U12>>(), 2 };
+/// assert_eq!{ rsqrt_iterations::<FixedI32<U28>>(), 3 };
+/// ```
+pub fn rsqrt_iterations<T>() -> usize
+    where T: crate::MixedTypeInfo
+{
+    // Accurate bits, in tenths.
+    let target = 10*(T::INT_BITS+T::FRAC_BITS+1);
+    let mut bits = 52;
+    let mut iterations = 0;
+    while bits < target && iterations < 5
+    {
+        iterations += 1;
+        // The error after an iteration is 1.5 times the squared error.
+        bits = 2*bits - 6;
+    }
+    return iterations;
+}
+
+/// Normalize a magnitude to a Q64 mantissa in the 0.5=<d<1 range, and the exponent where `magnitude = d*2^exponent`.
+fn normalize( magnitude: u64, frac_bits: u32 ) -> (u64, i32)
+{
+    let leading_zeros = magnitude.leading_zeros();
+    return (magnitude << leading_zeros, 64 - leading_zeros as i32 - frac_bits as i32);
+}
+
+/// Scale a Q62 estimate by `2^exponent` to raw bits with `frac_bits` fractional bits, rounding to nearest.
+///
+/// Results above 128 bits saturate at `u128::MAX`.
+fn denormalize( y: u64, exponent: i32, frac_bits: u32 ) -> u128
+{
+    let shift = exponent - 62 + frac_bits as i32;
+    if 0 <= shift
+    {
+        if 64 <= shift
+        {
+            return u128::MAX;
+        }
+        return (y as u128) << shift;
+    }
+    let shift = -shift as u32;
+    if 128 <= shift
+    {
+        return 0;
+    }
+    return ((y as u128) + (1u128 << (shift-1))) >> shift;
+}
+
+/// The reciprocal of the raw bits of a fixed-point magnitude, as raw bits with the same fractional bits.
+///
+/// The result is not limited to the range of the type. The reciprocal of zero is `u128::MAX`.
+///
+/// ## Arguments
+///
+/// * `magnitude`  - The raw bits of the magnitude.
+/// * `frac_bits`  - The number of fractional bits of `magnitude` and of the result, at most 64.
+/// * `iterations` - The number of Newton-Raphson iterations, see `recip_iterations`.
+pub fn recip_bits( magnitude: u64, frac_bits: u32, iterations: usize ) -> u128
+{
+    if magnitude == 0
+    {
+        return u128::MAX;
+    }
+
+    let (d, exponent) = normalize(magnitude, frac_bits);
+
+    // 1 < 1/d =< 2, in Q62.
+    let mut y = RECIP_OFFSET_Q62 - (((d as u128)*(RECIP_SLOPE_Q62 as u128)) >> 64) as u64;
+    for _n in 0..iterations
+    {
+        let dy = (((d as u128)*(y as u128)) >> 64) as u64;
+        y = (((y as u128)*(((2u64 << 62) - dy) as u128)) >> 62) as u64;
+    }
+    return denormalize(y, -exponent, frac_bits);
+}
+
+/// The reciprocal square root of the raw bits of a fixed-point magnitude, as raw bits with the same fractional bits.
+///
+/// The result is not limited to the range of the type. The reciprocal square root of zero is `u128::MAX`.
+///
+/// ## Arguments
+///
+/// * `magnitude`  - The raw bits of the magnitude.
+/// * `frac_bits`  - The number of fractional bits of `magnitude` and of the result, at most 64.
+/// * `iterations` - The number of Newton-Raphson iterations, see `rsqrt_iterations`.
+pub fn rsqrt_bits( magnitude: u64, frac_bits: u32, iterations: usize ) -> u128
+{
+    if magnitude == 0
+    {
+        return u128::MAX;
+    }
+
+    let (d, exponent) = normalize(magnitude, frac_bits);
+
+    // 1 < 1/sqrt(d) =< sqrt(2), in Q62.
+    let mut y = RSQRT_OFFSET_Q62 - (((d as u128)*(RSQRT_SLOPE_Q62 as u128)) >> 64) as u64;
+    for _n in 0..iterations
+    {
+        let y2  = (((y as u128)*(y as u128)) >> 62) as u64;
+        let dy2 = (((d as u128)*(y2 as u128)) >> 64) as u64;
+        y = (((y as u128)*(((3u64 << 62) - dy2) as u128)) >> 63) as u64;
+    }
+
+    // The square root of an odd power of two leaves a factor of 1/sqrt(2).
+    let odd = exponent & 1;
+    if odd != 0
+    {
+        y = (((y as u128)*FRAC_1_SQRT_2_Q64) >> 64) as u64;
+    }
+    return denormalize(y, -(exponent-odd)/2, frac_bits);
+}
@@ -60,32 +60,200 @@ use crate::*;
 pub fn niirf<T>( x: T, iterations: usize ) -> T 
     where T: crate::MixedReal + crate::MixedZero + crate::MixedAbs + crate::MixedOps + crate::MixedPowi
 {
-    if x == T::mixed_zero()
+    return niirf_with_lut( x, iterations, &NiirfLut::new() );
+}
+
+/// The number of NIIRF iterations for a result accurate to the fractional bits of `T`.
+/// 
+/// The normalized estimate is accurate to 5.5, 10.4 and 15.4 bits after one, two and three iterations, and gains 3.7 bits per further iteration.
+/// The count is limited to 13 iterations, where `f64` has converged.
+/// 
+/// ## Example
+/// 
+/// ```
+/// use mixed_num::trigonometry::*;
+/// use fixed::{types::extra::{U12, U28}, FixedI16, FixedI32};
+/// 
+/// assert_eq!{ sqrt::niirf_iterations::<FixedI16<U12>>(), 3 };
+/// assert_eq!{ sqrt::niirf_iterations::<FixedI32<U28>>(), 7 };
+/// 
+/// let x = FixedI32::<U28>::from_num(0.23);
+/// let y = sqrt::niirf(x, sqrt::niirf_iterations::<FixedI32<U28>>());
+/// assert!{ (y.to_num::<f64>()-0.23f64.sqrt()).abs() < 1e-8 };
+/// ```
+pub fn niirf_iterations<T>() -> usize
+    where T: crate::MixedTypeInfo
+{
+    // Accurate bits, in tenths.
+    let target = 10*T::FRAC_BITS;
+    let mut bits = 55;
+    let mut iterations = 1;
+    while bits < target && iterations < 13
     {
-        return T::mixed_zero();
+        iterations += 1;
+        bits += match iterations {
+            2 => 49,
+            3 => 50,
+            _ => 37,
+        };
     }
+    return iterations;
+}
 
-    let mut x_ = x.mixed_abs();
+/// Calculate the square root of each element in `input` using the NIIRF method, writing the result to `output`.
+/// 
+/// The lookup-table for the acceleration factor β is built once for the whole slice, see `niirf`.
+/// 
+/// Only the first `min(input.len(), output.len())` elements are processed.
+/// 
+/// ## Arguments 
+///
+/// * `input`      - The arguments which to calculate the root of.
+/// * `output`     - The buffer to write the roots to.
+/// * `iterations` - The number of iterations to run (start with 2).
+/// 
+/// ## Example
+/// 
+/// ```
+/// use mixed_num::trigonometry::*;
+/// use fixed::{types::extra::U28, FixedI32};
+/// 
+/// let x = [FixedI32::<U28>::from_num(0.23), FixedI32::<U28>::from_num(1.6)];
+/// let mut y = [FixedI32::<U28>::from_num(0); 2];
+/// sqrt::niirf_slice(&x, &mut y, 2);
+/// assert_eq!{ y[0].to_num::<f32>(), 0.47960657f32 };
+/// assert_eq!{ y[1].to_num::<f32>(), 1.2644687f32 };
+/// ``` 
+pub fn niirf_slice<T>( input: &[T], output: &mut [T], iterations: usize )
+    where T: crate::MixedReal + crate::MixedZero + crate::MixedAbs + crate::MixedOps + crate::MixedPowi
+{
+    let lut = NiirfLut::new();
+    for (y, x) in output.iter_mut().zip(input.iter())
+    {
+        *y = niirf_with_lut( *x, iterations, &lut );
+    }
+}
 
-    // First we normalize x to the range 0.25 =< x < 1.
-    let mut norm = 0i32; // Number of normalizations.
-    while x_< T::mixed_from_num(0.25)
+/// The hypotenuse `sqrt(a²+b²)` of two magnitudes, such as the raw bits of fixed-point values, rounded to nearest.
+/// 
+/// The sum of squares is calculated exactly in 128 bits for magnitudes below `2^63`.
+/// Larger magnitudes are scaled down before squaring, and the result scaled up again, saturating at `u128::MAX`.
+/// 
+/// ## Arguments 
+///
+/// * `a` - The first magnitude.
+/// * `b` - The second magnitude.
+/// 
+/// ## Example
+/// 
+/// ```
+/// use mixed_num::trigonometry::*;
+/// 
+/// assert_eq!{ sqrt::hypot_bits(3<<60, 4<<60), 5<<60 };
+/// assert_eq!{ sqrt::hypot_bits(1, 1), 1 };
+/// assert_eq!{ sqrt::hypot_bits(2, 2), 3 };
+/// ```
+pub fn hypot_bits( a: u128, b: u128 ) -> u128
+{
+    let (max, min) = if a < b { (b, a) } else { (a, b) };
+
+    // Scale the magnitudes below 2^63, so that the sum of squares fits in 128 bits.
+    let shift = (128 - max.leading_zeros()).saturating_sub(63);
+    let max = max >> shift;
+    let min = min >> shift;
+
+    let sum  = max*max + min*min;
+    let mut root = isqrt(sum);
+    // Round to nearest, where sum >= (root+1/2)^2.
+    if root < sum - root*root
     {
-        norm -=1;
-        x_ = x_*T::mixed_from_num(4);
+        root += 1;
     }
-    while T::mixed_from_num(1.0) <= x_
+
+    if root.leading_zeros() < shift
     {
-        norm +=1;
-        x_ = x_/T::mixed_from_num(4);
+        return u128::MAX;
     }
+    return root << shift;
+}
 
-    /// LUT for getting the acceleration factor β.
-    fn beta<T>( x: T) -> T
-        where T: MixedReal
-    {   
-        // There is one β value for each of the 12 regions in the range 4/16 to 16/16.
-        let beta_values = [ T::mixed_from_num(0.961914),
+/// A fast estimate of the hypotenuse `sqrt(a²+b²)` of two magnitudes, by the alpha max plus beta min method, with two segments.
+/// 
+/// The estimate is `max(max+5/32*min, 27/32*max+71/128*min)`, with an error below 1.3%, and up to two less from rounding down the scaled magnitudes.
+/// The factors are small integers over powers of two, so no division is needed.
+/// 
+/// ## Arguments 
+///
+/// * `a` - The first magnitude.
+/// * `b` - The second magnitude.
+/// 
+/// ## Example
+/// 
+/// ```
+/// use mixed_num::trigonometry::*;
+/// 
+/// let estimate = sqrt::hypot_fast_bits(3<<20, 4<<20);
+/// assert!{ (estimate as f64/(5<<20) as f64-1f64).abs() < 0.013 };
+/// ```
+pub fn hypot_fast_bits( a: u128, b: u128 ) -> u128
+{
+    let (max, min) = if a < b { (b, a) } else { (a, b) };
+
+    let z0 = max.saturating_add( scale_floor(min, 5, 5) );
+    let z1 = scale_floor(max, 27, 5).saturating_add( scale_floor(min, 71, 7) );
+    return z0.max(z1);
+}
+
+/// `floor(x*numerator/2^shift)`, without overflow for numerators up to `2^shift`.
+fn scale_floor( x: u128, numerator: u128, shift: u32 ) -> u128
+{
+    let fraction = x & ((1 << shift) - 1);
+    return (x >> shift)*numerator + ((fraction*numerator) >> shift);
+}
+
+/// `floor(sqrt(x))`, computed one bit of the root at a time.
+fn isqrt( x: u128 ) -> u128
+{
+    let mut remainder = x;
+    let mut root = 0u128;
+    // The highest power of four at most `x`.
+    let mut bit = if x == 0 { 0 } else { 1u128 << ((127 - x.leading_zeros()) & !1) };
+    while bit != 0
+    {
+        if root + bit <= remainder
+        {
+            remainder -= root + bit;
+            root = (root >> 1) + bit;
+        }
+        else
+        {
+            root >>= 1;
+        }
+        bit >>= 2;
+    }
+    return root;
+}
+
+/// Constants used by the NIIRF method, including the LUT for the acceleration factor β.
+struct NiirfLut<T>
+{
+    /// There is one β value for each of the 12 regions in the range 4/16 to 16/16.
+    beta_values: [T; 12],
+    /// Upper limits of the 11 first regions.
+    limits:      [T; 11],
+    quarter:     T,
+    one:         T,
+    two:         T,
+    three:       T,
+    y0_offset:   T,
+}
+
+impl <T: MixedReal> NiirfLut<T>
+{
+    fn new() -> Self
+    {
+        return NiirfLut{
+            beta_values: [  T::mixed_from_num(0.961914),
                             T::mixed_from_num(0.840332),
                             T::mixed_from_num(0.782715),
                             T::mixed_from_num(0.734869),
@@ -96,51 +264,69 @@ pub fn niirf<T>( x: T, iterations: usize ) -> T
                             T::mixed_from_num(0.573731),
                             T::mixed_from_num(0.556152),
                             T::mixed_from_num(0.516113),
-                            T::mixed_from_num(0.502930)];
+                            T::mixed_from_num(0.502930)],
+            limits: [   T::mixed_from_num(5.0/16.0),
+                        T::mixed_from_num(6.0/16.0),
+                        T::mixed_from_num(7.0/16.0),
+                        T::mixed_from_num(8.0/16.0),
+                        T::mixed_from_num(9.0/16.0),
+                        T::mixed_from_num(10.0/16.0),
+                        T::mixed_from_num(11.0/16.0),
+                        T::mixed_from_num(12.0/16.0),
+                        T::mixed_from_num(13.0/16.0),
+                        T::mixed_from_num(14.0/16.0),
+                        T::mixed_from_num(15.0/16.0)],
+            quarter:   T::mixed_from_num(0.25),
+            one:       T::mixed_from_num(1.0),
+            two:       T::mixed_from_num(2),
+            three:     T::mixed_from_num(3),
+            y0_offset: T::mixed_from_num(0.354167),
+        };
+    }
 
-        if x < T::mixed_from_num(5.0/16.0) {
-            return beta_values[0];
-        }
-        else if x < T::mixed_from_num(6.0/16.0) {
-            return beta_values[1];
-        }
-        else if x < T::mixed_from_num(7.0/16.0) {
-            return beta_values[2];
-        }
-        else if x < T::mixed_from_num(8.0/16.0) {
-            return beta_values[3];
-        }
-        else if x < T::mixed_from_num(9.0/16.0) {
-            return beta_values[4];
-        }
-        else if x < T::mixed_from_num(10.0/16.0) {
-            return beta_values[5];
-        }
-        else if x < T::mixed_from_num(11.0/16.0) {
-            return beta_values[6];
-        }
-        else if x < T::mixed_from_num(12.0/16.0) {
-            return beta_values[7];
-        }
-        else if x < T::mixed_from_num(13.0/16.0) {
-            return beta_values[8];
-        }
-        else if x < T::mixed_from_num(14.0/16.0) {
-            return beta_values[9];
-        }
-        else if x < T::mixed_from_num(15.0/16.0) {
-            return beta_values[10];
-        }
-        else {
-            return beta_values[11];
+    /// LUT for getting the acceleration factor β.
+    fn beta( &self, x: T ) -> T
+    {
+        for (limit, beta) in self.limits.iter().zip(self.beta_values.iter())
+        {
+            if x < *limit {
+                return *beta;
+            }
         }
+        return self.beta_values[11];
+    }
+}
+
+fn niirf_with_lut<T>( x: T, iterations: usize, lut: &NiirfLut<T> ) -> T 
+    where T: crate::MixedReal + crate::MixedZero + crate::MixedAbs + crate::MixedOps + crate::MixedPowi
+{
+    if x == T::mixed_zero()
+    {
+        return T::mixed_zero();
+    }
+
+    let mut x_ = x.mixed_abs();
+
+    // First we normalize x to the range 0.25 =< x < 1.
+    let mut norm = 0i32; // Number of normalizations.
+    while x_< lut.quarter
+    {
+        norm -=1;
+        // Doubled twice, as 4 is out of range for some types.
+        x_ = x_+x_;
+        x_ = x_+x_;
+    }
+    while lut.one <= x_
+    {
+        norm +=1;
+        x_ = x_*lut.quarter;
     }
 
     // Estimate the square root for x, when 0.25 =< x < 1.
-    let mut y = (T::mixed_from_num(2)*x_)/T::mixed_from_num(3) + T::mixed_from_num(0.354167);   // y0
+    let mut y = (lut.two*x_)/lut.three + lut.y0_offset;   // y0
     for _n in 1..iterations
     {
-        y = beta(x_)*(x_-y.mixed_powi(2))+y;
+        y = lut.beta(x_)*(x_-y.mixed_powi(2))+y;
     }
 
     while norm.abs() != 0
@@ -148,14 +334,14 @@ pub fn niirf<T>( x: T, iterations: usize ) -> T
         // Denormalize the solution.
         if 0 < norm
         {
-            y = y*T::mixed_from_num(2);
+            y = y*lut.two;
             norm-=1;
         }
         else if norm < 0
         {
-            y = y/T::mixed_from_num(2);
+            y = y/lut.two;
             norm+=1;
         }
     }
     return y;
-}
\ No newline at end of file
+}
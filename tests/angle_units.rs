@@ -0,0 +1,96 @@
+use mixed_num::*;
+use fixed::{types::extra::{U13, U20, U26, U52}, FixedI16, FixedI32, FixedI64};
+
+fn check_exact<T>( name: &str )
+    where T: MixedReal + MixedNumSigned + MixedOps + MixedZero + MixedOne + MixedAngleUnits + core::fmt::Debug
+{
+    let (zero, one) = (T::mixed_zero(), T::mixed_one());
+    for k in -8i32..=8 {
+        let (sin, cos) = T::mixed_from_num(90*k).mixed_sincosd();
+        let expected = match k.rem_euclid(4) {
+            0 => (zero, one),
+            1 => (one, zero),
+            2 => (zero, -one),
+            _ => (-one, zero),
+        };
+        assert_eq!{ (sin, cos), expected, "{} {}°", name, 90*k };
+        assert_eq!{ T::mixed_from_num(90*k).mixed_sind(), expected.0 };
+        assert_eq!{ T::mixed_from_num(90*k).mixed_cosd(), expected.1 };
+    }
+
+    let d = |x: i32| T::mixed_from_num(x);
+    assert_eq!{ zero.mixed_atan2d(-one), d(180), "{}", name };
+    assert_eq!{ one.mixed_atan2d(zero), d(90) };
+    assert_eq!{ (-one).mixed_atan2d(zero), d(-90) };
+    assert_eq!{ (-one).mixed_atan2d(-one), d(-135) };
+    assert_eq!{ one.mixed_atan2d(one), d(45) };
+}
+
+fn check_accuracy<T>( name: &str, tolerance: f64 )
+    where T: MixedReal + MixedNumSigned + MixedOps + MixedAngleUnits
+{
+    for i in -720..=720 {
+        let degrees = i as f64*0.5f64;
+        let x = degrees.to_radians();
+        let (sin, cos) = T::mixed_from_num(degrees).mixed_sincosd();
+        let (sin, cos): (f64, f64) = (sin.mixed_to_num(), cos.mixed_to_num());
+        assert!{ (sin-x.sin()).abs() < tolerance && (cos-x.cos()).abs() < tolerance, "{} {}°", name, degrees };
+
+        let (y_, x_) = (T::mixed_from_num(0.5f64*x.sin()), T::mixed_from_num(0.5f64*x.cos()));
+        let angle: f64 = y_.mixed_atan2d(x_).mixed_to_num();
+        let reference = (0.5f64*x.sin()).atan2(0.5f64*x.cos()).to_degrees();
+        let error = (angle-reference).abs();
+        assert!{ error < 1e3*tolerance || (360f64-error).abs() < 1e3*tolerance, "{} atan2d {}°: {}", name, degrees, angle };
+    }
+}
+
+#[test]
+fn exact_at_right_angles() {
+    check_exact::<f32>("f32");
+    check_exact::<f64>("f64");
+    check_exact::<FixedI32<U20>>("FixedI32<U20>");
+    check_exact::<FixedI64<U52>>("FixedI64<U52>");
+
+    assert_eq!{ 180f64.mixed_sind(), 0f64 };
+    assert_eq!{ (-180f32).mixed_sind(), 0f32 };
+    // 1e6° = 2777 turns and 280°, exact in the reduction.
+    assert!{ (1e6f64.mixed_cosd()-280f64.to_radians().cos()).abs() < 1e-15 };
+}
+
+#[test]
+fn accuracy() {
+    check_accuracy::<f64>("f64", 1e-15);
+    check_accuracy::<f32>("f32", 1e-6);
+    check_accuracy::<FixedI32<U20>>("FixedI32<U20>", 2e-5);
+}
+
+#[test]
+fn conversions() {
+    let pi = core::f64::consts::PI;
+    assert!{ (pi.mixed_to_degrees()-180f64).abs() < 1e-12 };
+    assert!{ (180f32.mixed_to_radians()-core::f32::consts::PI).abs() < 1e-6 };
+    assert!{ (pi.mixed_to_turns()-0.5f64).abs() < 1e-15 };
+
+    let x = FixedI32::<U20>::from_num(pi);
+    assert!{ (x.mixed_to_degrees().to_num::<f64>()-180f64).abs() < 1e-3 };
+    assert!{ (FixedI32::<U20>::from_num(90).mixed_to_radians().to_num::<f64>()-pi/2f64).abs() < 1e-5 };
+
+    // Narrow fixed types convert to radians, as the degrees are not representable.
+    let x = FixedI16::<U13>::from_num(3.5);
+    assert!{ (x.mixed_to_radians().to_num::<f64>()-3.5f64.to_radians()).abs() < 1e-3 };
+    assert!{ (x.mixed_sind().to_num::<f64>()-3.5f64.to_radians().sin()).abs() < 1e-3 };
+    // 180/π is out of range, while the degrees are not.
+    let x = FixedI16::<U13>::from_num(0.0625);
+    assert!{ (x.mixed_to_degrees().to_num::<f64>()-0.0625f64.to_degrees()).abs() < 2e-4 };
+    let x = FixedI32::<U26>::from_num(0.1);
+    assert!{ (x.mixed_to_degrees().to_num::<f64>()-0.1f64.to_degrees()).abs() < 1e-6 };
+    let (y, x) = (FixedI16::<U13>::from_num(0.01), FixedI16::<U13>::from_num(1));
+    assert!{ (y.mixed_atan2d(x).to_num::<f64>()-0.01f64.atan2(1f64).to_degrees()).abs() < 0.05 };
+
+    assert_eq!{ 3i32.mixed_to_degrees(), 172i32 };
+    assert_eq!{ 180i32.mixed_to_radians(), 3i32 };
+    assert_eq!{ 450i32.mixed_sincosd(), (1i32, 0i32) };
+    assert_eq!{ (-180i16).mixed_cosd(), -1i16 };
+    assert_eq!{ 0i64.mixed_atan2d(-5i64), 180i64 };
+    assert_eq!{ 1i32.mixed_atan2d(2i32), 27i32 };
+}
@@ -0,0 +1,83 @@
+use mixed_num::*;
+use fixed::{types::extra::{U13, U28}, FixedI16, FixedI32};
+
+use core::f64::consts::PI;
+
+#[test]
+fn wraps_at_pi() {
+    assert_eq!{ BinaryAngle::<u32>::from_radians(PI), BinaryAngle::<u32>::HALF_TURN };
+    assert_eq!{ BinaryAngle::<u32>::from_radians(-PI), BinaryAngle::<u32>::HALF_TURN };
+    assert_eq!{ BinaryAngle::<u16>::from_radians(3f64*PI), BinaryAngle::<u16>::HALF_TURN };
+    assert_eq!{ BinaryAngle::<u8>::from_degrees(-90f32), BinaryAngle(0xC0u8) };
+
+    // Half a turn maps to -π, and just below it to π.
+    assert_eq!{ BinaryAngle::<u32>::HALF_TURN.to_radians::<f64>(), -PI };
+    let below = BinaryAngle::<u32>::HALF_TURN - BinaryAngle(1);
+    assert!{ (below.to_radians::<f64>()-PI).abs() < 1e-8 };
+    assert_eq!{ BinaryAngle::<u64>::HALF_TURN.to_degrees::<f64>(), -180f64 };
+
+    // Crossing ±π is exact.
+    let angle = BinaryAngle::<u32>::from_degrees(170f64) + BinaryAngle::<u32>::from_degrees(20f64);
+    assert_eq!{ angle, BinaryAngle::<u32>::from_degrees(-170f64) };
+    assert_eq!{ -BinaryAngle::<u32>::QUARTER_TURN, BinaryAngle::<u32>::QUARTER_TURN*3 };
+
+    // Fixed-point conversions.
+    let angle = BinaryAngle::<u16>::from_radians( FixedI16::<U13>::from_num(-PI) );
+    assert_eq!{ angle, BinaryAngle::<u16>::HALF_TURN };
+    let radians: FixedI32<U28> = BinaryAngle::<u16>::QUARTER_TURN.to_radians();
+    assert!{ (radians.to_num::<f64>()-PI/2f64).abs() < 1e-8 };
+}
+
+#[test]
+fn accumulation_is_exact() {
+    // A phase accumulator, with a step that is exact in both the binary angle and f64.
+    let step = BinaryAngle::<u32>(0x1234_5679);
+    let steps = 5_000_000u64;
+
+    let mut phase = BinaryAngle::<u32>::ZERO;
+    let mut radians = 0f64;
+    let radians_step: f64 = step.to_radians();
+    for _ in 0..steps {
+        phase += step;
+        radians += radians_step;
+    }
+    assert_eq!{ phase, step*(steps as u32) };
+    assert_eq!{ phase.0 as u64, (step.0 as u64*steps) % (1u64<<32) };
+
+    // The radian accumulator drifts, and needs wrapping.
+    let reference = (step.0 as f64*steps as f64) % 4294967296f64/4294967296f64*2f64*PI;
+    let wrapped = radians.rem_euclid(2f64*PI);
+    assert!{ 1e-9 < (wrapped-reference).abs() };
+    assert!{ (phase.sin::<f64>()-reference.sin()).abs() < 2e-8 };
+}
+
+#[test]
+fn trigonometry() {
+    for i in 0..4096u32 {
+        let angle = BinaryAngle::<u32>(i << 20);
+        let x: f64 = angle.to_radians();
+        let (sin, cos) = angle.sincos::<f64>();
+        assert!{ (sin-x.sin()).abs() < 2e-8 && (cos-x.cos()).abs() < 2e-8 };
+
+        let sin: FixedI32<U28> = BinaryAngle::<u8>((i >> 4) as u8).sin();
+        let x: f64 = BinaryAngle::<u8>((i >> 4) as u8).to_radians();
+        assert!{ (sin.to_num::<f64>()-x.sin()).abs() < 2e-8 };
+    }
+}
+
+#[test]
+fn polar() {
+    let z = Polar::new(2f32, BinaryAngle::<u32>::from_degrees(45f32));
+    let c = z.to_cartesian();
+    assert!{ (c.re-2f32.sqrt()).abs() < 1e-6 && (c.im-2f32.sqrt()).abs() < 1e-6 };
+
+    let product = z*z*z*z;
+    assert_eq!{ product.mag, 16f32 };
+    assert_eq!{ product.ang, BinaryAngle::<u32>::HALF_TURN };
+    assert_eq!{ product.to_radians().ang, -core::f32::consts::PI };
+    assert_eq!{ (z*z.conj()).ang, BinaryAngle::<u32>::ZERO };
+
+    let z = Polar::<f64, BinaryAngle<u16>>::from_radians( Polar::new(1f64, -PI/2f64) );
+    assert_eq!{ z.ang, BinaryAngle(0xC000u16) };
+    assert_eq!{ (z*3f64).mag, 3f64 };
+}
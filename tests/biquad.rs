@@ -0,0 +1,196 @@
+use mixed_num::*;
+use mixed_num::dsp::*;
+use fixed::{types::extra::{U13, U26, U28}, FixedI16, FixedI32};
+
+const Q: f64 = 0.9f64;
+const GAIN_DB: f64 = 7.5f64;
+
+/// `(a + jb)/(c + jd)`
+fn div( a: f64, b: f64, c: f64, d: f64 ) -> Cartesian<f64> {
+    let scale = 1f64/(c*c+d*d);
+    return Cartesian::new( (a*c+b*d)*scale, (b*c-a*d)*scale );
+}
+
+/// Magnitude of the analog prototype of each design at `ω`, with the bilinear frequency warping `Ω = tan(ω/2)/tan(ω0/2)`.
+fn analytic_magnitude( design: &str, w: f64, w0: f64 ) -> f64 {
+    let o = (w/2f64).tan()/(w0/2f64).tan();
+    let a = 10f64.powf(GAIN_DB/40f64);
+    let s = a.sqrt();
+    // H(jΩ) = (n0 - n2*Ω^2 + j*n1*Ω)/(d0 - d2*Ω^2 + j*d1*Ω)
+    let (n, d) = match design {
+        "lowpass"    => ([1f64, 0f64, 0f64], [1f64, 1f64/Q, 1f64]),
+        "highpass"   => ([0f64, 0f64, 1f64], [1f64, 1f64/Q, 1f64]),
+        "bandpass"   => ([0f64, 1f64/Q, 0f64], [1f64, 1f64/Q, 1f64]),
+        "notch"      => ([1f64, 0f64, 1f64], [1f64, 1f64/Q, 1f64]),
+        "peaking"    => ([1f64, a/Q, 1f64], [1f64, 1f64/(a*Q), 1f64]),
+        "low_shelf"  => ([a*a, a*s/Q, a], [1f64, s/Q, a]),
+        "high_shelf" => ([a, a*s/Q, a*a], [a, s/Q, 1f64]),
+        _ => panic!(),
+    };
+    return div( n[0]-n[2]*o*o, n[1]*o, d[0]-d[2]*o*o, d[1]*o ).mag();
+}
+
+fn design<T>( name: &str, w0: T ) -> BiquadCoefficients<T>
+    where T: MixedReal + MixedNumSigned + MixedOps + MixedSin + MixedZero + MixedOne + MixedPow
+{
+    let q = T::mixed_from_num(Q);
+    let gain_db = T::mixed_from_num(GAIN_DB);
+    return match name {
+        "lowpass"    => BiquadCoefficients::lowpass(w0, q),
+        "highpass"   => BiquadCoefficients::highpass(w0, q),
+        "bandpass"   => BiquadCoefficients::bandpass(w0, q),
+        "notch"      => BiquadCoefficients::notch(w0, q),
+        "peaking"    => BiquadCoefficients::peaking(w0, q, gain_db),
+        "low_shelf"  => BiquadCoefficients::low_shelf(w0, q, gain_db),
+        "high_shelf" => BiquadCoefficients::high_shelf(w0, q, gain_db),
+        _ => panic!(),
+    };
+}
+
+const DESIGNS: [&str; 7] = ["lowpass", "highpass", "bandpass", "notch", "peaking", "low_shelf", "high_shelf"];
+
+#[test]
+fn designs_match_analytic_response() {
+    for name in DESIGNS {
+        for w0 in [0.05f64, 0.7f64, 2.5f64] {
+            let c64 = design::<f64>(name, w0);
+            let c32 = design::<f32>(name, w0 as f32);
+            let cfx = c64.quantize::<FixedI32<U28>>();
+            for i in 1..64 {
+                let w = core::f64::consts::PI*(i as f64)/64f64;
+                let expected = analytic_magnitude(name, w, w0);
+                let tol = 1e-9f64*(1f64+expected);
+                assert!{ (c64.frequency_response(w).mag()-expected).abs() < tol, "{} {} {}", name, w0, w };
+                // Quantization moves the poles, which matters the most close to DC.
+                assert!{ (c32.frequency_response(w).mag()-expected).abs() < 2e-3*(1f64+expected), "{} {} {}", name, w0, w };
+                assert!{ (cfx.frequency_response(w).mag()-expected).abs() < 2e-5*(1f64+expected), "{} {} {}", name, w0, w };
+            }
+        }
+    }
+}
+
+#[test]
+fn from_f64_normalizes() {
+    let c = BiquadCoefficients::<f64>::from_f64( [1f64, 2f64, 1f64], [2f64, -1f64, 0.5f64] );
+    assert_eq!{ c, BiquadCoefficients{ b0: 0.5f64, b1: 1f64, b2: 0.5f64, a1: -0.5f64, a2: 0.25f64 } };
+}
+
+/// Steady state amplitude of a filtered tone, relative to the input amplitude.
+fn measured_gain<T, A>( filter: &mut Biquad<T, A>, w: f64 ) -> f64
+    where T: MixedReal + MixedOps + MixedZero,
+          A: MixedNum + MixedOps + MixedZero + MixedNumConversion<T>
+{
+    let amplitude = 0.2f64;
+    let mut max = 0f64;
+    for n in 0..3000 {
+        let y: f64 = filter.process_sample( T::mixed_from_num(amplitude*(w*n as f64).cos()) ).mixed_to_num();
+        if 2000 <= n {
+            max = max.max(y.abs());
+        }
+    }
+    return max/amplitude;
+}
+
+#[test]
+fn filters_match_frequency_response() {
+    for form in [BiquadForm::DirectForm1, BiquadForm::TransposedDirectForm2] {
+        for name in ["lowpass", "bandpass", "peaking"] {
+            let coeffs = design::<f64>(name, 0.4f64);
+            for w in [0.1f64, 0.4f64, 1.3f64] {
+                let expected = coeffs.frequency_response(w).mag();
+
+                let mut filter = Biquad::<f64>::new(coeffs, form);
+                assert!{ (measured_gain(&mut filter, w)-expected).abs() < 2e-3 };
+
+                let mut filter = Biquad::<f32>::new(coeffs.quantize(), form);
+                assert!{ (measured_gain(&mut filter, w)-expected).abs() < 2e-3 };
+
+                let mut filter = Biquad::<FixedI32<U28>>::new(coeffs.quantize(), form);
+                assert!{ (measured_gain(&mut filter, w)-expected).abs() < 2e-3 };
+            }
+        }
+    }
+}
+
+/// RMS error of a narrow lowpass in `FixedI16<U13>`, relative to the `f64` filter with the same coefficients.
+fn lowpass_rms_error( error_feedback: ErrorFeedback ) -> f64 {
+    let coeffs = BiquadCoefficients::<f64>::lowpass( 0.02f64, core::f64::consts::FRAC_1_SQRT_2 ).quantize::<FixedI16<U13>>();
+    let mut reference = Biquad::<f64>::new(coeffs.quantize(), BiquadForm::DirectForm1);
+    let mut filter = Biquad::<FixedI16<U13>, FixedI32<U26>>::new(coeffs, BiquadForm::DirectForm1);
+    filter.set_error_feedback(error_feedback);
+    assert_eq!{ filter.error_feedback(), error_feedback };
+
+    let mut sum = 0f64;
+    for n in 0..4000 {
+        let x = FixedI16::<U13>::from_num( 0.4f64*(0.003f64*n as f64).sin() + 0.1f64*(1.1f64*n as f64).sin() );
+        let y: f64 = filter.process_sample(x).to_num();
+        let e = y - reference.process_sample(x.to_num());
+        if 1000 <= n {
+            sum += e*e;
+        }
+    }
+    return (sum/3000f64).sqrt();
+}
+
+#[test]
+fn error_feedback_reduces_quantization_noise() {
+    let none   = lowpass_rms_error(ErrorFeedback::None);
+    let first  = lowpass_rms_error(ErrorFeedback::FirstOrder);
+    let second = lowpass_rms_error(ErrorFeedback::SecondOrder);
+    assert!{ first < none/4f64, "{} {}", none, first };
+    assert!{ second < none/4f64, "{} {}", none, second };
+}
+
+#[test]
+fn cascade_matches_sections() {
+    let sections = [ design::<f64>("lowpass", 0.5f64), design::<f64>("notch", 1.2f64), design::<f64>("high_shelf", 2f64) ];
+    let mut cascade = BiquadCascade::<f64, f64, 3>::new(sections, BiquadForm::TransposedDirectForm2);
+    let mut single = sections.map( |c| Biquad::<f64>::new(c, BiquadForm::DirectForm1) );
+
+    let x: [f64; 100] = core::array::from_fn( |n| (0.37f64*n as f64).sin() );
+    let mut y = [0f64; 100];
+    cascade.process(&x, &mut y);
+    for (x_, y_) in x.iter().zip(y.iter()) {
+        let mut expected = *x_;
+        for s in single.iter_mut() {
+            expected = s.process_sample(expected);
+        }
+        assert!{ (y_-expected).abs() < 1e-12 };
+    }
+
+    let w = 0.9f64;
+    let h = cascade.frequency_response(w);
+    let mut expected = Cartesian::new(1f64, 0f64);
+    for s in sections.iter() {
+        expected = complex::mul_cartesian(expected, s.frequency_response(w));
+    }
+    assert!{ (h.re-expected.re).abs() < 1e-12 && (h.im-expected.im).abs() < 1e-12 };
+
+    cascade.reset();
+    let mut z = x;
+    cascade.process_in_place(&mut z);
+    assert_eq!{ y, z };
+}
+
+#[test]
+fn block_lengths_differ() {
+    // The shorter of the input and output is processed.
+    let sections = [ design::<f64>("lowpass", 0.5f64), design::<f64>("peaking", 1.2f64) ];
+    let mut cascade = BiquadCascade::<f64, f64, 2>::new(sections, BiquadForm::DirectForm1);
+    let mut reference = cascade;
+    let mut single = Biquad::<f64>::new(sections[0], BiquadForm::TransposedDirectForm2);
+    let mut single_reference = single;
+
+    let x: [f64; 20] = core::array::from_fn( |n| (0.37f64*n as f64).sin() );
+    let mut y = [0f64; 20];
+    let mut z = [0f64; 20];
+    cascade.process(&x[..12], &mut y[..8]);
+    cascade.process(&x[8..], &mut y[8..]);
+    cascade.process(&x, &mut []);
+    single.process(&x[..5], &mut z);
+    single.process(&x[5..], &mut z[5..]);
+    for n in 0..20 {
+        assert_eq!{ y[n], reference.process_sample(x[n]) };
+        assert_eq!{ z[n], single_reference.process_sample(x[n]) };
+    }
+}
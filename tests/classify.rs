@@ -0,0 +1,57 @@
+use mixed_num::*;
+use fixed::{types::extra::{U12, U28}, FixedI32, FixedU16};
+
+#[test]
+fn floats() {
+    assert_eq!{ f64::NAN.mixed_classify(), MixedCategory::Nan };
+    assert_eq!{ f32::NEG_INFINITY.mixed_classify(), MixedCategory::Infinite };
+    assert_eq!{ (-0f64).mixed_classify(), MixedCategory::Zero };
+    assert_eq!{ f64::from_bits(1).mixed_classify(), MixedCategory::Subnormal };
+    assert_eq!{ f32::MAX.mixed_classify(), MixedCategory::Normal };
+
+    assert!{ f64::NAN.mixed_is_nan() && !f64::NAN.mixed_is_finite() && !f64::NAN.mixed_is_saturated() };
+    assert!{ f32::INFINITY.mixed_is_infinite() && f32::INFINITY.mixed_is_saturated() };
+    assert!{ f32::MAX.mixed_is_finite() && !f32::MAX.mixed_is_saturated() };
+    // Overflow saturates to infinity.
+    assert!{ (f32::MAX*2f32).mixed_is_saturated() };
+}
+
+#[test]
+fn fixed() {
+    type T = FixedI32<U28>;
+    assert_eq!{ T::MAX.mixed_classify(), MixedCategory::Saturated };
+    assert_eq!{ T::MIN.mixed_classify(), MixedCategory::Saturated };
+    assert_eq!{ T::ZERO.mixed_classify(), MixedCategory::Zero };
+    assert_eq!{ T::DELTA.mixed_classify(), MixedCategory::Normal };
+    assert!{ !T::MAX.mixed_is_nan() && T::MAX.mixed_is_finite() && !T::MAX.mixed_is_infinite() };
+    assert!{ T::from_num(7).saturating_mul(T::from_num(2)).mixed_is_saturated() };
+    assert!{ !(T::MAX-T::DELTA).mixed_is_saturated() };
+
+    // Unsigned zero is zero, not saturated.
+    type U = FixedU16<U12>;
+    assert_eq!{ U::ZERO.mixed_classify(), MixedCategory::Zero };
+    assert!{ !U::ZERO.mixed_is_saturated() && U::MAX.mixed_is_saturated() };
+}
+
+#[test]
+fn integers() {
+    assert_eq!{ i16::MIN.mixed_classify(), MixedCategory::Saturated };
+    assert_eq!{ u8::MAX.mixed_classify(), MixedCategory::Saturated };
+    assert_eq!{ 0u32.mixed_classify(), MixedCategory::Zero };
+    assert_eq!{ (-5i64).mixed_classify(), MixedCategory::Normal };
+    assert!{ 100i8.saturating_add(100).mixed_is_saturated() && i32::MAX.mixed_is_finite() && !0i32.mixed_is_nan() };
+}
+
+#[test]
+fn cartesian() {
+    assert_eq!{ Cartesian::new(f64::INFINITY, f64::NAN).mixed_classify(), MixedCategory::Nan };
+    assert_eq!{ Cartesian::new(1f32, f32::NEG_INFINITY).mixed_classify(), MixedCategory::Infinite };
+    assert_eq!{ Cartesian::new(0f64, 0f64).mixed_classify(), MixedCategory::Zero };
+    assert_eq!{ Cartesian::new(0f64, 2f64).mixed_classify(), MixedCategory::Normal };
+    assert_eq!{ Cartesian::new(0f64, f64::from_bits(3)).mixed_classify(), MixedCategory::Subnormal };
+    assert_eq!{ Cartesian::new(FixedI32::<U28>::ZERO, FixedI32::<U28>::MIN).mixed_classify(), MixedCategory::Saturated };
+
+    let z = Cartesian::new(1f64, f64::INFINITY);
+    assert!{ !z.mixed_is_nan() && z.mixed_is_infinite() && !z.mixed_is_finite() && z.mixed_is_saturated() };
+    assert!{ Cartesian::new(3i32, -4i32).mixed_is_finite() };
+}
@@ -0,0 +1,69 @@
+use mixed_num::*;
+use fixed::{types::extra::{U0, U5, U13, U28, U61}, FixedI8, FixedI16, FixedI32, FixedI64, FixedU8, FixedU32};
+
+use core::f64::consts;
+
+/// The constants of `MixedConsts`, and their f64 values.
+fn constants<T>() -> [(T, f64); 9]
+    where T: MixedConsts
+{
+    return [
+        (T::mixed_pi(),             consts::PI),
+        (T::mixed_e(),              consts::E),
+        (T::mixed_ln2(),            consts::LN_2),
+        (T::mixed_ln10(),           consts::LN_10),
+        (T::mixed_sqrt2(),          consts::SQRT_2),
+        (T::mixed_frac_1_sqrt2(),   consts::FRAC_1_SQRT_2),
+        (T::mixed_frac_pi_2(),      consts::FRAC_PI_2),
+        (T::mixed_frac_1_pi(),      consts::FRAC_1_PI),
+        (T::mixed_one(),            1f64),
+    ];
+}
+
+/// Check that the constants are rounded to nearest, within half an lsb.
+fn check_fixed<T>( name: &str )
+    where T: MixedConsts + MixedOps
+{
+    let lsb: f64 = T::mixed_epsilon().mixed_to_num();
+    for (i, (constant, reference)) in constants::<T>().iter().enumerate() {
+        let value: f64 = constant.mixed_to_num();
+        // The f64 reference is accurate to 2^-53.
+        assert!{ (value-reference).abs() <= 0.5f64*lsb + 4e-16, "{} constant {}: {} != {}", name, i, value, reference };
+    }
+}
+
+#[test]
+fn fixed_constants_are_rounded_to_nearest() {
+    check_fixed::<FixedI8<U0>>("FixedI8<U0>");
+    check_fixed::<FixedI8<U5>>("FixedI8<U5>");
+    check_fixed::<FixedU8<U5>>("FixedU8<U5>");
+    check_fixed::<FixedI16<U13>>("FixedI16<U13>");
+    check_fixed::<FixedI32<U28>>("FixedI32<U28>");
+    check_fixed::<FixedU32<U28>>("FixedU32<U28>");
+    check_fixed::<FixedI64<U61>>("FixedI64<U61>");
+
+    // Bit accurate beyond f64, e = 2.B7E151628AED2A6A...
+    assert_eq!{ FixedI64::<U61>::mixed_e().to_bits(), 0x56FC_2A2C_515D_A54D };
+    assert_eq!{ FixedI64::<U61>::mixed_epsilon().to_bits(), 1 };
+    assert_eq!{ FixedI8::<U0>::mixed_ln2(), FixedI8::<U0>::ONE };
+}
+
+#[test]
+fn float_constants() {
+    for (constant, reference) in constants::<f64>() {
+        assert_eq!{ constant, reference };
+    }
+    for (constant, reference) in constants::<f32>() {
+        assert_eq!{ constant, reference as f32 };
+    }
+    assert_eq!{ f32::mixed_epsilon(), f32::EPSILON };
+    assert_eq!{ f64::mixed_epsilon(), f64::EPSILON };
+}
+
+#[test]
+fn imaginary_unit() {
+    let i = Cartesian::<FixedI32<U28>>::i();
+    assert_eq!{ i.re, FixedI32::<U28>::ZERO };
+    assert_eq!{ i.im, FixedI32::<U28>::ONE };
+    assert_eq!{ Cartesian::<f64>::i()*Cartesian::<f64>::i(), Cartesian::new(-1f64, 0f64) };
+}
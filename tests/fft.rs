@@ -0,0 +1,78 @@
+use mixed_num::*;
+use mixed_num::dsp::*;
+use fixed::{types::extra::U12, types::extra::U28, FixedI16, FixedI32};
+
+/// Naive DFT in f64.
+fn dft( x: &[Cartesian<f64>], inverse: bool ) -> Vec<Cartesian<f64>> {
+    let n = x.len();
+    let sign = if inverse { 1f64 } else { -1f64 };
+    return (0..n).map(|k| {
+        let mut acc = Cartesian::new(0f64, 0f64);
+        for (j, x_) in x.iter().enumerate() {
+            let phi = sign*2f64*core::f64::consts::PI*((k*j) % n) as f64/(n as f64);
+            acc.re += x_.re*phi.cos() - x_.im*phi.sin();
+            acc.im += x_.re*phi.sin() + x_.im*phi.cos();
+        }
+        acc
+    }).collect();
+}
+
+/// Deterministic test signal with magnitude below one.
+fn signal( n: usize ) -> Vec<Cartesian<f64>> {
+    return (0..n).map(|i| {
+        let i = i as f64;
+        Cartesian::new( 0.5f64*(0.7f64*i).sin() + 0.2f64*(1.9f64*i).cos(), 0.3f64*(0.31f64*i*i).cos() )
+    }).collect();
+}
+
+/// Compare the transform of type `T` with the naive DFT, scaled by `2^-exponent` and `gain`.
+fn test_fft<T, const N: usize>( scaling: FftScaling, gain: f64, tol: f64 )
+    where T: MixedReal + MixedNumSigned + MixedSin + MixedOps + MixedZero + MixedAbs
+{
+    let fft = Fft::<T, N>::new().unwrap();
+    let x = signal(N);
+
+    for inverse in [false, true] {
+        let mut data: Vec<Cartesian<T>> = x.iter().map(|x_| Cartesian::new(T::mixed_from_num(x_.re), T::mixed_from_num(x_.im))).collect();
+        let exponent = if inverse { fft.ifft(&mut data, scaling) } else { fft.fft(&mut data, scaling) };
+        let expected = dft(&x, inverse);
+
+        let scale = gain*libm::exp2(-(exponent as f64));
+        for (y, e) in data.iter().zip(expected.iter()) {
+            let re: f64 = y.re.mixed_to_num();
+            let im: f64 = y.im.mixed_to_num();
+            assert!{ (re-e.re*scale).abs() < tol && (im-e.im*scale).abs() < tol, "N={} {}+{}i != {}+{}i", N, re, im, e.re*scale, e.im*scale };
+        }
+    }
+}
+
+#[test]
+fn fft_float_matches_dft() {
+    test_fft::<f32, 1>(FftScaling::None, 1f64, 1e-5);
+    test_fft::<f32, 2>(FftScaling::None, 1f64, 1e-5);
+    test_fft::<f32, 16>(FftScaling::None, 1f64, 1e-5);
+    test_fft::<f32, 1024>(FftScaling::None, 1f64, 1e-3);
+    test_fft::<f64, 12>(FftScaling::None, 1f64, 1e-12);
+    test_fft::<f64, 45>(FftScaling::None, 1f64, 1e-12);
+    test_fft::<f64, 60>(FftScaling::None, 1f64, 1e-12);
+    test_fft::<f64, 750>(FftScaling::None, 1f64, 1e-10);
+}
+
+#[test]
+fn fft_fixed_matches_dft() {
+    test_fft::<FixedI32<U28>, 64>(FftScaling::PerStage, 1f64/64f64, 1e-7);
+    test_fft::<FixedI32<U28>, 30>(FftScaling::PerStage, 1f64/30f64, 1e-7);
+    test_fft::<FixedI32<U28>, 8>(FftScaling::None, 1f64, 1e-7);
+
+    // The unscaled output would overflow the ±8 range of the type.
+    test_fft::<FixedI16<U12>, 64>(FftScaling::BlockFloatingPoint, 1f64, 0.02);
+    test_fft::<FixedI16<U12>, 60>(FftScaling::BlockFloatingPoint, 1f64, 0.02);
+}
+
+#[test]
+fn fft_unsupported_sizes() {
+    assert!{ Fft::<f32, 0>::new().is_none() };
+    assert!{ Fft::<f32, 7>::new().is_none() };
+    assert!{ Fft::<f32, 22>::new().is_none() };
+    assert!{ Fft::<f32, 360>::new().is_some() };
+}
@@ -0,0 +1,160 @@
+use mixed_num::*;
+use mixed_num::dsp::*;
+use mixed_num::dsp::design::*;
+use fixed::{types::extra::{U20, U24}, FixedI32};
+
+use core::f64::consts::PI;
+
+/// Magnitude of the FIR frequency response at `w`.
+fn fir_magnitude<T: MixedReal>( h: &[T], w: f64 ) -> f64 {
+    let mut re = 0f64;
+    let mut im = 0f64;
+    for (n, h_) in h.iter().enumerate() {
+        let h_: f64 = h_.mixed_to_num();
+        re += h_*(w*n as f64).cos();
+        im -= h_*(w*n as f64).sin();
+    }
+    return (re*re+im*im).sqrt();
+}
+
+/// Largest deviation from one in the passbands, and largest magnitude in the stopbands, on a dense grid.
+fn fir_ripple<T: MixedReal>( h: &[T], passbands: &[(f64, f64)], stopbands: &[(f64, f64)] ) -> (f64, f64) {
+    let mut ripple = 0f64;
+    let mut stop = 0f64;
+    for i in 0..=2000 {
+        let w = PI*(i as f64)/2000f64;
+        let m = fir_magnitude(h, w);
+        if passbands.iter().any( |(a, b)| *a <= w && w <= *b ) {
+            ripple = ripple.max((m-1f64).abs());
+        }
+        if stopbands.iter().any( |(a, b)| *a <= w && w <= *b ) {
+            stop = stop.max(m);
+        }
+    }
+    return (ripple, stop);
+}
+
+fn db( x: f64 ) -> f64 {
+    return 20f64*x.log10();
+}
+
+#[test]
+fn kaiser_parameters() {
+    assert!{ (kaiser_beta(60f64)-5.65326f64).abs() < 1e-12 };
+    assert!{ (kaiser_beta(30f64)-2.11662f64).abs() < 1e-4 };
+    assert_eq!{ kaiser_beta(15f64), 0f64 };
+    assert_eq!{ kaiser_length(60f64, 0.1f64*PI), 74 };
+}
+
+#[test]
+fn fir_lowpass_meets_specification() {
+    let beta = kaiser_beta(60f64);
+
+    let h: [f64; 75] = fir_lowpass(0.3f64*PI, beta);
+    let (ripple, stop) = fir_ripple(&h, &[(0f64, 0.25f64*PI)], &[(0.35f64*PI, PI)]);
+    assert!{ ripple < 1.5e-3 && db(stop) < -59f64, "{} {}", ripple, db(stop) };
+    // Linear phase.
+    for n in 0..75 {
+        assert_eq!{ h[n], h[74-n] };
+    }
+
+    let h: [f32; 75] = fir_lowpass(0.3f32*f32::mixed_pi(), beta as f32);
+    let (ripple, stop) = fir_ripple(&h, &[(0f64, 0.25f64*PI)], &[(0.35f64*PI, PI)]);
+    assert!{ ripple < 1.5e-3 && db(stop) < -59f64, "{} {}", ripple, db(stop) };
+
+    // The fixed-point square root limits the accuracy of the window.
+    let h: [FixedI32<U20>; 75] = fir_lowpass(FixedI32::<U20>::from_num(0.3f64*PI), FixedI32::<U20>::from_num(beta));
+    let (ripple, stop) = fir_ripple(&h, &[(0f64, 0.25f64*PI)], &[(0.35f64*PI, PI)]);
+    assert!{ ripple < 1e-2 && db(stop) < -45f64, "{} {}", ripple, db(stop) };
+}
+
+#[test]
+fn fir_highpass_and_bandpass_meet_specification() {
+    let beta = kaiser_beta(50f64);
+
+    let h: [f64; 61] = fir_highpass(0.6f64*PI, beta);
+    let (ripple, stop) = fir_ripple(&h, &[(0.66f64*PI, PI)], &[(0f64, 0.54f64*PI)]);
+    assert!{ ripple < 5e-3 && db(stop) < -49f64, "{} {}", ripple, db(stop) };
+    assert!{ (fir_magnitude(&h, PI)-1f64).abs() < 1e-12 };
+
+    let h: [f64; 61] = fir_bandpass(0.3f64*PI, 0.6f64*PI, beta);
+    // Both band edges contribute to the ripple.
+    let (ripple, stop) = fir_ripple(&h, &[(0.36f64*PI, 0.54f64*PI)], &[(0f64, 0.24f64*PI), (0.66f64*PI, PI)]);
+    assert!{ ripple < 7e-3 && db(stop) < -49f64, "{} {}", ripple, db(stop) };
+    assert!{ (fir_magnitude(&h, 0.45f64*PI)-1f64).abs() < 1e-12 };
+}
+
+/// Chebyshev polynomial `T_n(x)`.
+fn chebyshev( n: usize, x: f64 ) -> f64 {
+    if x.abs() <= 1f64 {
+        return (n as f64*x.acos()).cos();
+    }
+    return (n as f64*x.abs().acosh()).cosh()*x.signum().powi(n as i32);
+}
+
+/// Analytic magnitude of the designs, with the bilinear frequency warping.
+fn analytic_magnitude( order: usize, cutoff: f64, ripple_db: Option<f64>, highpass: bool, w: f64 ) -> f64 {
+    let mut x = (w/2f64).tan()/(cutoff/2f64).tan();
+    if highpass {
+        x = 1f64/x;
+    }
+    let h2 = match ripple_db {
+        None         => 1f64/(1f64+x.powi(2*order as i32)),
+        Some(ripple) => 1f64/(1f64+(10f64.powf(ripple/10f64)-1f64)*chebyshev(order, x).powi(2)),
+    };
+    return h2.sqrt();
+}
+
+fn check_iir<const S: usize>( sections: [BiquadCoefficients<f64>; S], order: usize, cutoff: f64, ripple_db: Option<f64>, highpass: bool, tol: f64 ) {
+    let filter = BiquadCascade::<f64, f64, S>::new(sections, BiquadForm::DirectForm1);
+    for i in 1..200 {
+        let w = PI*(i as f64)/200f64;
+        let expected = analytic_magnitude(order, cutoff, ripple_db, highpass, w);
+        let m = filter.frequency_response(w).mag();
+        assert!{ (m-expected).abs() < tol, "order {} at {}: {} != {}", order, w, m, expected };
+    }
+}
+
+#[test]
+fn butterworth_matches_analytic_response() {
+    check_iir::<1>(butterworth_lowpass(1, 0.4f64*PI), 1, 0.4f64*PI, None, false, 1e-12);
+    check_iir::<2>(butterworth_lowpass(4, 0.1f64*PI), 4, 0.1f64*PI, None, false, 1e-12);
+    check_iir::<3>(butterworth_lowpass(5, 0.7f64*PI), 5, 0.7f64*PI, None, false, 1e-12);
+    check_iir::<2>(butterworth_highpass(3, 0.3f64*PI), 3, 0.3f64*PI, None, true, 1e-12);
+    check_iir::<3>(butterworth_highpass(6, 0.5f64*PI), 6, 0.5f64*PI, None, true, 1e-12);
+
+    // Designs in f32 and fixed-point, compared in f64.
+    let sections: [BiquadCoefficients<f32>; 2] = butterworth_lowpass(4, 0.2f32*f32::mixed_pi());
+    check_iir::<2>(sections.map( |s| s.quantize() ), 4, 0.2f64*PI, None, false, 1e-4);
+    let sections: [BiquadCoefficients<FixedI32<U24>>; 3] = butterworth_highpass(5, FixedI32::<U24>::from_num(0.4f64*PI));
+    check_iir::<3>(sections.map( |s| s.quantize() ), 5, 0.4f64*PI, None, true, 1e-3);
+}
+
+#[test]
+fn chebyshev1_matches_analytic_response() {
+    check_iir::<2>(chebyshev1_lowpass(4, 0.25f64*PI, 1f64), 4, 0.25f64*PI, Some(1f64), false, 1e-9);
+    check_iir::<3>(chebyshev1_lowpass(5, 0.5f64*PI, 0.5f64), 5, 0.5f64*PI, Some(0.5f64), false, 1e-9);
+    check_iir::<2>(chebyshev1_highpass(3, 0.6f64*PI, 2f64), 3, 0.6f64*PI, Some(2f64), true, 1e-9);
+
+    let sections: [BiquadCoefficients<f32>; 3] = chebyshev1_lowpass(6, 0.3f32*f32::mixed_pi(), 1f32);
+    check_iir::<3>(sections.map( |s| s.quantize() ), 6, 0.3f64*PI, Some(1f64), false, 1e-3);
+}
+
+#[test]
+fn chebyshev1_passband_ripple() {
+    let ripple_db = 0.5f64;
+    let sections: [BiquadCoefficients<f64>; 4] = chebyshev1_lowpass(8, 0.4f64*PI, ripple_db);
+    let filter = BiquadCascade::<f64, f64, 4>::new(sections, BiquadForm::TransposedDirectForm2);
+
+    let mut max = f64::MIN;
+    let mut min = f64::MAX;
+    for i in 0..=1000 {
+        let m = db(filter.frequency_response(0.4f64*PI*(i as f64)/1000f64).mag());
+        max = max.max(m);
+        min = min.min(m);
+    }
+    assert!{ max < 1e-9 && -ripple_db-1e-9 < min && min < -ripple_db+1e-3, "{} {}", max, min };
+
+    // Stopband attenuation an octave above the cutoff.
+    assert!{ db(filter.frequency_response(0.8f64*PI).mag()) < -80f64 };
+}
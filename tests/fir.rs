@@ -0,0 +1,194 @@
+use mixed_num::*;
+use mixed_num::dsp::*;
+use fixed::{types::extra::{U15, U20, U40}, FixedI16, FixedI32, FixedI64};
+
+/// Test signal in the ±0.5 range.
+fn signal( n: usize ) -> f64 {
+    return 0.3f64*(0.37f64*n as f64).sin() + 0.2f64*(1.9f64*n as f64+0.4f64).cos();
+}
+
+/// Lowpass-like coefficients in the ±0.5 range.
+fn coefficients( n: usize ) -> [f64; 11] {
+    let mut coeffs = [0f64; 11];
+    for (k, c) in coeffs.iter_mut().enumerate() {
+        *c = 0.45f64*(0.3f64*(k as f64-5f64)+0.01f64*n as f64).cos()/(1f64+(k as f64-5f64).abs());
+    }
+    return coeffs;
+}
+
+/// Direct convolution `y[n] = Σ_k h[k]*x[n-k]`, with zero initial state.
+fn convolve( x: &[f64], h: &[f64], y: &mut [f64] ) {
+    for (n, y_) in y.iter_mut().enumerate() {
+        *y_ = 0f64;
+        for (k, h_) in h.iter().enumerate() {
+            if k <= n {
+                *y_ += h_*x[n-k];
+            }
+        }
+    }
+}
+
+/// `to_s`, `to_c` and `to_f64` convert the test data, as not all sample types have `MixedNumConversion<f64>`.
+fn test_fir<S, C, A>( to_s: fn(f64) -> S, to_c: fn(f64) -> C, to_f64: fn(S) -> f64, tol: f64 )
+    where S: MixedMac<C, A>,
+          C: Copy,
+          A: Default
+{
+    let x_f64: [f64; 50] = core::array::from_fn(signal);
+    let h_f64 = coefficients(0);
+    let mut expected = [0f64; 50];
+    convolve(&x_f64, &h_f64, &mut expected);
+
+    let x: [S; 50] = x_f64.map(to_s);
+    let h: [C; 11] = h_f64.map(to_c);
+    let mut delay_line = [S::default(); 11];
+    let mut fir = Fir::<S, C, A>::new(&h, &mut delay_line);
+
+    // Uneven blocks, to cross the wrap of the delay line at different positions.
+    let mut y = [S::default(); 50];
+    fir.process(&x[..7], &mut y[..7]);
+    fir.process(&x[7..30], &mut y[7..30]);
+    for n in 30..50 {
+        y[n] = fir.process_sample(x[n]);
+    }
+    for (y_, e) in y.iter().zip(expected.iter()) {
+        let y_ = to_f64(*y_);
+        assert!{ (y_-e).abs() < tol, "{} != {}", y_, e };
+    }
+
+    fir.reset();
+    let mut z = x;
+    fir.process_in_place(&mut z);
+    for (z_, e) in z.iter().zip(expected.iter()) {
+        let z_ = to_f64(*z_);
+        assert!{ (z_-e).abs() < tol };
+    }
+}
+
+#[test]
+fn fir_matches_convolution() {
+    test_fir::<f32, f32, f32>( |x| x as f32, |x| x as f32, |y| y as f64, 1e-6 );
+    test_fir::<f64, f64, f64>( |x| x, |x| x, |y| y, 1e-12 );
+    // Rounding of the input and coefficients, and truncation of the output.
+    type Q15 = FixedI16<U15>;
+    test_fir::<Q15, Q15, FixedI64<U40>>( Q15::from_num, Q15::from_num, |y| y.to_num::<f64>(), 4f64/32768f64 );
+}
+
+#[test]
+fn fir_complex_samples_real_coefficients() {
+    type S = FixedI32<U20>;
+    type A = FixedI64<U40>;
+
+    let x_re: [f64; 40] = core::array::from_fn(signal);
+    let x_im: [f64; 40] = core::array::from_fn( |n| signal(n+17) );
+    let h_f64 = coefficients(3);
+    let mut expected_re = [0f64; 40];
+    let mut expected_im = [0f64; 40];
+    convolve(&x_re, &h_f64, &mut expected_re);
+    convolve(&x_im, &h_f64, &mut expected_im);
+
+    let x: [Cartesian<S>; 40] = core::array::from_fn( |n| Cartesian::new(S::from_num(x_re[n]), S::from_num(x_im[n])) );
+    let h: [S; 11] = h_f64.map(S::from_num);
+    let mut delay_line = [Cartesian::<S>::default(); 11];
+    let mut fir = Fir::<Cartesian<S>, S, Cartesian<A>>::new(&h, &mut delay_line);
+
+    let mut y = [Cartesian::<S>::default(); 40];
+    fir.process(&x, &mut y);
+    for n in 0..40 {
+        let re: f64 = y[n].re.mixed_to_num();
+        let im: f64 = y[n].im.mixed_to_num();
+        assert!{ (re-expected_re[n]).abs() < 1e-5 && (im-expected_im[n]).abs() < 1e-5 };
+    }
+}
+
+#[test]
+fn decimating_fir_matches_convolution() {
+    let x: [f64; 50] = core::array::from_fn(signal);
+    let h = coefficients(1);
+    let mut expected = [0f64; 50];
+    convolve(&x, &h, &mut expected);
+
+    for factor in [1, 2, 3, 7] {
+        let mut delay_line = [0f64; 11];
+        let mut fir = DecimatingFir::<f64, f64, f64>::new(&h, &mut delay_line, factor);
+
+        // Blocks that are not multiples of the factor.
+        let mut y = [0f64; 50];
+        let n = fir.process(&x[..13], &mut y);
+        let n = n + fir.process(&x[13..], &mut y[n..]);
+        assert_eq!{ n, 50usize.div_ceil(factor) };
+        for (i, y_) in y[..n].iter().enumerate() {
+            assert!{ (y_-expected[i*factor]).abs() < 1e-12 };
+        }
+    }
+}
+
+#[test]
+fn interpolating_fir_matches_convolution() {
+    let x: [f64; 20] = core::array::from_fn(signal);
+    let h = coefficients(2);
+
+    for factor in [1, 2, 3, 4] {
+        // Zero-stuffed input at the output rate.
+        let mut upsampled = [0f64; 80];
+        for (n, x_) in x.iter().enumerate() {
+            upsampled[n*factor] = *x_;
+        }
+        let mut expected = [0f64; 80];
+        convolve(&upsampled[..20*factor], &h, &mut expected[..20*factor]);
+
+        let mut delay_line = [0f64; 11];
+        let mut fir = InterpolatingFir::<f64, f64, f64>::new(&h, &mut delay_line[..11usize.div_ceil(factor)], factor);
+        let mut y = [0f64; 80];
+        fir.process(&x[..5], &mut y[..5*factor]);
+        fir.process(&x[5..], &mut y[5*factor..20*factor]);
+        for (y_, e) in y[..20*factor].iter().zip(expected.iter()) {
+            assert!{ (y_-e).abs() < 1e-12 };
+        }
+    }
+}
+
+#[test]
+fn block_lengths_differ() {
+    let x: [f64; 20] = core::array::from_fn(signal);
+    let h = coefficients(4);
+    let mut expected = [0f64; 20];
+    convolve(&x, &h, &mut expected);
+
+    // The shorter of the input and output is processed.
+    let mut delay_line = [0f64; 11];
+    let mut fir = Fir::<f64, f64, f64>::new(&h, &mut delay_line);
+    let mut y = [0f64; 20];
+    fir.process(&x[..12], &mut y[..8]);
+    fir.process(&x[8..20], &mut y[8..]);
+    fir.process(&x[..3], &mut []);
+    for (y_, e) in y.iter().zip(expected.iter()) {
+        assert!{ (y_-e).abs() < 1e-12 };
+    }
+
+    // Decimation stops when the output is full, before the next kept input sample.
+    let mut delay_line = [0f64; 11];
+    let mut fir = DecimatingFir::<f64, f64, f64>::new(&h, &mut delay_line, 3);
+    let mut y = [0f64; 7];
+    assert_eq!{ fir.process(&x[..20], &mut y[..2]), 2 };
+    assert_eq!{ fir.process(&x[6..20], &mut y[2..]), 5 };
+    for (i, y_) in y.iter().enumerate() {
+        assert!{ (y_-expected[3*i]).abs() < 1e-12 };
+    }
+
+    // Interpolation processes the input samples with room for all output phases.
+    let mut delay_line = [0f64; 6];
+    let mut fir = InterpolatingFir::<f64, f64, f64>::new(&h, &mut delay_line, 2);
+    let mut y = [0f64; 9];
+    fir.process(&x[..10], &mut y);
+    assert_eq!{ y[8], 0f64 };
+    let mut upsampled = [0f64; 8];
+    for n in 0..4 {
+        upsampled[2*n] = x[n];
+    }
+    let mut expected = [0f64; 8];
+    convolve(&upsampled, &h, &mut expected);
+    for (y_, e) in y[..8].iter().zip(expected.iter()) {
+        assert!{ (y_-e).abs() < 1e-12 };
+    }
+}
@@ -0,0 +1,111 @@
+use mixed_num::*;
+use mixed_num::dsp::*;
+use fixed::{types::extra::U24, FixedI32};
+
+const FS: f64 = 8000f64;
+const DTMF_ROWS: [f64; 4] = [697f64, 770f64, 852f64, 941f64];
+const DTMF_COLUMNS: [f64; 4] = [1209f64, 1336f64, 1477f64, 1633f64];
+
+/// Sum of two tones of amplitude `amplitude`.
+fn dtmf<T: MixedReal>( f1: f64, f2: f64, amplitude: f64, output: &mut [T] ) {
+    for (n, y) in output.iter_mut().enumerate() {
+        let t = n as f64/FS;
+        *y = T::mixed_from_num( amplitude*((2f64*core::f64::consts::PI*f1*t).sin() + (2f64*core::f64::consts::PI*f2*t).sin()) );
+    }
+}
+
+/// Index of the strongest of the `frequencies` in `input`.
+fn detect<T>( input: &[T], frequencies: &[f64] ) -> usize
+    where T: MixedReal + MixedNumSigned + MixedOps + MixedZero + MixedSin
+{
+    let mut max = T::mixed_zero();
+    let mut index = 0;
+    for (i, f) in frequencies.iter().enumerate() {
+        let mut goertzel = Goertzel::new( T::mixed_from_num(2f64*core::f64::consts::PI*f/FS) );
+        goertzel.process(input);
+        if max < goertzel.power() {
+            max = goertzel.power();
+            index = i;
+        }
+    }
+    return index;
+}
+
+fn test_dtmf_detection<T>( amplitude: f64 )
+    where T: MixedReal + MixedNumSigned + MixedOps + MixedZero + MixedSin
+{
+    let mut x = [T::mixed_zero(); 205];
+    for row in 0..4 {
+        for column in 0..4 {
+            dtmf(DTMF_ROWS[row], DTMF_COLUMNS[column], amplitude, &mut x);
+            assert_eq!{ detect(&x, &DTMF_ROWS), row };
+            assert_eq!{ detect(&x, &DTMF_COLUMNS), column };
+        }
+    }
+}
+
+#[test]
+fn goertzel_dtmf_detection() {
+    test_dtmf_detection::<f32>(0.5f64);
+    // The bin power of a tone is (A*N/2)^2, which must fit the ±128 range.
+    test_dtmf_detection::<FixedI32<U24>>(0.05f64);
+}
+
+#[test]
+fn goertzel_matches_dft() {
+    let n = 40;
+    let mut x = [0f32; 40];
+    dtmf(697f64, 1633f64, 0.3f64, &mut x);
+
+    for k in 0..n {
+        let mut expected = Cartesian::new(0f64, 0f64);
+        for (j, x_) in x.iter().enumerate() {
+            let phi = -2f64*core::f64::consts::PI*((k*j) % n) as f64/(n as f64);
+            expected.re += *x_ as f64*phi.cos();
+            expected.im += *x_ as f64*phi.sin();
+        }
+
+        let mut goertzel = Goertzel::<f32>::from_bin(k, n);
+        goertzel.process(&x);
+        let bin = goertzel.bin();
+        assert!{ (bin.re as f64-expected.re).abs() < 1e-4 && (bin.im as f64-expected.im).abs() < 1e-4 };
+        assert!{ (goertzel.power() as f64-(expected.re*expected.re+expected.im*expected.im)).abs() < 1e-3 };
+    }
+}
+
+fn test_sliding_dft<T>( damping: f64, tol: f64 )
+    where T: MixedReal + MixedNumSigned + MixedOps + MixedZero + MixedSin + MixedPowi
+{
+    const N: usize = 64;
+    let mut x = [T::mixed_zero(); 1000];
+    dtmf(770f64, 1477f64, 0.25f64, &mut x);
+
+    let k = 6;
+    let mut sdft = SlidingDft::<T, N>::new(k, T::mixed_from_num(damping));
+    for (i, x_) in x.iter().enumerate() {
+        let bin = sdft.update(*x_);
+        if i+1 < N {
+            continue;
+        }
+
+        // Damped DFT over the most recent N samples.
+        let mut expected = Cartesian::new(0f64, 0f64);
+        for j in 0..N {
+            let x_j: f64 = x[i+1-N+j].mixed_to_num();
+            let r = damping.powi((N-j) as i32);
+            let phi = 2f64*core::f64::consts::PI*((k*(N-j)) % N) as f64/(N as f64);
+            expected.re += r*x_j*phi.cos();
+            expected.im += r*x_j*phi.sin();
+        }
+        let re: f64 = bin.re.mixed_to_num();
+        let im: f64 = bin.im.mixed_to_num();
+        assert!{ (re-expected.re).abs() < tol && (im-expected.im).abs() < tol, "{}: {}+{}i != {}+{}i", i, re, im, expected.re, expected.im };
+    }
+}
+
+#[test]
+fn sliding_dft_matches_dft() {
+    test_sliding_dft::<f64>(1f64, 1e-9);
+    test_sliding_dft::<f32>(1f64-1f64/65536f64, 1e-4);
+    test_sliding_dft::<FixedI32<U24>>(1f64-1f64/65536f64, 1e-4);
+}
@@ -0,0 +1,110 @@
+use mixed_num::*;
+use fixed::{types::extra::{U5, U12, U28, U60}, FixedI8, FixedI16, FixedI32, FixedI64, FixedU16};
+
+/// Checks the hypotenuse of `x` and `y` against f64, to within half an lsb where in range, and the fast estimate to within 1.3% and two lsb.
+fn check<T>( x: T, y: T )
+    where T: MixedReal + MixedTypeInfo + MixedHypot + core::fmt::Debug
+{
+    let x_f64: f64 = x.mixed_to_num();
+    let y_f64: f64 = y.mixed_to_num();
+    let max: f64 = T::mixed_max_value().mixed_to_num();
+    let lsb = libm::ldexp(1f64, -(T::FRAC_BITS as i32));
+    let reference = libm::hypot(x_f64, y_f64);
+
+    let hypot: f64 = x.mixed_hypot(y).mixed_to_num();
+    assert!{ (hypot-reference.min(max)).abs() <= lsb/2f64 + 1e-15*reference, "hypot of {:?} and {:?} is {:?}", x, y, x.mixed_hypot(y) };
+
+    let fast: f64 = x.mixed_hypot_fast(y).mixed_to_num();
+    if reference*1.013f64 < max
+    {
+        assert!{ (fast-reference).abs() <= 0.013f64*reference + 2f64*lsb, "fast hypot of {:?} and {:?}", x, y };
+    }
+}
+
+#[test]
+fn fixed_8_bits_full_range() {
+    for a in i8::MIN..=i8::MAX
+    {
+        for b in i8::MIN..=i8::MAX
+        {
+            check(FixedI8::<U5>::from_bits(a), FixedI8::<U5>::from_bits(b));
+        }
+    }
+}
+
+#[test]
+fn fixed_edge_of_range() {
+    type T = FixedI32<U28>;
+    let values = [T::MIN, T::MIN+T::DELTA, -T::MAX/2, -T::ONE, -T::DELTA, T::ZERO, T::DELTA, T::ONE, T::MAX/2, T::MAX-T::DELTA, T::MAX];
+    for a in values
+    {
+        for b in values
+        {
+            check(a, b);
+        }
+    }
+
+    // Representable results of parts whose squares overflow.
+    let x = T::from_num(4.8);
+    let y = T::from_num(-6.0);
+    assert!{ (x.mixed_hypot(y).to_num::<f64>()-libm::hypot(4.8, 6.0)).abs() < 4e-9 };
+    assert_eq!{ T::MAX.mixed_hypot(T::ZERO), T::MAX };
+    assert_eq!{ T::MIN.mixed_hypot(T::ZERO), T::MAX };
+    assert_eq!{ T::MAX.mixed_hypot(T::MAX), T::MAX };
+
+    type U = FixedU16<U12>;
+    check(U::MAX, U::MAX);
+    check(U::from_num(9), U::from_num(12));
+    check(FixedI16::<U12>::MIN, FixedI16::<U12>::from_num(0.5));
+
+    type W = FixedI64<U60>;
+    let values = [W::MIN, -W::ONE, W::DELTA, W::from_num(2.5), W::MAX];
+    for a in values
+    {
+        for b in values
+        {
+            check(a, b);
+        }
+    }
+}
+
+#[test]
+fn integers() {
+    assert_eq!{ 3i32.mixed_hypot(-4), 5 };
+    assert_eq!{ i64::MIN.mixed_hypot(0), i64::MAX };
+    assert_eq!{ u128::MAX.mixed_hypot(u128::MAX), u128::MAX };
+    assert_eq!{ (1u128 << 100).mixed_hypot(0), 1u128 << 100 };
+    assert_eq!{ (3u128 << 100).mixed_hypot(4u128 << 100), 5u128 << 100 };
+    assert_eq!{ 300u16.mixed_hypot(400).mixed_hypot(1200), 1300 };
+    assert_eq!{ 30i8.mixed_hypot_fast(40), 49 };
+}
+
+#[test]
+fn floats() {
+    assert_eq!{ 3e300f64.mixed_hypot(-4e300f64), 5e300f64 };
+    assert_eq!{ 3e-30f32.mixed_hypot(4e-30f32), 5e-30f32 };
+    for n in 0..=90
+    {
+        let (sin, cos) = libm::sincos((n as f64).to_radians());
+        let fast = (2f64*cos).mixed_hypot_fast(-2f64*sin);
+        assert!{ (fast-2f64).abs() < 2f64*0.013f64 };
+    }
+}
+
+#[test]
+fn cartesian_magnitude() {
+    // The squared parts overflow the fixed-point range.
+    type T = FixedI32<U28>;
+    let z = Cartesian::new(T::from_num(-3), T::from_num(4));
+    assert_eq!{ z.mag(), T::from_num(5) };
+    assert_eq!{ z.abs(), T::from_num(5) };
+    assert_eq!{ mixed_num::complex::abs(z), T::from_num(5) };
+    assert_eq!{ z.to_polar().mag, T::from_num(5) };
+    assert!{ (z.mag_fast().to_num::<f64>()-5f64).abs() < 5f64*0.013 };
+
+    let z = Cartesian::new(1e200f64, 1e200f64);
+    assert!{ (z.mag()/1e200f64-2f64.sqrt()).abs() < 1e-15 };
+
+    let polar = Polar::new(2f32, 0.3f32);
+    assert_eq!{ polar.mag_fast(), 2f32 };
+}
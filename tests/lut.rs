@@ -0,0 +1,107 @@
+use mixed_num::*;
+use mixed_num::trigonometry::lut::*;
+use fixed::{types::extra::{U5, U13, U28}, FixedI8, FixedI16, FixedI32};
+
+use core::f64::consts::PI;
+
+const Q30: f64 = (1u64<<30) as f64;
+const TURN: f64 = 4294967296f64;
+
+#[test]
+fn table_interpolation_accuracy() {
+    let mut linear = 0f64;
+    let mut quadratic = 0f64;
+    // Every 4099th binary angle, which visits all table segments and offsets.
+    for k in 0..(1u64<<32)/4099 {
+        let angle = (k*4099) as u32;
+        let reference = (2f64*PI*(angle as f64)/TURN).sin();
+        linear    = linear.max( (sin_bam(angle, Interpolation::Linear) as f64/Q30-reference).abs() );
+        quadratic = quadratic.max( (sin_bam(angle, Interpolation::Quadratic) as f64/Q30-reference).abs() );
+
+        let reference = (2f64*PI*(angle as f64)/TURN).cos();
+        linear = linear.max( (cos_bam(angle, Interpolation::Linear) as f64/Q30-reference).abs() );
+    }
+    assert!{ linear < 5e-6, "{}", linear };
+    assert!{ quadratic < 2e-8, "{}", quadratic };
+
+    // Exact at the quadrants.
+    assert_eq!{ sin_bam(0, Interpolation::Linear), 0 };
+    assert_eq!{ sin_bam(1<<30, Interpolation::Quadratic), 1<<30 };
+    assert_eq!{ sin_bam(3<<30, Interpolation::Linear), -(1<<30) };
+    assert_eq!{ cos_bam(1<<31, Interpolation::Linear), -(1<<30) };
+}
+
+#[test]
+fn atan2_table_accuracy() {
+    let mut error = 0f64;
+    for i in -300i64..=300 {
+        for j in [-1000000i64, -777, -1, 0, 1, 3, 512, 999999] {
+            for (y, x) in [(i, j), (j, i)] {
+                let reference = (y as f64).atan2(x as f64);
+                let angle = atan2_bam(y, x) as f64*2f64*PI/TURN;
+                error = error.max( (angle-reference).abs() );
+            }
+        }
+    }
+    assert!{ error < 2e-6, "{}", error };
+
+    // Extreme arguments do not overflow.
+    assert!{ (atan2_bam(i64::MAX, i64::MIN)-(3<<29)).abs() < 100 };
+}
+
+#[test]
+fn angle_conversions() {
+    // π/2 in FixedI32<U28>.
+    let bits = FixedI32::<U28>::from_num(PI/2f64).to_bits() as i64;
+    assert!{ (radians_to_bam(bits, 28) as i64-(1<<30)).abs() <= 2 };
+    // -π/2 wraps to three quarters of a turn.
+    assert!{ (radians_to_bam(-bits, 28) as i64-(3<<30)).abs() <= 2 };
+
+    assert_eq!{ bam_to_radians(1<<31, 28), FixedI32::<U28>::from_num(PI).to_bits() as i64 };
+    assert_eq!{ q30_to_bits(1<<30, 13), 1<<13 };
+    assert_eq!{ q30_to_bits(-(1<<29), 40), -(1<<39) };
+}
+
+/// Exhaustive test of the table backend of `FixedI16<U13>`, for every input.
+#[test]
+fn fixed_i16_exhaustive() {
+    let lsb = 2f64.powi(-13);
+    let mut errors = [0f64; 3];
+    for bits in i16::MIN..=i16::MAX {
+        let x_ = FixedI16::<U13>::from_bits(bits);
+        let x = x_.to_num::<f64>();
+
+        errors[0] = errors[0].max( (x_.mixed_sin().to_num::<f64>()-x.sin()).abs() );
+        errors[1] = errors[1].max( (x_.mixed_cos().to_num::<f64>()-x.cos()).abs() );
+        errors[2] = errors[2].max( (x_.mixed_atan().to_num::<f64>()-x.atan()).abs() );
+
+        let (sin, cos) = x_.mixed_sincos();
+        assert_eq!{ (sin, cos), (x_.mixed_sin(), x_.mixed_cos()) };
+    }
+    // Rounding to the type, and the table error.
+    for error in errors {
+        assert!{ error < lsb/2f64+5e-6, "{:?}", errors };
+    }
+
+    // atan2 of every y against a set of x.
+    let mut error = 0f64;
+    for bits in i16::MIN..=i16::MAX {
+        let y_ = FixedI16::<U13>::from_bits(bits);
+        for x in [-4f64, -1f64, -0.3f64, 0f64, 0.0001f64, 0.5f64, 3.9f64] {
+            let x_ = FixedI16::<U13>::from_num(x);
+            let reference = y_.to_num::<f64>().atan2(x_.to_num::<f64>());
+            error = error.max( (y_.mixed_atan2(x_).to_num::<f64>()-reference).abs() );
+        }
+    }
+    assert!{ error < lsb/2f64+2e-6, "{}", error };
+}
+
+#[test]
+fn fixed_i8_backend() {
+    for bits in i8::MIN..=i8::MAX {
+        let x_ = FixedI8::<U5>::from_bits(bits);
+        let x = x_.to_num::<f64>();
+        assert!{ (x_.mixed_sin().to_num::<f64>()-x.sin()).abs() <= 1f64/64f64 };
+        assert!{ (x_.mixed_cos().to_num::<f64>()-x.cos()).abs() <= 1f64/64f64 };
+    }
+}
@@ -0,0 +1,83 @@
+use mixed_num::*;
+use fixed::{types::extra::{U12, U28}, FixedI32, FixedU16};
+
+#[test]
+fn floats_follow_min_num() {
+    let nan = f64::NAN;
+    assert_eq!{ nan.mixed_min(2f64), 2f64 };
+    assert_eq!{ 2f64.mixed_min(nan), 2f64 };
+    assert_eq!{ nan.mixed_max(-2f64), -2f64 };
+    assert!{ nan.mixed_max(nan).is_nan() };
+    assert_eq!{ f32::NEG_INFINITY.mixed_max(f32::MIN), f32::MIN };
+
+    assert_eq!{ nan.mixed_clamp(-1f64, 1f64), -1f64 };
+    assert_eq!{ 5f64.mixed_clamp(nan, 1f64), 1f64 };
+    assert_eq!{ 5f64.mixed_clamp(-1f64, nan), 5f64 };
+    assert_eq!{ f64::INFINITY.mixed_clamp(-1f64, 1f64), 1f64 };
+    // min > max does not panic, and max wins.
+    assert_eq!{ 0f32.mixed_clamp(1f32, -1f32), -1f32 };
+
+    assert_eq!{ (-3f32).mixed_min_by_mag(2f32), 2f32 };
+    assert_eq!{ (-3f32).mixed_max_by_mag(2f32), -3f32 };
+    assert_eq!{ nan.mixed_min_by_mag(-7f64), -7f64 };
+    assert_eq!{ (-7f64).mixed_max_by_mag(nan), -7f64 };
+}
+
+#[test]
+fn fixed_at_saturation_limits() {
+    type T = FixedI32<U28>;
+    assert_eq!{ T::MAX.mixed_min(T::MIN), T::MIN };
+    assert_eq!{ T::MIN.mixed_max(T::ZERO), T::ZERO };
+    assert_eq!{ T::MIN.mixed_clamp(T::NEG_ONE, T::ONE), T::NEG_ONE };
+    assert_eq!{ T::MAX.mixed_clamp(T::NEG_ONE, T::ONE), T::ONE };
+    assert_eq!{ T::ZERO.mixed_clamp(T::ONE, T::NEG_ONE), T::NEG_ONE };
+
+    // The magnitude of MIN does not overflow.
+    assert_eq!{ T::MIN.mixed_max_by_mag(T::MAX), T::MIN };
+    assert_eq!{ T::MAX.mixed_min_by_mag(T::MIN), T::MAX };
+
+    type U = FixedU16<U12>;
+    assert_eq!{ U::MAX.mixed_clamp(U::ZERO, U::ONE), U::ONE };
+    assert_eq!{ U::ONE.mixed_min_by_mag(U::ZERO), U::ZERO };
+}
+
+#[test]
+fn integers() {
+    assert_eq!{ i8::MIN.mixed_max_by_mag(i8::MAX), i8::MIN };
+    assert_eq!{ i64::MIN.mixed_clamp(-10, 10), -10 };
+    assert_eq!{ 7u8.mixed_min(3), 3 };
+    assert_eq!{ (-4i32).mixed_min_by_mag(4), -4 };
+}
+
+#[test]
+fn cartesian_by_magnitude() {
+    let a = Cartesian::new(3f64, 4f64);
+    let b = Cartesian::new(-5.5f64, 0f64);
+    assert_eq!{ a.mixed_min(b), a };
+    assert_eq!{ a.mixed_max(b), b };
+    assert_eq!{ a.mixed_min_by_mag(b), a };
+    assert_eq!{ Cartesian::new(f64::NAN, 0f64).mixed_min(a), a };
+
+    // The magnitude is clamped, with the phase kept.
+    let zero = Cartesian::new(0f64, 0f64);
+    let z = Cartesian::new(0.3f64, -0.4f64).mixed_clamp(Cartesian::new(1f64, 0f64), Cartesian::new(2f64, 0f64));
+    assert!{ (z.re-0.6f64).abs() < 1e-15 && (z.im+0.8f64).abs() < 1e-15 };
+    assert_eq!{ a.mixed_clamp(zero, b), a };
+    assert_eq!{ zero.mixed_clamp(Cartesian::new(0f64, 2f64), b), Cartesian::new(2f64, 0f64) };
+
+    // Fixed-point magnitudes are compared without overflow.
+    type T = FixedI32<U28>;
+    let big = Cartesian::new(T::MAX, T::MAX);
+    let small = Cartesian::new(T::MIN, T::ZERO);
+    assert_eq!{ big.mixed_max(small), big };
+    let limited = big.mixed_clamp(Cartesian::new(T::ZERO, T::ZERO), Cartesian::new(T::ONE, T::ZERO));
+    assert!{ (limited.re.to_num::<f64>()-0.5f64.sqrt()).abs() < 1e-8 && limited.re == limited.im };
+
+    // The magnitude of `min` is out of range on the real axis.
+    let one = Cartesian::new(T::ONE, T::ZERO);
+    assert_eq!{ one.mixed_clamp(big, big), Cartesian::new(T::MAX, T::ZERO) };
+    assert_eq!{ Cartesian::new(-T::ONE, T::ZERO).mixed_clamp(big, big), Cartesian::new(T::MIN, T::ZERO) };
+    assert_eq!{ Cartesian::new(T::ZERO, T::ZERO).mixed_clamp(big, big), Cartesian::new(T::MAX, T::ZERO) };
+    let z = Cartesian::new(T::ONE, T::ONE).mixed_clamp(big, big);
+    assert!{ (z.re.to_num::<f64>()-T::MAX.to_num::<f64>()).abs() < 1e-7 && z.re == z.im };
+}
@@ -0,0 +1,88 @@
+use mixed_num::*;
+use mixed_num::dsp::*;
+use fixed::{types::extra::U13, types::extra::U28, FixedI16, FixedI32};
+
+/// Mix a tone at `f0` down to DC in blocks, and check that the output is constant.
+fn test_tone_to_dc<T>( f0: T, tol: f64 )
+    where T: MixedReal + MixedNumSigned + MixedOps + MixedZero + MixedPi + MixedAbs + MixedSin + MixedWrapPhase
+{
+    let mut tone = [Cartesian::new(T::mixed_zero(), T::mixed_zero()); 300];
+    Nco::new(f0).fill_cartesian(&mut tone);
+
+    let mut mixer = Mixer::new(f0, MixerDirection::Down);
+    let mut baseband = tone;
+    for (x, y) in tone.chunks(37).zip(baseband.chunks_mut(37)) {
+        mixer.process(x, y);
+    }
+
+    for y in baseband {
+        let re: f64 = y.re.mixed_to_num();
+        let im: f64 = y.im.mixed_to_num();
+        assert!{ (re-1f64).abs() < tol && im.abs() < tol, "{}+{}i", re, im };
+    }
+
+    // Mixing the DC back up restores the tone.
+    let mut mixer = Mixer::new(f0, MixerDirection::Up);
+    mixer.process_in_place(&mut baseband);
+    for (x, y) in tone.iter().zip(baseband.iter()) {
+        let err_re: f64 = (x.re-y.re).mixed_to_num();
+        let err_im: f64 = (x.im-y.im).mixed_to_num();
+        assert!{ err_re.abs() < 2f64*tol && err_im.abs() < 2f64*tol };
+    }
+}
+
+/// Mix a real tone at `f0` down, and check that the average over a whole period is 0.5 at DC.
+fn test_real_tone_to_dc<T>( f0: T, period: usize, tol: f64 )
+    where T: MixedReal + MixedNumSigned + MixedOps + MixedZero + MixedPi + MixedAbs + MixedSin + MixedWrapPhase
+{
+    let mut tone = [T::mixed_zero(); 64];
+    Nco::new(f0).fill_real(&mut tone);
+
+    let mut baseband = [Cartesian::new(T::mixed_zero(), T::mixed_zero()); 64];
+    let mut mixer = Mixer::new(f0, MixerDirection::Down);
+    mixer.process_real(&tone[..32], &mut baseband[..32]);
+    mixer.process_real(&tone[32..], &mut baseband[32..]);
+
+    let mut re = 0f64;
+    let mut im = 0f64;
+    // Average across the block boundary.
+    for y in baseband[32-period/2..32+period/2].iter() {
+        let y_re: f64 = y.re.mixed_to_num();
+        let y_im: f64 = y.im.mixed_to_num();
+        re += y_re;
+        im += y_im;
+    }
+    re /= period as f64;
+    im /= period as f64;
+    assert!{ (re-0.5f64).abs() < tol && im.abs() < tol, "{}+{}i", re, im };
+}
+
+#[test]
+fn mixer_tone_to_dc() {
+    test_tone_to_dc( 0.3f32, 1e-5 );
+    test_tone_to_dc( -2.5f32, 1e-5 );
+    test_tone_to_dc( FixedI32::<U28>::from_num(0.3), 1e-6 );
+    test_tone_to_dc( FixedI32::<U28>::from_num(-2.5), 1e-6 );
+
+    let f0 = 2f64*core::f64::consts::PI/16f64;
+    test_real_tone_to_dc( f0 as f32, 16, 1e-5 );
+    test_real_tone_to_dc( FixedI32::<U28>::from_num(f0), 16, 1e-6 );
+}
+
+#[test]
+fn mixer_rounded_output() {
+    let f0 = FixedI32::<U28>::from_num(0.7);
+    let mut tone = [Cartesian::new(FixedI32::<U28>::from_num(0), FixedI32::<U28>::from_num(0)); 64];
+    Nco::new(f0).fill_cartesian(&mut tone);
+
+    let mut rounded  = [Cartesian::new(FixedI16::<U13>::from_num(0), FixedI16::<U13>::from_num(0)); 64];
+    let mut extended = tone;
+    Mixer::new(f0, MixerDirection::Up).process_rounded(&tone, &mut rounded);
+    Mixer::new(f0, MixerDirection::Up).process(&tone, &mut extended);
+
+    let lsb = FixedI16::<U13>::DELTA.to_num::<f64>();
+    for (r, e) in rounded.iter().zip(extended.iter()) {
+        assert!{ (r.re.to_num::<f64>() - e.re.to_num::<f64>()).abs() <= lsb/2f64 };
+        assert!{ (r.im.to_num::<f64>() - e.im.to_num::<f64>()).abs() <= lsb/2f64 };
+    }
+}
@@ -0,0 +1,116 @@
+use mixed_num::*;
+use mixed_num::dsp::*;
+use fixed::{types::extra::{U28, U29}, FixedI32};
+
+const N: usize = 1024;
+
+/// Spurious free dynamic range [dB] of a complex tone in bin `k` of an `N` point DFT.
+fn sfdr( x: &[Cartesian<f64>], k: usize ) -> f64 {
+    let mut carrier = 0f64;
+    let mut max_spur = 0f64;
+    for bin in 0..x.len() {
+        let mut acc = Cartesian::new(0f64, 0f64);
+        for (n, x_) in x.iter().enumerate() {
+            let phi = -2f64*core::f64::consts::PI*((bin*n) % x.len()) as f64/(x.len() as f64);
+            acc.re += x_.re*phi.cos() - x_.im*phi.sin();
+            acc.im += x_.re*phi.sin() + x_.im*phi.cos();
+        }
+        let power = acc.re*acc.re + acc.im*acc.im;
+        if bin == k {
+            carrier = power;
+        }
+        else if max_spur < power {
+            max_spur = power;
+        }
+    }
+    return 10f64*(carrier/max_spur).log10();
+}
+
+fn tone<T>( mut nco: Nco<T> ) -> [Cartesian<f64>; N]
+    where T: MixedReal + MixedNumSigned + MixedOps + MixedZero + MixedPi + MixedAbs + MixedSin + MixedWrapPhase
+{
+    let mut x = [Cartesian::new(0f64, 0f64); N];
+    for x_ in x.iter_mut() {
+        let y = nco.next_cartesian();
+        *x_ = Cartesian::new(y.re.mixed_to_num(), y.im.mixed_to_num());
+    }
+    return x;
+}
+
+#[test]
+fn nco_spectral_purity_f32() {
+    let k = 37;
+    let f = 2f32*f32::mixed_pi()*(k as f32)/(N as f32);
+
+    assert!{ sfdr(&tone(Nco::new(f)), k) > 100f64 };
+    assert!{ sfdr(&tone(Nco::new_binary(f)), k) > 100f64 };
+}
+
+#[test]
+fn nco_spectral_purity_fixed() {
+    let k = 37;
+    let f = FixedI32::<U28>::from_num( 2f64*core::f64::consts::PI*(k as f64)/(N as f64) );
+
+    assert!{ sfdr(&tone(Nco::new(f)), k) > 120f64 };
+    assert!{ sfdr(&tone(Nco::new_binary(f)), k) > 120f64 };
+
+    let mut nco = Nco::new_binary(f);
+    nco.set_phase_dither( FixedI32::<U28>::from_num(1e-4) );
+    assert!{ sfdr(&tone(nco), k) > 90f64 };
+}
+
+#[test]
+fn nco_binary_phase_does_not_drift() {
+    let mut nco = Nco::new_binary( 0.1f32 );
+    let tuning_word = nco.tuning_word();
+
+    let mut x = [0f32; 1000];
+    for _ in 0..100 {
+        nco.fill_real(&mut x);
+    }
+    // A single step by the accumulated tuning word.
+    let mut expected = Nco::<f32>::new_binary(0f32);
+    expected.set_tuning_word( tuning_word.wrapping_mul(100_000) );
+    expected.next_real();
+    assert_eq!{ nco.phase(), expected.phase() };
+}
+
+#[test]
+fn nco_modulation() {
+    let f = 0.25f64;
+    let mut nco = Nco::new(f);
+    let mut reference = Nco::new(f);
+
+    // A frequency step accumulates into the phase.
+    let fm = [0.1f64, 0.2f64, 0f64];
+    let mut y = [Cartesian::new(0f64, 0f64); 3];
+    nco.fill_cartesian_fm(&fm, &mut y);
+    reference.set_phase( 0.3f64 );
+    for _ in 0..3 {
+        reference.next_cartesian();
+    }
+    assert!{ (nco.phase() - reference.phase()).abs() < 1e-12 };
+
+    // A phase offset only affects the current sample.
+    let mut nco = Nco::new(f);
+    let x = nco.next_real_modulated(0f64, 1f64);
+    assert_eq!{ x, 1f64.cos() };
+    assert_eq!{ nco.next_real(), f.cos() };
+}
+
+#[test]
+fn nco_narrowest_fixed_type() {
+    // 2π is out of the range of the type.
+    type T = FixedI32<U29>;
+    for f in [0.5f64, 3f64, -0.5f64, -3f64] {
+        let mut nco = Nco::new(T::from_num(f));
+        for n in 1..=200 {
+            nco.next_real();
+            let phase = nco.phase().to_num::<f64>();
+            let reference = (f*n as f64+core::f64::consts::PI).rem_euclid(2f64*core::f64::consts::PI)-core::f64::consts::PI;
+            let error = (phase-reference).abs();
+            assert!{ -core::f64::consts::PI <= phase && phase < core::f64::consts::PI, "{} {}", f, phase };
+            assert!{ error < 1e-6 || (2f64*core::f64::consts::PI-error).abs() < 1e-6, "{} {}: {} != {}", f, n, phase, reference };
+        }
+    }
+}
@@ -0,0 +1,154 @@
+use mixed_num::*;
+use mixed_num::polynomial::*;
+use mixed_num::trigonometry::*;
+use fixed::{types::extra::{U12, U13, U24, U28}, FixedI16, FixedI32};
+
+#[test]
+fn horner_and_estrin_agree() {
+    let p = Polynomial::new([0.5f64, -1f64, 0.25f64, 2f64, -0.75f64, 0.125f64, 1f64]);
+    for i in 0..=20 {
+        let x = -1f64+(i as f64)/10f64;
+        let mut reference = 0f64;
+        for (k, c) in p.coeffs.iter().enumerate() {
+            reference += c*x.powi(k as i32);
+        }
+        assert!{ (p.horner(x)-reference).abs() < 1e-12 };
+        assert!{ (p.estrin(x)-reference).abs() < 1e-12 };
+    }
+
+    // Odd and even number of coefficients.
+    let q = Polynomial::<FixedI32<U24>, 5>::from_f64([0.5f64, -1f64, 0.25f64, 2f64, -0.75f64]);
+    let r = Polynomial::<FixedI32<U24>, 4>::from_f64([0.5f64, -1f64, 0.25f64, 2f64]);
+    for i in 0..=20 {
+        let x = FixedI32::<U24>::from_num(-1f64+(i as f64)/10f64);
+        assert!{ (q.horner(x)-q.estrin(x)).abs().to_num::<f64>() < 1e-6 };
+        assert!{ (r.horner(x)-r.estrin(x)).abs().to_num::<f64>() < 1e-6 };
+    }
+
+    assert_eq!{ Polynomial::<f32, 0>::new([]).estrin(2f32), 0f32 };
+    assert_eq!{ Polynomial::new([3f32]).estrin(2f32), 3f32 };
+}
+
+#[test]
+fn derivative_and_integral() {
+    let p = Polynomial::new([1f64, 2f64, 3f64, 4f64]);
+
+    let dp: Polynomial<f64, 3> = p.derivative();
+    assert_eq!{ dp.coeffs, [2f64, 6f64, 12f64] };
+
+    let ip: Polynomial<f64, 5> = p.integral(5f64);
+    assert_eq!{ ip.coeffs, [5f64, 1f64, 1f64, 1f64, 1f64] };
+
+    let back: Polynomial<f64, 4> = ip.derivative();
+    assert_eq!{ back, p };
+
+    // k*c[k] exceeds the integer range of the type, while the coefficients do not.
+    let p = Polynomial::<FixedI16<U13>, 6>::from_f64([0f64, 0f64, 0f64, 0f64, 0f64, 0.5f64]);
+    let dp: Polynomial<FixedI16<U13>, 5> = p.derivative();
+    assert_eq!{ dp.coeffs[4].to_num::<f64>(), 2.5f64 };
+    assert_eq!{ p.degree(), 5 };
+}
+
+#[test]
+#[should_panic]
+fn integral_needs_an_extra_coefficient() {
+    let p = Polynomial::new([1f64, 2f64]);
+    let _: Polynomial<f64, 2> = p.integral(0f64);
+}
+
+fn contains( roots: &[Cartesian<f64>], re: f64, im: f64, tol: f64 ) -> bool {
+    return roots.iter().any( |r| (r.re-re).abs() < tol && (r.im-im).abs() < tol );
+}
+
+#[test]
+fn durand_kerner_roots() {
+    // (x-1)(x-2)(x+3) = x^3 - 7x + 6, with a zero highest coefficient.
+    let p = Polynomial::new([6f64, -7f64, 0f64, 1f64, 0f64]);
+    let mut roots = [Cartesian::new(0f64, 0f64); 4];
+    assert_eq!{ p.roots(&mut roots, 200), 3 };
+    for (re, im) in [(1f64, 0f64), (2f64, 0f64), (-3f64, 0f64)] {
+        assert!{ contains(&roots[..3], re, im, 1e-10), "{:?}", roots };
+    }
+
+    // 2(x^2 - 2x + 2)(x - 0.5), roots 1±i and 0.5.
+    let p = Polynomial::new([-2f64, 6f64, -5f64, 2f64]);
+    let mut roots = [Cartesian::new(0f64, 0f64); 3];
+    assert_eq!{ p.roots(&mut roots, 200), 3 };
+    for (re, im) in [(1f64, 1f64), (1f64, -1f64), (0.5f64, 0f64)] {
+        assert!{ contains(&roots, re, im, 1e-10), "{:?}", roots };
+    }
+
+    // The same polynomial in fixed point.
+    let p = Polynomial::<FixedI32<U24>, 4>::from_f64([-2f64, 6f64, -5f64, 2f64]);
+    let mut roots = [Cartesian::new(FixedI32::<U24>::ZERO, FixedI32::<U24>::ZERO); 3];
+    assert_eq!{ p.roots(&mut roots, 200), 3 };
+    let roots = roots.map( |r| Cartesian::new(r.re.to_num::<f64>(), r.im.to_num::<f64>()) );
+    for (re, im) in [(1f64, 1f64), (1f64, -1f64), (0.5f64, 0f64)] {
+        assert!{ contains(&roots, re, im, 1e-4), "{:?}", roots };
+    }
+
+    // Constant polynomials have no roots.
+    let mut roots = [Cartesian::new(FixedI32::<U24>::ZERO, FixedI32::<U24>::ZERO); 3];
+    assert_eq!{ Polynomial::<FixedI32<U24>, 3>::from_f64([0f64; 3]).roots(&mut roots, 200), 0 };
+    assert_eq!{ Polynomial::<FixedI32<U24>, 3>::from_f64([2f64, 0f64, 0f64]).roots(&mut roots, 200), 0 };
+
+    // (x-0.5)^3, where the distance of the estimates underflows, and the triple root is accurate to the cube root of the resolution.
+    let p = Polynomial::<FixedI32<U12>, 4>::from_f64([-0.125f64, 0.75f64, -1.5f64, 1f64]);
+    let mut roots = [Cartesian::new(FixedI32::<U12>::ZERO, FixedI32::<U12>::ZERO); 3];
+    assert_eq!{ p.roots(&mut roots, 200), 3 };
+    for r in roots.iter() {
+        assert!{ (r.re.to_num::<f64>()-0.5f64).abs() < 0.15 && r.im.to_num::<f64>().abs() < 0.15, "{:?}", roots };
+    }
+}
+
+#[test]
+fn chebyshev_fit_accuracy() {
+    let p = Polynomial::<f64, 10>::chebyshev_fit( libm::exp, -1f64, 1f64 );
+    let q = Polynomial::<FixedI32<U24>, 10>::chebyshev_fit( libm::exp, -1f64, 1f64 );
+    // An interval away from zero.
+    let r = Polynomial::<f64, 10>::chebyshev_fit( libm::log, 1f64, 2f64 );
+    for i in 0..=200 {
+        let x = -1f64+(i as f64)/100f64;
+        assert!{ (p.horner(x)-x.exp()).abs() < 1e-9 };
+        assert!{ (q.horner(FixedI32::<U24>::from_num(x)).to_num::<f64>()-x.exp()).abs() < 2e-6 };
+
+        let x = 1f64+(i as f64)/200f64;
+        assert!{ (r.horner(x)-x.ln()).abs() < 1e-7 };
+    }
+}
+
+#[test]
+fn trigonometric_approximations() {
+    // The Taylor polynomial in sin, evaluated with Horner's method.
+    for i in 0..=200 {
+        let x = -3.14f64+6.28f64*(i as f64)/200f64;
+        assert!{ (sin(x)-x.sin()).abs() < 1e-4 };
+        assert!{ (sin(FixedI32::<U28>::from_num(x)).to_num::<f64>()-x.sin()).abs() < 1e-4 };
+        assert!{ (cos(FixedI32::<U28>::from_num(x)).to_num::<f64>()-x.cos()).abs() < 1e-4 };
+    }
+
+    // Custom approximations.
+    let approximation = Polynomial::<FixedI32<U28>, 10>::chebyshev_fit( libm::sin, -1.6f64, 1.6f64 );
+    for i in 0..=200 {
+        let x = -3.14f64+6.28f64*(i as f64)/200f64;
+        assert!{ (sin_with(FixedI32::<U28>::from_num(x), &approximation).to_num::<f64>()-x.sin()).abs() < 1e-6 };
+        assert!{ (cos_with(FixedI32::<U28>::from_num(x), &approximation).to_num::<f64>()-x.cos()).abs() < 1e-6 };
+    }
+    assert_eq!{ sin_with(1f32, &|x: f32| x), 1f32 };
+
+    let approximation = Polynomial::<f64, 16>::chebyshev_fit( libm::atan, 0f64, 1f64 );
+    for i in 0..32 {
+        let theta = -3.1f64+6.2f64*(i as f64)/31f64;
+        let (y, x) = (2f64*theta.sin(), 2f64*theta.cos());
+        assert!{ (atan::atan2_with(y, x, &approximation)-theta).abs() < 1e-9 };
+        assert_eq!{ y.mixed_atan2_with(x, &approximation), atan::atan2_with(y, x, &approximation) };
+    }
+    assert_eq!{ atan::atan2_with(0f64, 0f64, &approximation), 0f64 };
+
+    // The trait methods.
+    let approximation = Polynomial::<FixedI32<U28>, 10>::chebyshev_fit( libm::sin, -1.6f64, 1.6f64 );
+    let x = FixedI32::<U28>::from_num(2.5);
+    assert_eq!{ x.mixed_sin_with(&approximation), sin_with(x, &approximation) };
+    assert_eq!{ x.mixed_cos_with(&approximation), cos_with(x, &approximation) };
+    assert_eq!{ 1f32.mixed_sin_with(&|x: f32| x), 1f32 };
+}
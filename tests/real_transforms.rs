@@ -0,0 +1,149 @@
+use mixed_num::*;
+use mixed_num::dsp::*;
+use fixed::{types::extra::U12, types::extra::U28, FixedI16, FixedI32};
+use core::f64::consts::PI;
+
+/// Deterministic test signal with magnitude below one.
+fn signal( n: usize ) -> Vec<f64> {
+    return (0..n).map(|i| 0.5f64*(0.7f64*i as f64).sin() + 0.3f64*(0.11f64*(i*i) as f64).cos()).collect();
+}
+
+fn to_type<T: MixedReal>( x: &[f64] ) -> Vec<T> {
+    return x.iter().map(|x_| T::mixed_from_num(*x_)).collect();
+}
+
+fn assert_close<T: MixedReal>( y: &[T], expected: &[f64], scale: f64, tol: f64 ) {
+    for (y_, e) in y.iter().zip(expected.iter()) {
+        let y_: f64 = y_.mixed_to_num();
+        assert!{ (y_-e*scale).abs() < tol, "{} != {}", y_, e*scale };
+    }
+}
+
+fn dft_real( x: &[f64] ) -> Vec<Cartesian<f64>> {
+    let n = x.len();
+    return (0..=n/2).map(|k| {
+        let mut acc = Cartesian::new(0f64, 0f64);
+        for (j, x_) in x.iter().enumerate() {
+            let phi = -2f64*PI*((k*j) % n) as f64/(n as f64);
+            acc.re += x_*phi.cos();
+            acc.im += x_*phi.sin();
+        }
+        acc
+    }).collect();
+}
+
+fn dct2( x: &[f64] ) -> Vec<f64> {
+    let n = x.len() as f64;
+    return (0..x.len()).map(|k| x.iter().enumerate().map(|(i, x_)| x_*(PI*(2*i+1) as f64*k as f64/(2f64*n)).cos()).sum()).collect();
+}
+
+fn dct3( x: &[f64] ) -> Vec<f64> {
+    let n = x.len() as f64;
+    return (0..x.len()).map(|i| x[0]/2f64 + x.iter().enumerate().skip(1).map(|(k, x_)| x_*(PI*(2*i+1) as f64*k as f64/(2f64*n)).cos()).sum::<f64>()).collect();
+}
+
+fn dct4( x: &[f64] ) -> Vec<f64> {
+    let n = x.len() as f64;
+    return (0..x.len()).map(|k| x.iter().enumerate().map(|(i, x_)| x_*(PI*(2*i+1) as f64*(2*k+1) as f64/(4f64*n)).cos()).sum()).collect();
+}
+
+fn test_rfft<T, const M: usize>( scaling: FftScaling, gain: f64, tol: f64 )
+    where T: MixedReal + MixedNumSigned + MixedSin + MixedOps + MixedZero + MixedAbs
+{
+    let rfft = RealFft::<T, M>::new().unwrap();
+    let x = signal(2*M);
+    let input: Vec<T> = to_type(&x);
+    let mut spectrum = vec![Cartesian::new(T::mixed_zero(), T::mixed_zero()); M+1];
+
+    let exponent = rfft.rfft(&input, &mut spectrum, scaling);
+    let scale = gain*libm::exp2(-(exponent as f64));
+    let expected = dft_real(&x);
+    let re: Vec<T> = spectrum.iter().map(|z| z.re).collect();
+    let im: Vec<T> = spectrum.iter().map(|z| z.im).collect();
+    assert_close(&re, &expected.iter().map(|z| z.re).collect::<Vec<f64>>(), scale, tol);
+    assert_close(&im, &expected.iter().map(|z| z.im).collect::<Vec<f64>>(), scale, tol);
+
+    // The inverse of the exact spectrum, scaled by 1/(2*M) to stay in range.
+    let n = (2*M) as f64;
+    let mut spectrum: Vec<Cartesian<T>> = expected.iter().map(|z| Cartesian::new(T::mixed_from_num(z.re/n), T::mixed_from_num(z.im/n))).collect();
+    let mut output = vec![T::mixed_zero(); 2*M];
+    let exponent = rfft.irfft(&mut spectrum, &mut output, scaling);
+    let scale = if scaling == FftScaling::PerStage { 1f64/n } else { 1f64 };
+    assert_close(&output, &x, scale*libm::exp2(-(exponent as f64)), tol);
+}
+
+fn test_dct<T, const N: usize>( scaling: FftScaling, gain: f64, tol: f64 )
+    where T: MixedReal + MixedNumSigned + MixedSin + MixedOps + MixedZero + MixedAbs
+{
+    let dct = Dct::<T, N>::new().unwrap();
+    let x = signal(N);
+    let input: Vec<T> = to_type(&x);
+    let mut output = vec![T::mixed_zero(); N];
+    let mut scratch = vec![Cartesian::new(T::mixed_zero(), T::mixed_zero()); N];
+
+    let exponent = dct.dct2(&input, &mut output, &mut scratch, scaling);
+    assert_close(&output, &dct2(&x), gain*libm::exp2(-(exponent as f64)), tol);
+
+    let exponent = dct.dct3(&input, &mut output, &mut scratch, scaling);
+    assert_close(&output, &dct3(&x), gain*libm::exp2(-(exponent as f64)), tol);
+
+    let exponent = dct.dct4(&input, &mut output, &mut scratch, scaling);
+    assert_close(&output, &dct4(&x), gain*libm::exp2(-(exponent as f64)), tol);
+}
+
+#[test]
+fn rfft_matches_dft() {
+    test_rfft::<f64, 1>(FftScaling::None, 1f64, 1e-12);
+    test_rfft::<f64, 8>(FftScaling::None, 1f64, 1e-12);
+    test_rfft::<f64, 15>(FftScaling::None, 1f64, 1e-12);
+    test_rfft::<f32, 256>(FftScaling::None, 1f64, 1e-3);
+    test_rfft::<f32, 30>(FftScaling::PerStage, 1f64/60f64, 1e-6);
+    test_rfft::<FixedI32<U28>, 32>(FftScaling::PerStage, 1f64/64f64, 1e-7);
+    test_rfft::<FixedI32<U28>, 6>(FftScaling::None, 1f64, 1e-6);
+    test_rfft::<FixedI16<U12>, 32>(FftScaling::BlockFloatingPoint, 1f64, 0.02);
+}
+
+#[test]
+fn dct_matches_direct_formula() {
+    test_dct::<f64, 1>(FftScaling::None, 1f64, 1e-12);
+    test_dct::<f64, 8>(FftScaling::None, 1f64, 1e-12);
+    test_dct::<f64, 15>(FftScaling::None, 1f64, 1e-12);
+    test_dct::<f32, 64>(FftScaling::None, 1f64, 1e-4);
+    test_dct::<f32, 24>(FftScaling::PerStage, 1f64/24f64, 1e-6);
+    test_dct::<FixedI32<U28>, 32>(FftScaling::PerStage, 1f64/32f64, 1e-7);
+    test_dct::<FixedI16<U12>, 64>(FftScaling::BlockFloatingPoint, 1f64, 0.05);
+}
+
+#[test]
+fn dct4_error_at_large_sizes() {
+    // The error is the rounding of the scaled FFT stages, as for the DCT-II, and does not grow with the output index.
+    fn check<T, const N: usize>( lsb: f64 )
+        where T: MixedReal + MixedNumSigned + MixedSin + MixedOps + MixedZero + MixedAbs
+    {
+        let dct = Dct::<T, N>::new().unwrap();
+        let x = signal(N);
+        let mut output = vec![T::mixed_zero(); N];
+        let mut scratch = vec![Cartesian::new(T::mixed_zero(), T::mixed_zero()); N];
+        dct.dct4(&to_type::<T>(&x), &mut output, &mut scratch, FftScaling::PerStage);
+        assert_close(&output, &dct4(&x), 1f64/N as f64, 10f64*lsb);
+    }
+    check::<FixedI32<U28>, 4096>(libm::exp2(-28f64));
+    check::<FixedI16<U12>, 1024>(libm::exp2(-12f64));
+}
+
+#[test]
+fn dct_inverses() {
+    let dct = Dct::<f64, 12>::new().unwrap();
+    let x = signal(12);
+    let mut y = [0f64; 12];
+    let mut z = [0f64; 12];
+    let mut scratch = [Cartesian::new(0f64, 0f64); 12];
+
+    dct.dct2(&x, &mut y, &mut scratch, FftScaling::None);
+    dct.dct3(&y, &mut z, &mut scratch, FftScaling::None);
+    assert_close(&z, &x, 6f64, 1e-12);
+
+    dct.dct4(&x, &mut y, &mut scratch, FftScaling::None);
+    dct.dct4(&y, &mut z, &mut scratch, FftScaling::None);
+    assert_close(&z, &x, 6f64, 1e-12);
+}
@@ -0,0 +1,187 @@
+use mixed_num::*;
+use fixed::{types::extra::{U0, U5, U12, U16, U28, U32, U60}, FixedI8, FixedI16, FixedI32, FixedI64, FixedU8, FixedU16, FixedU32};
+
+/// The error of `value` in lsb of `T`, relative to `reference` limited to the range of `T`.
+fn error_lsb<T>( value: T, reference: f64 ) -> f64
+    where T: MixedReal + MixedTypeInfo
+{
+    let max: f64 = T::mixed_max_value().mixed_to_num();
+    let min: f64 = T::mixed_min_value().mixed_to_num();
+    let value: f64 = value.mixed_to_num();
+    let lsb = libm::ldexp(1f64, -(T::FRAC_BITS as i32));
+    let error = (value - reference.min(max).max(min)).abs();
+    // f64 references are limited to 53 bits.
+    if error <= 1e-15*reference.abs()
+    {
+        return 0f64;
+    }
+    return error/lsb;
+}
+
+/// Checks the reciprocal and the reciprocal square root of `x` to within `lsb`, and the fast variants to within `fast_relative`.
+fn check<T>( x: T, lsb: f64, fast_relative: f64 )
+    where T: MixedReal + MixedTypeInfo + MixedRecip + MixedRsqrt + core::fmt::Debug
+{
+    let x_f64: f64 = x.mixed_to_num();
+    if x_f64 == 0f64
+    {
+        assert_eq!{ x.mixed_recip(), T::mixed_max_value() };
+        assert_eq!{ x.mixed_rsqrt(), T::mixed_max_value() };
+        return;
+    }
+    let recip = 1f64/x_f64;
+    let rsqrt = 1f64/x_f64.abs().sqrt();
+    assert!{ error_lsb(x.mixed_recip(), recip) <= lsb, "recip of {:?} is {:?}", x, x.mixed_recip() };
+    assert!{ error_lsb(x.mixed_rsqrt(), rsqrt) <= lsb, "rsqrt of {:?} is {:?}", x, x.mixed_rsqrt() };
+
+    // The fast variants, where the result is well within range.
+    let max: f64 = T::mixed_max_value().mixed_to_num();
+    let lsb_f64 = libm::ldexp(1f64, -(T::FRAC_BITS as i32));
+    if recip.abs() < max/2f64
+    {
+        let fast: f64 = x.mixed_recip_fast().mixed_to_num();
+        assert!{ (fast-recip).abs() <= fast_relative*recip.abs() + lsb_f64, "fast recip of {:?}", x };
+    }
+    if rsqrt < max/2f64
+    {
+        let fast: f64 = x.mixed_rsqrt_fast().mixed_to_num();
+        assert!{ (fast-rsqrt).abs() <= fast_relative*rsqrt + lsb_f64, "fast rsqrt of {:?}", x };
+    }
+}
+
+#[test]
+fn fixed_8_and_16_bits_full_range() {
+    for bits in i8::MIN..=i8::MAX
+    {
+        check(FixedI8::<U5>::from_bits(bits), 1f64, 2e-5);
+        check(FixedU8::<U0>::from_bits(bits as u8), 1f64, 2e-5);
+    }
+    for bits in i16::MIN..=i16::MAX
+    {
+        check(FixedI16::<U12>::from_bits(bits), 1f64, 2e-5);
+        check(FixedU16::<U0>::from_bits(bits as u16), 1f64, 2e-5);
+    }
+}
+
+#[test]
+fn fixed_32_bits() {
+    for bits in (i32::MIN..=i32::MAX).step_by(9973)
+    {
+        check(FixedI32::<U16>::from_bits(bits), 1f64, 2e-5);
+        check(FixedI32::<U28>::from_bits(bits), 1f64, 2e-5);
+        check(FixedU32::<U28>::from_bits(bits as u32), 1f64, 2e-5);
+    }
+    // Powers of two are exact.
+    for n in -1..=1
+    {
+        let x = FixedI32::<U28>::from_num(libm::ldexp(1f64, 2*n));
+        assert_eq!{ x.mixed_recip(), FixedI32::<U28>::from_num(libm::ldexp(1f64, -2*n)) };
+        assert_eq!{ x.mixed_rsqrt(), FixedI32::<U28>::from_num(libm::ldexp(1f64, -n)) };
+    }
+}
+
+#[test]
+fn fixed_64_bits() {
+    for bits in (i64::MIN..=i64::MAX).step_by(1<<44)
+    {
+        for offset in [0i64, 1, 12345]
+        {
+            check(FixedI64::<U32>::from_bits(bits.saturating_add(offset)), 2f64, 2e-5);
+            check(FixedI64::<U60>::from_bits(bits.saturating_add(offset)), 2f64, 2e-5);
+        }
+    }
+}
+
+#[test]
+fn fixed_saturation() {
+    type T = FixedI32<U28>;
+    assert_eq!{ T::DELTA.mixed_recip(), T::MAX };
+    assert_eq!{ (-T::DELTA).mixed_recip(), T::MIN };
+    assert_eq!{ T::DELTA.mixed_rsqrt(), T::MAX };
+    assert_eq!{ T::MIN.mixed_recip(), T::from_num(-0.125) };
+    // Negative values are forced positive.
+    assert_eq!{ T::from_num(-4).mixed_rsqrt(), T::from_num(0.5) };
+}
+
+#[test]
+fn floats() {
+    let mut x = 1e-30f64;
+    while x < 1e30f64
+    {
+        for value in [x, -x]
+        {
+            assert!{ (value.mixed_recip()-1f64/value).abs() <= 1e-16*(1f64/value).abs() };
+            assert!{ (value.mixed_recip_fast()-1f64/value).abs() <= 3e-3*(1f64/value).abs() };
+            assert!{ ((value as f32).mixed_recip_fast()-1f32/value as f32).abs() <= 3e-3*(1f32/value as f32).abs() };
+        }
+        assert!{ (x.mixed_rsqrt_fast()*x.sqrt()-1f64).abs() <= 2e-3 };
+        assert!{ ((x as f32).mixed_rsqrt_fast()*(x as f32).sqrt()-1f32).abs() <= 2e-3 };
+        assert!{ (x.mixed_rsqrt()*x.sqrt()-1f64).abs() <= 2e-16 };
+        x *= 1.37f64;
+    }
+    assert!{ (-1f32).mixed_rsqrt().is_nan() };
+    assert_eq!{ 0f64.mixed_recip(), f64::INFINITY };
+}
+
+#[test]
+fn floats_outside_the_fast_estimate() {
+    for value in [f64::MAX, -f64::MAX, 2e307f64, -3e307f64, f64::MIN_POSITIVE, 1e-310f64, -1e-310f64, 0f64, -0f64, f64::INFINITY, f64::NEG_INFINITY]
+    {
+        let recip = 1f64/value;
+        let fast = value.mixed_recip_fast();
+        assert!{ fast == recip || (fast-recip).abs() <= 3e-3*recip.abs(), "fast recip of {}", value };
+    }
+    for value in [f32::MAX, -f32::MAX, 2e38f32, f32::MIN_POSITIVE, 1e-40f32, 0f32, -0f32, f32::INFINITY, f32::NEG_INFINITY]
+    {
+        let recip = 1f32/value;
+        let fast = value.mixed_recip_fast();
+        assert!{ fast == recip || (fast-recip).abs() <= 3e-3*recip.abs(), "fast recip of {}", value };
+    }
+    assert!{ f64::NAN.mixed_recip_fast().is_nan() && f32::NAN.mixed_recip_fast().is_nan() };
+
+    for value in [-1f64, -4f64, -f64::MAX, f64::NEG_INFINITY, -1e-310f64]
+    {
+        assert!{ value.mixed_rsqrt_fast().is_nan() };
+    }
+    for value in [-1f32, -4f32, -f32::MAX, f32::NEG_INFINITY, -1e-40f32]
+    {
+        assert!{ value.mixed_rsqrt_fast().is_nan() };
+    }
+    assert_eq!{ 0f64.mixed_rsqrt_fast(), f64::INFINITY };
+    assert_eq!{ (-0f32).mixed_rsqrt_fast(), f32::NEG_INFINITY };
+    assert_eq!{ f64::INFINITY.mixed_rsqrt_fast(), 0f64 };
+    assert!{ (f32::MAX.mixed_rsqrt_fast()*f32::MAX.sqrt()-1f32).abs() <= 2e-3 };
+    assert!{ (1e-310f64.mixed_rsqrt_fast()*1e-310f64.sqrt()-1f64).abs() <= 1e-15 };
+    assert!{ f64::NAN.mixed_rsqrt_fast().is_nan() && f32::NAN.mixed_rsqrt_fast().is_nan() };
+}
+
+#[test]
+fn cartesian() {
+    for (re, im) in [(3f64, 4f64), (-1e-3, 2f64), (5f64, -1e-9), (-2f64, -2f64)]
+    {
+        let z = Cartesian::new(re, im);
+        let r = z.mixed_recip();
+        let product = z*r;
+        assert!{ (product.re-1f64).abs() < 1e-15 && product.im.abs() < 1e-15 };
+    }
+    // The squared magnitude of large values would overflow.
+    let z = Cartesian::new(1e200f64, -1e200f64).mixed_recip();
+    assert!{ (z.re-0.5e-200).abs() < 1e-214 && (z.im-0.5e-200).abs() < 1e-214 };
+
+    type T = FixedI32<U16>;
+    let z = Cartesian::new(T::from_num(200), T::from_num(-300)).mixed_recip();
+    let reference = 1f64/(200f64*200f64+300f64*300f64);
+    assert!{ (z.re.to_num::<f64>()-200f64*reference).abs() < 3e-5 };
+    assert!{ (z.im.to_num::<f64>()-300f64*reference).abs() < 3e-5 };
+
+    // The denominator of parts above half the range would overflow.
+    for (re, im) in [(T::MAX, T::MAX), (T::MAX, -T::MAX), (-T::MAX, T::from_num(-1e4)), (T::from_num(3e4), T::MAX)]
+    {
+        let z = Cartesian::new(re, im).mixed_recip();
+        let (re, im): (f64, f64) = (re.to_num(), im.to_num());
+        let reference = 1f64/(re*re+im*im);
+        assert!{ (z.re.to_num::<f64>()-re*reference).abs() < 3e-5 && (z.im.to_num::<f64>()+im*reference).abs() < 3e-5 };
+    }
+    let z = Cartesian::new(f64::MAX, f64::MAX).mixed_recip();
+    assert!{ (z.re*f64::MAX-0.5f64).abs() < 1e-15 && (z.im*f64::MAX+0.5f64).abs() < 1e-15 };
+}
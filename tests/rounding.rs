@@ -0,0 +1,136 @@
+use mixed_num::*;
+use fixed::{types::extra::{U0, U5, U12, U16, U28}, FixedI8, FixedI16, FixedI32, FixedU16, FixedU32};
+
+/// Checks the rounding properties against f64 for `x` in the range of `T`.
+fn check_properties<T>( x: T )
+    where T: MixedReal + MixedRound + MixedTrunc + MixedOps + core::fmt::Debug
+{
+    let trunc = x.mixed_trunc();
+    let fract = x.mixed_fract();
+    assert_eq!{ trunc+fract, x, "trunc + fract of {:?}", x };
+
+    let x_f64: f64 = x.mixed_to_num();
+    let trunc_f64: f64 = trunc.mixed_to_num();
+    let fract_f64: f64 = fract.mixed_to_num();
+    assert_eq!{ trunc_f64, libm::trunc(x_f64), "trunc of {:?}", x };
+    assert!{ fract_f64.abs() < 1f64 && (fract_f64 == 0f64 || fract_f64.signum() == x_f64.signum()), "fract of {:?}", x };
+
+    // The rounded integer saturates where out of range.
+    let round: f64 = x.mixed_round().mixed_to_num();
+    let round_even: f64 = x.mixed_round_ties_even().mixed_to_num();
+    let max: f64 = T::mixed_max_value().mixed_to_num();
+    let min: f64 = T::mixed_min_value().mixed_to_num();
+    assert_eq!{ round, libm::round(x_f64).min(max).max(min), "round of {:?}", x };
+    assert_eq!{ round_even, libm::rint(x_f64).min(max).max(min), "round_ties_even of {:?}", x };
+}
+
+/// Checks that `x == rhs*q + r` with `0=<r<|rhs|`.
+fn check_euclid<T>( x: T, rhs: T )
+    where T: MixedReal + MixedEuclid + MixedOps + core::fmt::Debug
+{
+    let q = x.mixed_div_euclid(rhs);
+    let r = x.mixed_rem_euclid(rhs);
+    let x_f64: f64 = x.mixed_to_num();
+    let rhs_f64: f64 = rhs.mixed_to_num();
+    let q_f64: f64 = q.mixed_to_num();
+    let r_f64: f64 = r.mixed_to_num();
+    assert!{ 0f64 <= r_f64 && r_f64 < rhs_f64.abs(), "rem_euclid of {:?} by {:?}", x, rhs };
+
+    // A saturated quotient is out of range.
+    if q != T::mixed_max_value() && q != T::mixed_min_value()
+    {
+        assert_eq!{ q_f64, libm::trunc(q_f64), "div_euclid of {:?} by {:?}", x, rhs };
+        assert_eq!{ rhs_f64*q_f64+r_f64, x_f64, "{:?} by {:?}", x, rhs };
+    }
+}
+
+#[test]
+fn floats() {
+    for i in -400..=400
+    {
+        let x = i as f64*0.125f64;
+        check_properties(x);
+        check_properties(x as f32);
+        check_euclid(x, 2.5f64);
+        check_euclid(x as f32, -0.75f32);
+    }
+
+    assert_eq!{ 0.5f64.mixed_round_ties_even(), 0f64 };
+    assert_eq!{ 1.5f64.mixed_round_ties_even(), 2f64 };
+    assert!{ f64::NAN.mixed_trunc().is_nan() };
+    assert!{ f32::INFINITY.mixed_fract().is_nan() };
+    assert!{ 1f64.mixed_rem_euclid(0f64).is_nan() };
+}
+
+#[test]
+fn fixed_full_range() {
+    for bits in i8::MIN..=i8::MAX
+    {
+        check_properties(FixedI8::<U5>::from_bits(bits));
+        check_properties(FixedI8::<U0>::from_bits(bits));
+    }
+    for bits in (i16::MIN..=i16::MAX).step_by(7)
+    {
+        let x = FixedI16::<U12>::from_bits(bits);
+        check_properties(x);
+        check_euclid(x, FixedI16::<U12>::from_num(-1.25));
+        check_euclid(x, FixedI16::<U12>::ONE);
+    }
+    for bits in (u16::MIN..=u16::MAX).step_by(5)
+    {
+        let x = FixedU16::<U12>::from_bits(bits);
+        check_properties(x);
+        check_euclid(x, FixedU16::<U12>::from_num(0.75));
+    }
+    for bits in (i32::MIN..=i32::MAX).step_by(1<<20)
+    {
+        check_properties(FixedI32::<U16>::from_bits(bits));
+        check_properties(FixedU32::<U28>::from_bits(bits as u32));
+        check_euclid(FixedI32::<U16>::from_bits(bits), FixedI32::<U16>::from_num(360));
+    }
+}
+
+#[test]
+fn fixed_limits() {
+    type T = FixedI32<U28>;
+    assert_eq!{ T::MAX.mixed_round(), T::MAX };
+    assert_eq!{ T::MIN.mixed_round(), T::MIN };
+    assert_eq!{ T::MIN.mixed_rem_euclid(-T::DELTA), T::ZERO };
+    // The quotient saturates.
+    assert_eq!{ T::MAX.mixed_div_euclid(T::DELTA), T::MAX };
+    assert_eq!{ T::from_num(-2.5).mixed_round_ties_even(), -2 };
+}
+
+#[test]
+fn integers() {
+    for x in -50i32..=50
+    {
+        assert_eq!{ x.mixed_round(), x };
+        assert_eq!{ x.mixed_trunc()+x.mixed_fract(), x };
+        for rhs in [-7, -1, 1, 3]
+        {
+            let q = x.mixed_div_euclid(rhs);
+            let r = x.mixed_rem_euclid(rhs);
+            assert!{ 0 <= r && r < rhs.abs() };
+            assert_eq!{ rhs*q+r, x };
+        }
+    }
+    assert_eq!{ i32::MIN.mixed_div_euclid(-1), i32::MAX };
+    assert_eq!{ i32::MIN.mixed_rem_euclid(-1), 0 };
+    assert_eq!{ 7u8.mixed_rem_euclid(3), 1 };
+}
+
+#[test]
+#[should_panic]
+fn integer_division_by_zero() {
+    let _ = 7i16.mixed_div_euclid(0);
+}
+
+#[test]
+fn cartesian_by_part() {
+    let z = Cartesian::new(2.5f64, -1.75f64);
+    assert_eq!{ z.mixed_round(), Cartesian::new(3f64, -2f64) };
+    assert_eq!{ z.mixed_round_ties_even(), Cartesian::new(2f64, -2f64) };
+    assert_eq!{ z.mixed_trunc(), Cartesian::new(2f64, -1f64) };
+    assert_eq!{ z.mixed_fract(), Cartesian::new(0.5f64, -0.75f64) };
+}
@@ -0,0 +1,133 @@
+use mixed_num::*;
+use fixed::{types::extra::U20, types::extra::U28, FixedI32};
+
+fn linspace<T: MixedReal>( start: f64, stop: f64, output: &mut [T] ) {
+    let n = output.len();
+    for (i, y) in output.iter_mut().enumerate() {
+        *y = T::mixed_from_num( start + (stop-start)*(i as f64)/((n-1) as f64) );
+    }
+}
+
+/// Asserts that the slice result `a` is within `tol` of the scalar result `b`.
+fn assert_close<T: MixedReal + core::fmt::Debug>( a: T, b: T, tol: f64 ) {
+    if tol == 0f64 {
+        assert_eq!{ a, b };
+    }
+    else {
+        let a_: f64 = a.mixed_to_num();
+        let b_: f64 = b.mixed_to_num();
+        assert!{ (a_-b_).abs() <= tol, "{:?} != {:?}", a, b };
+    }
+}
+
+/// `trig_tol` is the allowed difference between the slice and scalar sin and cos.
+fn test_real_slices<T>( trig_tol: f64 )
+    where T: MixedReal + MixedSinSlice + MixedCosSlice + MixedAtanSlice + MixedSqrtSlice + core::fmt::Debug
+{
+    let mut x = [T::mixed_from_num(0); 64];
+    let mut y = [T::mixed_from_num(0); 64];
+    linspace(-3.0, 3.0, &mut x);
+    linspace(2.0, -1.5, &mut y);
+
+    let mut out  = [T::mixed_from_num(0); 64];
+    let mut out2 = [T::mixed_from_num(0); 64];
+
+    x.mixed_sin_slice(&mut out);
+    for (o, x_) in out.iter().zip(x.iter()) {
+        assert_close( *o, x_.mixed_sin(), trig_tol );
+    }
+
+    x.mixed_sincos_slice(&mut out, &mut out2);
+    for ((s, c), x_) in out.iter().zip(out2.iter()).zip(x.iter()) {
+        let (s_, c_) = x_.mixed_sincos();
+        assert_close( *s, s_, trig_tol );
+        assert_close( *c, c_, trig_tol );
+    }
+
+    x.mixed_cos_slice(&mut out);
+    for (o, x_) in out.iter().zip(x.iter()) {
+        assert_close( *o, x_.mixed_cos(), trig_tol );
+    }
+
+    y.mixed_atan2_slice(&x, &mut out);
+    for (o, (y_, x_)) in out.iter().zip(y.iter().zip(x.iter())) {
+        assert_eq!{ *o, y_.mixed_atan2(*x_) };
+    }
+
+    y.mixed_atan2_poly_slice(&x, &mut out);
+    for (o, (y_, x_)) in out.iter().zip(y.iter().zip(x.iter())) {
+        assert_eq!{ *o, y_.mixed_atan2_poly(*x_) };
+    }
+
+    let mut positive = [T::mixed_from_num(0); 64];
+    linspace(0.0, 50.0, &mut positive);
+
+    positive.mixed_sqrt_into(&mut out);
+    for (o, x_) in out.iter().zip(positive.iter()) {
+        assert_eq!{ *o, x_.mixed_sqrt() };
+    }
+
+    positive.mixed_niirf_into(&mut out);
+    for (o, x_) in out.iter().zip(positive.iter()) {
+        assert_eq!{ *o, x_.mixed_niirf() };
+    }
+}
+
+fn test_cartesian_slices<T>()
+    where T: MixedReal + MixedCartesianSlice + core::fmt::Debug
+{
+    let mut re = [T::mixed_from_num(0); 32];
+    let mut im = [T::mixed_from_num(0); 32];
+    linspace(-2.0, 2.0, &mut re);
+    linspace(1.5, -1.0, &mut im);
+
+    let mut a = [Cartesian::new(T::mixed_from_num(0), T::mixed_from_num(0)); 32];
+    let mut b = a;
+    for i in 0..32 {
+        a[i] = Cartesian::new(re[i], im[i]);
+        b[i] = Cartesian::new(im[i], re[31-i]);
+    }
+
+    let mut mag = [T::mixed_from_num(0); 32];
+    a.mag_slice(&mut mag);
+    for (m, a_) in mag.iter().zip(a.iter()) {
+        assert_eq!{ *m, a_.mag() };
+    }
+
+    let mut product = a;
+    a.mul_slice(&b, &mut product);
+    for (p, (a_, b_)) in product.iter().zip(a.iter().zip(b.iter())) {
+        assert_eq!{ *p, *a_ * *b_ };
+    }
+}
+
+#[test]
+fn slice_math_equals_scalar_path() {
+    // The SIMD sin and cos are not bit exact with libm.
+    if cfg!(all(feature = "simd", target_arch = "x86_64")) {
+        test_real_slices::<f32>(1e-6);
+    }
+    else {
+        test_real_slices::<f32>(0f64);
+    }
+    test_real_slices::<f64>(0f64);
+    test_real_slices::<FixedI32<U20>>(0f64);
+
+    test_cartesian_slices::<f32>();
+    test_cartesian_slices::<f64>();
+    test_cartesian_slices::<FixedI32<U20>>();
+}
+
+#[test]
+fn slice_math_uses_shortest_length() {
+    let x = [FixedI32::<U28>::from_num(0.25); 4];
+    let mut y = [FixedI32::<U28>::from_num(0); 2];
+
+    x.mixed_sqrt_into(&mut y);
+    assert_eq!{ y, [x[0].mixed_sqrt(); 2] };
+
+    let mut z = [FixedI32::<U28>::from_num(-1); 6];
+    x.mixed_sqrt_into(&mut z);
+    assert_eq!{ z[3], x[0].mixed_sqrt() };
+    assert_eq!{ z[4], FixedI32::<U28>::from_num(-1) };
+}
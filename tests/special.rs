@@ -0,0 +1,134 @@
+use mixed_num::*;
+use fixed::{types::extra::{U13, U20, U28, U40}, FixedI16, FixedI32, FixedI64};
+
+/// f64 reference of I0, by numerical integration of `1/π ∫ exp(x*cos(θ)) dθ` over 0..π.
+fn reference_i0( x: f64 ) -> f64 {
+    let steps = 10000;
+    let mut sum = 0f64;
+    for i in 0..steps {
+        let theta = core::f64::consts::PI*(i as f64+0.5f64)/(steps as f64);
+        sum += (x*theta.cos()).exp();
+    }
+    return sum/(steps as f64);
+}
+
+/// Largest error of `f` relative to `reference` over `xs`, as `|y-r|/max(1, |r|)`.
+fn max_error<T>( f: fn(&T) -> T, reference: fn(f64) -> f64, xs: &[f64] ) -> f64
+    where T: MixedReal
+{
+    let mut max = 0f64;
+    for x in xs.iter() {
+        let y: f64 = f(&T::mixed_from_num(*x)).mixed_to_num();
+        let r = reference(*x);
+        max = max.max( (y-r).abs()/r.abs().max(1f64) );
+    }
+    return max;
+}
+
+const I0_X: [f64; 9] = [0f64, 0.1f64, 0.5f64, -1f64, 2f64, 3.75f64, 5f64, -7f64, 8f64];
+
+#[test]
+fn bessel_i0_accuracy() {
+    // (type, error bound)
+    let table = [
+        ("f64",           max_error::<f64>(f64::mixed_bessel_i0, reference_i0, &I0_X),                       1e-14f64),
+        ("f32",           max_error::<f32>(f32::mixed_bessel_i0, reference_i0, &I0_X),                       1e-6f64),
+        ("FixedI64<U40>", max_error::<FixedI64<U40>>(FixedI64::<U40>::mixed_bessel_i0, reference_i0, &I0_X), 1e-10f64),
+        ("FixedI32<U20>", max_error::<FixedI32<U20>>(FixedI32::<U20>::mixed_bessel_i0, reference_i0, &I0_X), 1e-5f64),
+    ];
+    for (name, error, bound) in table {
+        assert!{ error < bound, "{}: {} >= {}", name, error, bound };
+    }
+
+    let error = max_error::<FixedI16<U13>>(FixedI16::<U13>::mixed_bessel_i0, reference_i0, &[0f64, 0.5f64, 1f64, -1.5f64, 2f64]);
+    assert!{ error < 2e-3, "FixedI16<U13>: {}", error };
+}
+
+const GAMMA_X: [f64; 13] = [-2.5f64, -1.5f64, -0.5f64, 0.1f64, 0.5f64, 1f64, 1.5f64, 2f64, 2.5f64, 3.3f64, 4.5f64, 6f64, 7.5f64];
+const LGAMMA_X: [f64; 14] = [-2.5f64, -0.5f64, 0.1f64, 0.5f64, 1f64, 1.5f64, 2f64, 3.3f64, 6.9f64, 7f64, 10f64, 50f64, 120.5f64, 300f64];
+
+#[test]
+fn gamma_accuracy() {
+    let table = [
+        ("f64",           max_error::<f64>(f64::mixed_gamma, libm::tgamma, &GAMMA_X),                       1e-14f64),
+        ("f32",           max_error::<f32>(f32::mixed_gamma, libm::tgamma, &GAMMA_X),                       1e-6f64),
+        ("FixedI64<U40>", max_error::<FixedI64<U40>>(FixedI64::<U40>::mixed_gamma, libm::tgamma, &GAMMA_X), 5e-7f64),
+        ("FixedI32<U20>", max_error::<FixedI32<U20>>(FixedI32::<U20>::mixed_gamma, libm::tgamma, &GAMMA_X), 5e-5f64),
+    ];
+    for (name, error, bound) in table {
+        assert!{ error < bound, "{}: {} >= {}", name, error, bound };
+    }
+
+    let error = max_error::<FixedI16<U13>>(FixedI16::<U13>::mixed_gamma, libm::tgamma, &[0.5f64, 1f64, 1.5f64, 2.5f64, 3.5f64]);
+    assert!{ error < 2e-3, "FixedI16<U13>: {}", error };
+}
+
+#[test]
+fn lgamma_accuracy() {
+    let table = [
+        ("f64",           max_error::<f64>(f64::mixed_lgamma, libm::lgamma, &LGAMMA_X),                       1e-14f64),
+        ("f32",           max_error::<f32>(f32::mixed_lgamma, libm::lgamma, &LGAMMA_X),                       1e-6f64),
+        ("FixedI64<U40>", max_error::<FixedI64<U40>>(FixedI64::<U40>::mixed_lgamma, libm::lgamma, &LGAMMA_X), 5e-7f64),
+        ("FixedI32<U20>", max_error::<FixedI32<U20>>(FixedI32::<U20>::mixed_lgamma, libm::lgamma, &LGAMMA_X), 2e-5f64),
+    ];
+    for (name, error, bound) in table {
+        assert!{ error < bound, "{}: {} >= {}", name, error, bound };
+    }
+
+    // Without the Stirling series.
+    let error = max_error::<FixedI16<U13>>(FixedI16::<U13>::mixed_lgamma, libm::lgamma, &[0.1f64, 0.5f64, 1.5f64, 2.5f64, 3.9f64]);
+    assert!{ error < 2e-3, "FixedI16<U13>: {}", error };
+
+    // Poles.
+    assert_eq!{ FixedI32::<U20>::from_num(-2).mixed_lgamma(), FixedI32::<U20>::MAX };
+    assert_eq!{ FixedI32::<U20>::from_num(0).mixed_lgamma(), FixedI32::<U20>::MAX };
+}
+
+const ERF_X: [f64; 15] = [-3f64, -1.5f64, -0.5f64, -0.1f64, 0f64, 0.1f64, 0.3f64, 0.5f64, 1f64, 1.5f64, 2f64, 2.5f64, 3f64, 3.9f64, 5f64];
+
+/// The points of `ERF_X` in the range of `T`, with 6.5 where in range, and the limits of `T`.
+fn erf_points<T>() -> Vec<f64>
+    where T: MixedReal
+{
+    let max: f64 = T::mixed_max_value().mixed_to_num();
+    let min: f64 = T::mixed_min_value().mixed_to_num();
+    let mut points: Vec<f64> = ERF_X.iter().chain([6.5f64, -6.5f64].iter()).copied().filter(|x| min <= *x && *x <= max).collect();
+    // MAX of 64 bit types rounds up in f64, so take the f64 below, which converts to MAX for narrower types.
+    points.push(libm::nextafter(max, 0f64));
+    points.push(min);
+    return points;
+}
+
+#[test]
+fn erf_accuracy() {
+    let table = [
+        ("f64",           max_error::<f64>(f64::mixed_erf, libm::erf, &ERF_X),                       1e-15f64),
+        ("f32",           max_error::<f32>(f32::mixed_erf, libm::erf, &ERF_X),                       1e-6f64),
+        ("FixedI64<U40>", max_error::<FixedI64<U40>>(FixedI64::<U40>::mixed_erf, libm::erf, &erf_points::<FixedI64<U40>>()), 3e-7f64),
+        ("FixedI32<U28>", max_error::<FixedI32<U28>>(FixedI32::<U28>::mixed_erf, libm::erf, &erf_points::<FixedI32<U28>>()), 3e-7f64),
+        ("FixedI32<U20>", max_error::<FixedI32<U20>>(FixedI32::<U20>::mixed_erf, libm::erf, &erf_points::<FixedI32<U20>>()), 5e-5f64),
+    ];
+    for (name, error, bound) in table {
+        assert!{ error < bound, "{}: {} >= {}", name, error, bound };
+    }
+
+    let error = max_error::<FixedI16<U13>>(FixedI16::<U13>::mixed_erf, libm::erf, &[-4f64, -3.9f64, -1f64, 0f64, 0.3f64, 1.5f64, 3.9f64, FixedI16::<U13>::MAX.to_num::<f64>()]);
+    assert!{ error < 2e-3, "FixedI16<U13>: {}", error };
+}
+
+#[test]
+fn erfc_accuracy() {
+    let table = [
+        ("f64",           max_error::<f64>(f64::mixed_erfc, libm::erfc, &ERF_X),                       1e-15f64),
+        ("f32",           max_error::<f32>(f32::mixed_erfc, libm::erfc, &ERF_X),                       1e-6f64),
+        ("FixedI64<U40>", max_error::<FixedI64<U40>>(FixedI64::<U40>::mixed_erfc, libm::erfc, &erf_points::<FixedI64<U40>>()), 3e-7f64),
+        ("FixedI32<U28>", max_error::<FixedI32<U28>>(FixedI32::<U28>::mixed_erfc, libm::erfc, &erf_points::<FixedI32<U28>>()), 3e-7f64),
+    ];
+    for (name, error, bound) in table {
+        assert!{ error < bound, "{}: {} >= {}", name, error, bound };
+    }
+
+    // The floating-point erfc keeps its relative accuracy in the tail.
+    assert!{ ((5f64).mixed_erfc()/libm::erfc(5f64)-1f64).abs() < 1e-15 };
+    assert!{ ((5f32).mixed_erfc() as f64/libm::erfc(5f64)-1f64).abs() < 1e-6 };
+}
@@ -0,0 +1,76 @@
+use mixed_num::*;
+use fixed::{types::extra::{U13, U20, U28, U58}, FixedI16, FixedI32, FixedI64};
+
+use core::f64::consts::PI;
+
+/// Largest absolute errors of the tiers, `[sin_fast, sin_precise, cos_fast, cos_precise, atan2_fast, atan2_precise]`.
+fn max_errors<T>() -> [f64; 6]
+    where T: MixedReal + MixedTrigonometryAccuracy
+{
+    let mut errors = [0f64; 6];
+    let steps = 4000;
+    for i in 0..steps {
+        let x = -PI+2f64*PI*(i as f64)/(steps as f64);
+        let x_ = T::mixed_from_num(x);
+        // Compare against the quantized argument.
+        let x: f64 = x_.mixed_to_num();
+
+        let sin_fast: f64    = x_.mixed_sin_fast().mixed_to_num();
+        let sin_precise: f64 = x_.mixed_sin_precise().mixed_to_num();
+        let cos_fast: f64    = x_.mixed_cos_fast().mixed_to_num();
+        let cos_precise: f64 = x_.mixed_cos_precise().mixed_to_num();
+        errors[0] = errors[0].max( (sin_fast-x.sin()).abs() );
+        errors[1] = errors[1].max( (sin_precise-x.sin()).abs() );
+        errors[2] = errors[2].max( (cos_fast-x.cos()).abs() );
+        errors[3] = errors[3].max( (cos_precise-x.cos()).abs() );
+
+        let y_ = T::mixed_from_num(0.9f64*x.sin());
+        let x_ = T::mixed_from_num(0.9f64*x.cos());
+        let (y, x): (f64, f64) = (y_.mixed_to_num(), x_.mixed_to_num());
+        let reference = y.atan2(x);
+        let atan2_fast: f64    = y_.mixed_atan2_fast(x_).mixed_to_num();
+        let atan2_precise: f64 = y_.mixed_atan2_precise(x_).mixed_to_num();
+        // The branch cut at ±π.
+        let wrap = |e: f64| -> f64 { if PI < e.abs() { (e.abs()-2f64*PI).abs() } else { e.abs() } };
+        errors[4] = errors[4].max( wrap(atan2_fast-reference) );
+        errors[5] = errors[5].max( wrap(atan2_precise-reference) );
+    }
+    return errors;
+}
+
+/// Check the documented bounds of `MixedTrigonometryAccuracy`, where `lsb` is the resolution of fixed-point types, and `ulp` the precision of floats.
+fn check<T>( name: &str, lsb: f64, ulp: f64 )
+    where T: MixedReal + MixedTrigonometryAccuracy
+{
+    // The f64 reference adds its own rounding.
+    let reference = 2f64*f64::EPSILON;
+    let fast    = [4e-6f64 + 32f64*lsb, 4e-6f64 + 32f64*lsb, 5e-3f64 + 32f64*lsb];
+    let precise = 32f64*lsb + 4f64*ulp + reference;
+    let bounds  = [fast[0], precise, fast[1], precise, fast[2], precise];
+    let labels  = ["sin_fast", "sin_precise", "cos_fast", "cos_precise", "atan2_fast", "atan2_precise"];
+
+    let errors = max_errors::<T>();
+    for i in 0..6 {
+        assert!{ errors[i] <= bounds[i], "{} {}: {} > {}", name, labels[i], errors[i], bounds[i] };
+    }
+}
+
+#[test]
+fn float_tiers_meet_bounds() {
+    check::<f64>("f64", 0f64, f64::EPSILON);
+    check::<f32>("f32", 0f64, f32::EPSILON as f64);
+}
+
+#[test]
+fn fixed_tiers_meet_bounds() {
+    check::<FixedI64<U58>>("FixedI64<U58>", 2f64.powi(-58), 0f64);
+    check::<FixedI32<U28>>("FixedI32<U28>", 2f64.powi(-28), 0f64);
+    check::<FixedI32<U20>>("FixedI32<U20>", 2f64.powi(-20), 0f64);
+    check::<FixedI16<U13>>("FixedI16<U13>", 2f64.powi(-13), 0f64);
+}
+
+#[test]
+fn fast_tier_is_less_accurate_than_precise_tier() {
+    let errors = max_errors::<FixedI32<U28>>();
+    assert!{ errors[1] < errors[0] && errors[3] < errors[2] && errors[5] < errors[4], "{:?}", errors };
+}
@@ -0,0 +1,114 @@
+use mixed_num::*;
+use mixed_num::trigonometry::*;
+use fixed::{types::extra::{U5, U12, U20, U28, U29, U60}, FixedI8, FixedI16, FixedI32, FixedI64, FixedU16};
+
+#[test]
+fn type_info() {
+    assert!{ FixedI32::<U28>::IS_FIXED && FixedI32::<U28>::IS_SIGNED && !FixedI32::<U28>::IS_FLOAT };
+    assert!{ !FixedU16::<U12>::IS_SIGNED };
+    assert_eq!{ (FixedU16::<U12>::FRAC_BITS, FixedU16::<U12>::INT_BITS), (12, 4) };
+    assert_eq!{ FixedI64::<U60>::EPSILON.to_bits(), 1 };
+
+    assert!{ f64::IS_FLOAT && !f64::IS_FIXED && !f64::IS_INTEGER };
+    assert_eq!{ (f64::FRAC_BITS, f64::INT_BITS), (52, 1024) };
+    assert_eq!{ (f32::FRAC_BITS, f32::INT_BITS), (23, 128) };
+    assert_eq!{ f32::EPSILON, <f32 as MixedTypeInfo>::EPSILON };
+
+    assert!{ u8::IS_INTEGER && !u8::IS_SIGNED && i128::IS_SIGNED };
+    assert_eq!{ (u64::FRAC_BITS, u64::INT_BITS, <u64 as MixedTypeInfo>::EPSILON), (0, 64, 1) };
+}
+
+#[test]
+fn ulp() {
+    assert_eq!{ 1f64.mixed_ulp(), f64::EPSILON };
+    assert_eq!{ (-1f32).mixed_ulp(), f32::EPSILON };
+    assert_eq!{ 0f64.mixed_ulp(), f64::from_bits(1) };
+    assert_eq!{ f64::MAX.mixed_ulp(), 2f64.powi(971) };
+    assert_eq!{ f32::INFINITY.mixed_ulp(), f32::INFINITY };
+    assert!{ f64::NAN.mixed_ulp().is_nan() };
+
+    assert_eq!{ FixedI32::<U20>::from_num(-3).mixed_ulp(), FixedI32::<U20>::DELTA };
+    assert_eq!{ 17i32.mixed_ulp(), 1i32 };
+}
+
+/// The largest error of the square root, in lsb of the root for roots above one.
+fn sqrt_error_lsb<T>() -> f64
+    where T: MixedReal + MixedOps + MixedSqrt + MixedTypeInfo
+{
+    let lsb: f64 = T::EPSILON.mixed_to_num();
+    let max: f64 = T::mixed_max_value().mixed_to_num();
+    let mut error = 0f64;
+    for i in 1..2000 {
+        let x = T::mixed_from_num(max*(i as f64)/2000f64);
+        let x_: f64 = x.mixed_to_num();
+        let y: f64 = x.mixed_sqrt().mixed_to_num();
+        // The normalization to 0.25=<x<1 scales the error by the root.
+        error = error.max( (y-x_.sqrt()).abs()/(lsb*x_.sqrt().max(1f64)) );
+    }
+    return error;
+}
+
+#[test]
+fn sqrt_iterations_follow_precision() {
+    assert_eq!{ sqrt::niirf_iterations::<f64>(), 13 };
+    assert_eq!{ sqrt::niirf_iterations::<i32>(), 1 };
+    assert!{ sqrt::niirf_iterations::<FixedI16<U12>>() < sqrt::niirf_iterations::<FixedI64<U60>>() };
+
+    assert!{ sqrt_error_lsb::<FixedI16<U12>>() < 8f64 };
+    assert!{ sqrt_error_lsb::<FixedI32<U28>>() < 8f64 };
+    assert!{ sqrt_error_lsb::<FixedI32<U20>>() < 8f64 };
+}
+
+#[test]
+fn sqrt_of_types_in_the_range_of_four() {
+    // 4 is not representable, so the normalization can not multiply by it.
+    type T = FixedI32<U29>;
+    assert!{ (T::from_num(0.5).mixed_niirf().to_num::<f64>()-0.7064f64).abs() < 1e-4 };
+    assert!{ sqrt_error_lsb::<T>() < 8f64 };
+
+    let x = [T::from_num(0.01), T::from_num(0.5), T::from_num(3.9)];
+    let mut y = [T::ZERO; 3];
+    x.mixed_sqrt_into(&mut y);
+    for (x_, y_) in x.iter().zip(y.iter()) {
+        assert_eq!{ *y_, x_.mixed_sqrt() };
+    }
+    sqrt::niirf_slice(&x, &mut y, 2);
+    assert_eq!{ y[1], T::from_num(0.5).mixed_niirf() };
+}
+
+/// The largest errors of `sin`, and of the full Taylor polynomial.
+fn sin_errors<T>() -> (f64, f64)
+    where T: MixedReal + MixedNumSigned + MixedOps + MixedPi + MixedTypeInfo
+{
+    let full = |x: T| -> T {
+        let x2 = x*x;
+        let mut y = T::mixed_from_num(1f64/362880f64);
+        for c in [-1f64/5040f64, 1f64/120f64, -1f64/6f64, 1f64] {
+            y = y*x2 + T::mixed_from_num(c);
+        }
+        return y*x;
+    };
+
+    let (mut error, mut error_full) = (0f64, 0f64);
+    for i in 0..=400 {
+        let x = T::mixed_from_num(-3.1f64+6.2f64*(i as f64)/400f64);
+        let x_: f64 = x.mixed_to_num();
+        let y: f64 = sin(x).mixed_to_num();
+        let y_full: f64 = sin_with(x, &full).mixed_to_num();
+        error = error.max( (y-x_.sin()).abs() );
+        error_full = error_full.max( (y_full-x_.sin()).abs() );
+    }
+    return (error, error_full);
+}
+
+#[test]
+fn sin_terms_follow_precision() {
+    // The dropped terms are below the rounding of narrow types.
+    let (error, error_full) = sin_errors::<FixedI8<U5>>();
+    assert!{ error <= error_full + 2f64.powi(-5), "{} {}", error, error_full };
+    let (error, error_full) = sin_errors::<FixedI16<U12>>();
+    assert!{ error <= error_full + 2f64.powi(-12), "{} {}", error, error_full };
+
+    let (error, error_full) = sin_errors::<FixedI32<U28>>();
+    assert_eq!{ error, error_full };
+}
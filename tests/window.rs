@@ -0,0 +1,132 @@
+use mixed_num::*;
+use mixed_num::dsp::window::*;
+use fixed::{types::extra::{U13, U20, U28}, FixedI16, FixedI32};
+
+use core::f64::consts::PI;
+
+const BLACKMAN_HARRIS: [f64; 4] = [0.35875f64, 0.48829f64, 0.14128f64, 0.01168f64];
+const FLAT_TOP: [f64; 5] = [0.21557895f64, 0.41663158f64, 0.277263158f64, 0.083578947f64, 0.006947368f64];
+
+/// f64 reference of the cosine-sum windows.
+fn reference_cosine_sum( n: usize, len: usize, coeffs: &[f64], symmetry: WindowSymmetry ) -> f64 {
+    let d = match symmetry {
+        WindowSymmetry::Symmetric => (len-1) as f64,
+        WindowSymmetry::Periodic  => len as f64,
+    };
+    let mut w = 0f64;
+    for (k, a) in coeffs.iter().enumerate() {
+        w += (-1f64).powi(k as i32)*a*(2f64*PI*(k*n) as f64/d).cos();
+    }
+    return w;
+}
+
+/// f64 reference of I0, by numerical integration of `1/π ∫ exp(x*cos(θ)) dθ` over 0..π.
+fn reference_i0( x: f64 ) -> f64 {
+    let steps = 10000;
+    let mut sum = 0f64;
+    for i in 0..steps {
+        let theta = PI*(i as f64+0.5f64)/(steps as f64);
+        sum += (x*theta.cos()).exp();
+    }
+    return sum/(steps as f64);
+}
+
+fn test_cosine_windows<T>( tol: f64 )
+    where T: MixedReal + MixedOps + MixedZero + MixedOne + MixedCos + MixedPi
+{
+    let generators: [(fn(&mut [T], WindowSymmetry), &[f64]); 4] = [
+        (hann,            &[0.5f64, 0.5f64]),
+        (hamming,         &[0.54f64, 0.46f64]),
+        (blackman_harris, &BLACKMAN_HARRIS),
+        (flat_top,        &FLAT_TOP),
+    ];
+    for (generator, coeffs) in generators {
+        for symmetry in [WindowSymmetry::Symmetric, WindowSymmetry::Periodic] {
+            for len in [1, 2, 7, 64] {
+                let mut w = [T::mixed_zero(); 64];
+                generator(&mut w[..len], symmetry);
+                for (n, w_) in w[..len].iter().enumerate() {
+                    let expected = if len == 1 { 1f64 } else { reference_cosine_sum(n, len, coeffs, symmetry) };
+                    let w_: f64 = w_.mixed_to_num();
+                    assert!{ (w_-expected).abs() < tol, "{:?} {} {}: {} != {}", symmetry, len, n, w_, expected };
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn cosine_windows_match_reference() {
+    test_cosine_windows::<f64>(1e-15);
+    test_cosine_windows::<f32>(1e-6);
+    test_cosine_windows::<FixedI32<U28>>(1e-6);
+    test_cosine_windows::<FixedI16<U13>>(2e-3);
+}
+
+#[test]
+fn symmetric_and_periodic_windows() {
+    let mut symmetric = [0f64; 33];
+    let mut periodic = [0f64; 32];
+    blackman_harris(&mut symmetric, WindowSymmetry::Symmetric);
+    blackman_harris(&mut periodic, WindowSymmetry::Periodic);
+    for n in 0..32 {
+        assert_eq!{ symmetric[n], symmetric[32-n] };
+        assert!{ (symmetric[n]-periodic[n]).abs() < 1e-15 };
+    }
+}
+
+#[test]
+fn kaiser_matches_reference() {
+    for beta in [0f64, 1f64, 5f64, 8.6f64] {
+        for symmetry in [WindowSymmetry::Symmetric, WindowSymmetry::Periodic] {
+            let d = match symmetry {
+                WindowSymmetry::Symmetric => 20f64,
+                WindowSymmetry::Periodic  => 21f64,
+            };
+            let mut w64 = [0f64; 21];
+            let mut w32 = [0f32; 21];
+            let mut wfx = [FixedI32::<U20>::from_num(0); 21];
+            kaiser(&mut w64, beta, symmetry);
+            kaiser(&mut w32, beta as f32, symmetry);
+            kaiser(&mut wfx, FixedI32::<U20>::from_num(beta), symmetry);
+            for n in 0..21 {
+                let r = 2f64*n as f64/d - 1f64;
+                let expected = reference_i0(beta*(1f64-r*r).sqrt())/reference_i0(beta);
+                assert!{ (w64[n]-expected).abs() < 1e-12 };
+                assert!{ (w32[n] as f64-expected).abs() < 1e-5 };
+                // The fixed-point square root limits the accuracy, with an error that scales with β.
+                assert!{ (wfx[n].to_num::<f64>()-expected).abs() < 1e-3+beta*6e-4, "{} {}: {} != {}", beta, n, wfx[n], expected };
+            }
+        }
+    }
+}
+
+#[test]
+fn coherent_gain_and_enbw() {
+    // For periodic windows, the coherent gain is a0, and the ENBW (a0^2 + Σ_(k>0) a_k^2/2)/a0^2.
+    let cases: [(fn(&mut [f64], WindowSymmetry), &[f64]); 4] = [
+        (hann,            &[0.5f64, 0.5f64]),
+        (hamming,         &[0.54f64, 0.46f64]),
+        (blackman_harris, &BLACKMAN_HARRIS),
+        (flat_top,        &FLAT_TOP),
+    ];
+    for (generator, coeffs) in cases {
+        let gain = coeffs[0];
+        let bandwidth = coeffs.iter().skip(1).fold(gain*gain, |sum, a| sum + a*a/2f64)/(gain*gain);
+
+        let mut w = [0f64; 256];
+        generator(&mut w, WindowSymmetry::Periodic);
+        assert!{ (coherent_gain(&w)-gain).abs() < 1e-12 };
+        assert!{ (enbw(&w)-bandwidth).abs() < 1e-7, "{} != {}", enbw(&w), bandwidth };
+    }
+
+    let mut w = [FixedI32::<U28>::from_num(0); 256];
+    hann(&mut w, WindowSymmetry::Periodic);
+    assert!{ (coherent_gain(&w).to_num::<f64>()-0.5f64).abs() < 1e-6 };
+    assert!{ (enbw(&w).to_num::<f64>()-1.5f64).abs() < 1e-5 };
+
+    let mut w = [FixedI16::<U13>::from_num(0); 64];
+    hann(&mut w, WindowSymmetry::Periodic);
+    assert!{ (coherent_gain(&w).to_num::<f64>()-0.5f64).abs() < 1e-2 };
+    assert!{ (enbw(&w).to_num::<f64>()-1.5f64).abs() < 2e-2 };
+}
@@ -0,0 +1,94 @@
+use mixed_num::*;
+use mixed_num::trigonometry::*;
+use fixed::{types::extra::{U13, U28, U29, U32, U60}, FixedI16, FixedI32, FixedI64};
+
+use core::f64::consts::PI;
+
+/// The reference, wrapped in f64 with the period `2*half`.
+fn reference( x: f64, half: f64 ) -> f64 {
+    return (x+half).rem_euclid(2f64*half)-half;
+}
+
+#[test]
+fn floats() {
+    for x in [0f64, PI, -PI, 3f64*PI, 1e6f64, -1e6f64, 1e15f64, -1e300f64] {
+        let wrapped = wrap_phase(x);
+        assert!{ -PI <= wrapped && wrapped < PI, "{}: {}", x, wrapped };
+        // The reference rounds x+π.
+        let tolerance = 1e-9f64 + x.abs()*f64::EPSILON;
+        let error = (wrapped-reference(x, PI)).abs();
+        assert!{ error < tolerance || (2f64*PI-error).abs() < tolerance, "{}", x };
+
+        let positive = wrap_phase_positive(x);
+        assert!{ 0f64 <= positive && positive < 2f64*PI, "{}: {}", x, positive };
+    }
+    assert_eq!{ wrap_phase(PI), -PI };
+    assert_eq!{ wrap_phase(-PI), -PI };
+    assert_eq!{ wrap_degrees(-900f64), -180f64 };
+    assert_eq!{ wrap_degrees(1e6f32), -80f32 };
+    assert_eq!{ wrap_turns(1e9f64+0.25f64), 0.25f64 };
+
+    let wrapped = f32::MAX.mixed_wrap_phase();
+    assert!{ -core::f32::consts::PI <= wrapped && wrapped < core::f32::consts::PI };
+    assert!{ f64::NAN.mixed_wrap_phase().is_nan() };
+
+    // Tiny negative angles round up to the period when it is added.
+    assert_eq!{ (-1e-20f64).mixed_wrap_phase_positive(), 0f64 };
+    assert_eq!{ (-1e-20f32).mixed_wrap_phase_positive(), 0f32 };
+    assert_eq!{ (-1e-20f64).mixed_wrap_degrees(), -1e-20f64 };
+    let positive = (-1e-15f64).mixed_wrap_phase_positive();
+    assert!{ 0f64 <= positive && positive < 2f64*PI };
+}
+
+fn check_fixed<T>( name: &str, lsb: f64 )
+    where T: MixedReal + MixedNumSigned + MixedOps + MixedZero + MixedWrapPhase
+{
+    let min: f64 = T::mixed_min_value().mixed_to_num();
+    let max: f64 = T::mixed_max_value().mixed_to_num();
+    let steps = 2000;
+    for i in 0..=steps {
+        let x = if i == steps { T::mixed_max_value() } else { T::mixed_from_num(min+(max-min)*(i as f64)/(steps as f64)) };
+        let x_: f64 = x.mixed_to_num();
+
+        // The rounding of π in the type, per wrapped period.
+        let tolerance = 2f64*lsb*(x_.abs()/(2f64*PI)+1f64);
+        for (wrapped, half) in [(x.mixed_wrap_phase(), PI), (x.mixed_wrap_turns(), 0.5f64)] {
+            let wrapped: f64 = wrapped.mixed_to_num();
+            assert!{ -half-tolerance <= wrapped && wrapped < half+tolerance, "{} {}: {}", name, x_, wrapped };
+            let error = (wrapped-reference(x_, half)).abs();
+            assert!{ error < tolerance || (2f64*half-error).abs() < tolerance, "{} {}: {} != {}", name, x_, wrapped, reference(x_, half) };
+        }
+    }
+}
+
+#[test]
+fn fixed_extremes() {
+    check_fixed::<FixedI16<U13>>("FixedI16<U13>", 2f64.powi(-13));
+    check_fixed::<FixedI32<U28>>("FixedI32<U28>", 2f64.powi(-28));
+    check_fixed::<FixedI64<U32>>("FixedI64<U32>", 2f64.powi(-32));
+    check_fixed::<FixedI64<U60>>("FixedI64<U60>", 2f64.powi(-60));
+
+    let phi = FixedI64::<U32>::MAX.mixed_wrap_degrees();
+    assert!{ (phi.to_num::<f64>()-reference(2f64.powi(31), 180f64)).abs() < 1e-6 };
+    let phi = FixedI64::<U32>::MIN.mixed_wrap_phase_positive();
+    assert!{ 0f64 <= phi.to_num::<f64>() && phi.to_num::<f64>() < 2f64*PI };
+
+    // 2π is out of range, the results above the maximum saturate.
+    type N = FixedI32<U29>;
+    assert_eq!{ N::from_num(3.5).mixed_wrap_phase_positive(), N::from_num(3.5) };
+    assert_eq!{ N::MAX.mixed_wrap_phase_positive(), N::MAX };
+    assert_eq!{ N::from_num(-3).mixed_wrap_phase_positive(), N::from_num(-3)+N::from_num(PI)+N::from_num(PI) };
+    assert_eq!{ N::from_num(-0.1).mixed_wrap_phase_positive(), N::MAX };
+    assert!{ (N::MIN.mixed_wrap_phase_positive().to_num::<f64>()-(2f64*PI-4f64)).abs() < 1e-8 };
+}
+
+#[test]
+fn integers() {
+    assert_eq!{ i32::MAX.mixed_wrap_degrees(), (i32::MAX as i64+180).rem_euclid(360) as i32 - 180 };
+    assert_eq!{ i64::MIN.mixed_wrap_degrees(), (i64::MIN as i128+180).rem_euclid(360) as i64 - 180 };
+    assert_eq!{ (-181i16).mixed_wrap_degrees(), 179i16 };
+    assert_eq!{ 127i8.mixed_wrap_degrees(), 127i8 };
+    assert_eq!{ 7i32.mixed_wrap_phase(), 1i32 };
+    assert_eq!{ (-7i32).mixed_wrap_phase_positive(), 5i32 };
+    assert_eq!{ 12345i32.mixed_wrap_turns(), 0i32 };
+}